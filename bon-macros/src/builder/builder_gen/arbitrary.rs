@@ -0,0 +1,158 @@
+use super::{BuilderGenCtx, Member, NamedMember};
+use crate::util::prelude::*;
+use quote::quote;
+
+impl BuilderGenCtx {
+    /// Generates the `from_arbitrary()` method requested with `#[builder(arbitrary)]`
+    /// that builds the struct from the values produced by the `arbitrary` crate.
+    /// It's meant for the fuzz harnesses, so every member is fed through its
+    /// setter and the value is built with the finishing function, which keeps
+    /// all the validations of the builder in place.
+    ///
+    /// The values are consumed from the input in the order of the members'
+    /// declaration, so the same bytes always produce the same value. The method
+    /// references the `arbitrary` crate by name, so the crate that uses the
+    /// attribute must depend on it directly.
+    pub(crate) fn arbitrary(&self) -> Result<Option<TokenStream2>> {
+        if !self.arbitrary.is_present() {
+            return Ok(None);
+        }
+
+        if let Some(unsafety) = &self.finish_func.unsafety {
+            bail!(
+                unsafety,
+                "`arbitrary` can't be used with an `unsafe` finishing function"
+            );
+        }
+
+        if let Some(asyncness) = &self.finish_func.asyncness {
+            bail!(
+                asyncness,
+                "`arbitrary` can't be used with an `async` finishing function"
+            );
+        }
+
+        let output = match &self.finish_func.output {
+            syn::ReturnType::Type(_, ty) => ty,
+            syn::ReturnType::Default => bail!(
+                &self.finish_func.ident,
+                "`arbitrary` requires the finishing function to return a value"
+            ),
+        };
+
+        // Every type that is produced from the input gets a bound, which also
+        // ties the lifetimes of the borrowed members to the lifetime of the input
+        let mut value_types = vec![];
+
+        let mut arbitrary_value = |ty: &syn::Type| {
+            value_types.push(quote!(#ty));
+            quote!(<#ty as arbitrary::Arbitrary<'__arbitrary>>::arbitrary(u)?)
+        };
+
+        let start_fn_args = self
+            .start_fn_args()
+            .map(|member| arbitrary_value(&member.base.norm_ty))
+            .collect::<Vec<_>>();
+
+        let setter_calls = self
+            .named_members()
+            .map(|member| {
+                validate_member(member)?;
+
+                let maybe_map_err = member
+                    .params
+                    .checked
+                    .as_ref()
+                    .map(|_| quote!(.map_err(|_| arbitrary::Error::IncorrectFormat)?));
+
+                let core_name = member.setter_method_core_name();
+
+                // The optional members are set with the `maybe_` setters, so
+                // they may stay unset
+                let (setter, value) = if let Some(inner_ty) = member.as_optional_norm_ty() {
+                    let setter = quote::format_ident!("maybe_{}", core_name.raw_name());
+                    (
+                        setter,
+                        arbitrary_value(&syn::parse_quote!(Option<#inner_ty>)),
+                    )
+                } else {
+                    (core_name.clone(), arbitrary_value(&member.norm_ty))
+                };
+
+                Ok(quote!(.#setter(#value) #maybe_map_err))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let finish_fn_args = self
+            .members
+            .iter()
+            .filter_map(Member::as_finish_fn_arg)
+            .map(|member| arbitrary_value(&member.norm_ty))
+            .collect::<Vec<_>>();
+
+        let start_fn_owner = if let Some(on) = self.start_func_on() {
+            quote!(<#on>)
+        } else {
+            quote!(Self)
+        };
+
+        let self_ty = &self
+            .assoc_method_ctx
+            .as_ref()
+            .expect("BUG: `arbitrary` is supported only for structs")
+            .self_ty;
+
+        let generics_decl = &self.generics.decl_without_defaults;
+        let where_clause = &self.generics.where_clause;
+        let finish_where_predicates = &self.finish_func.where_predicates;
+        let start_fn_ident = &self.start_func.ident;
+        let finish_fn_ident = &self.finish_func.ident;
+        let vis = &self.vis;
+
+        Ok(Some(quote! {
+            #[automatically_derived]
+            impl<#(#generics_decl,)*> #self_ty
+            #where_clause
+            {
+                ::bon::__from_arbitrary_fn! {
+                    /// Builds the value from the unstructured input of the `arbitrary`
+                    /// crate by passing an arbitrary value to every setter.
+                    #[allow(clippy::use_self)]
+                    #vis fn from_arbitrary<'__arbitrary>(
+                        u: &mut arbitrary::Unstructured<'__arbitrary>,
+                    ) -> arbitrary::Result<#output>
+                    where
+                        #(#value_types: arbitrary::Arbitrary<'__arbitrary>,)*
+                        #(#finish_where_predicates,)*
+                    {
+                        ::core::result::Result::Ok(
+                            #start_fn_owner::#start_fn_ident(#(#start_fn_args),*)
+                                #(#setter_calls)*
+                                .#finish_fn_ident(#(#finish_fn_args),*)
+                        )
+                    }
+                }
+            }
+        }))
+    }
+}
+
+fn validate_member(member: &NamedMember) -> Result {
+    let params = &member.params;
+    let unsupported = [
+        params.bitor.is_present().then(|| "bitor"),
+        params.collection.is_present().then(|| "collection"),
+        params.borrow.is_present().then(|| "borrow"),
+        params.one_of.as_ref().map(|_| "one_of"),
+    ];
+
+    if let Some(name) = unsupported.into_iter().flatten().next() {
+        bail!(
+            &member.orig_ident,
+            "`arbitrary` can't be used with `{name}` members, because their \
+            setters don't accept the value of the member as is"
+        );
+    }
+
+    Ok(())
+}
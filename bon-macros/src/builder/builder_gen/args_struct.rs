@@ -0,0 +1,203 @@
+use super::{BuilderGenCtx, Member, NamedMember};
+use crate::util::prelude::*;
+use quote::quote;
+
+pub(crate) struct ArgsStruct {
+    pub(crate) ident: syn::Ident,
+
+    /// Visibility of every field of the original struct. The fields of the
+    /// args struct have the same visibility, so the private fields can't be
+    /// set by the code that can't set them with a struct literal.
+    pub(crate) fields_vis: Vec<(syn::Ident, syn::Visibility)>,
+}
+
+impl BuilderGenCtx {
+    /// Generates a struct that mirrors all non-skipped members and a `from_args`
+    /// constructor that accepts it. This is a non-fluent alternative to the
+    /// builder for callers who prefer the struct literal syntax.
+    ///
+    /// Optional members are represented as `Option<T>` fields in the args struct.
+    /// For members with `#[builder(default)]` the `None` value means the default
+    /// value should be used.
+    ///
+    /// The `from_args` constructor doesn't call the setters, so the members
+    /// with validating setters aren't supported. The `one_of` members aren't
+    /// supported either, because the typestate can't enforce their exclusivity.
+    pub(crate) fn args_struct(&self) -> Result<Option<TokenStream2>> {
+        let args_struct = match &self.args_struct {
+            Some(args_struct) => args_struct,
+            None => return Ok(None),
+        };
+
+        for member in self.named_members() {
+            validate_member(member)?;
+        }
+
+        let args_ident = &args_struct.ident;
+
+        let self_ty = &self
+            .assoc_method_ctx
+            .as_ref()
+            .expect("BUG: `args_struct` is supported only for structs")
+            .self_ty;
+
+        let vis = &self.vis;
+
+        let field_vis = |ident: &syn::Ident| {
+            args_struct
+                .fields_vis
+                .iter()
+                .find(|(field, _)| field == ident)
+                .map(|(_, vis)| vis)
+                .unwrap_or(vis)
+        };
+
+        let fields = self
+            .members
+            .iter()
+            .filter_map(|member| {
+                let (ident, ty, docs) = match member {
+                    Member::Named(member) => (
+                        &member.orig_ident,
+                        member.set_state_type_param(),
+                        member.docs.as_slice(),
+                    ),
+                    Member::StartFnArg(member) => {
+                        let ty = &member.base.norm_ty;
+                        (&member.base.ident, quote!(#ty), [].as_slice())
+                    }
+                    Member::FinishFnArg(member) => {
+                        let ty = &member.norm_ty;
+                        (&member.ident, quote!(#ty), [].as_slice())
+                    }
+                    Member::Skipped(_) => return None,
+                };
+
+                let vis = field_vis(ident);
+
+                Some(quote! {
+                    #(#docs)*
+                    #vis #ident: #ty
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let args_vars = self
+            .members
+            .iter()
+            .filter(|member| !matches!(member, Member::Skipped(_)))
+            .map(Member::orig_ident);
+
+        // The members are evaluated in the same order as in the finishing
+        // function of the builder.
+        let members_vars_decls = self
+            .members_in_init_order()?
+            .into_iter()
+            .map(|member| {
+                let var_ident = member.orig_ident();
+                let ty = member.norm_ty();
+
+                let expr = match member {
+                    Member::Named(member) => {
+                        let maybe_default = self.member_default_fallback(member)?;
+                        quote!(#var_ident #maybe_default)
+                    }
+                    Member::Skipped(_) => self.member_expr(member)?,
+                    Member::StartFnArg(_) | Member::FinishFnArg(_) => quote!(#var_ident),
+                };
+
+                Ok(quote! {
+                    let #var_ident: #ty = #expr;
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let generics_decl_with_defaults = &self.generics.decl_with_defaults;
+        let generics_decl = &self.generics.decl_without_defaults;
+        let generic_args = &self.generics.args;
+        let where_clause = &self.generics.where_clause;
+        let finish_where_predicates = &self.finish_func.where_predicates;
+        let body = self.finish_func.body.generate(&self.members);
+
+        let struct_name = self_ty
+            .as_path()
+            .and_then(|path| path.path.segments.last())
+            .map(|segment| segment.ident.to_string())
+            .unwrap_or_default();
+
+        let args_struct_docs = format!(
+            "Members of [`{struct_name}`] for constructing it with \
+            [`{struct_name}::from_args()`]. Optional members are represented \
+            with `Option`, where `None` means the default value."
+        );
+
+        let from_args_docs = format!(
+            "Constructs the value from the [`{args_ident}`] struct. It's an equivalent \
+            of using the builder with all members set."
+        );
+
+        let allows = super::allow_warnings_on_member_types();
+
+        Ok(Some(quote! {
+            #[doc = #args_struct_docs]
+            #allows
+            #vis struct #args_ident<#(#generics_decl_with_defaults,)*>
+            #where_clause
+            {
+                #(#fields,)*
+            }
+
+            #allows
+            #[automatically_derived]
+            impl<#(#generics_decl,)*> #self_ty
+            #where_clause
+            {
+                #[doc = #from_args_docs]
+                #[inline(always)]
+                #[allow(
+                    // This is intentional. We want the constructor to compile away
+                    clippy::inline_always,
+                    // We normalize `Self` references intentionally to simplify code generation
+                    clippy::use_self,
+                    // Let's keep it as non-const for now to avoid restricting ourselves to only
+                    // const operations.
+                    clippy::missing_const_for_fn,
+                )]
+                #vis fn from_args(args: #args_ident<#(#generic_args,)*>) -> #self_ty
+                where
+                    #(#finish_where_predicates,)*
+                {
+                    let #args_ident { #(#args_vars,)* } = args;
+                    #(#members_vars_decls)*
+                    #body
+                }
+            }
+        }))
+    }
+}
+
+fn validate_member(member: &NamedMember) -> Result {
+    let params = &member.params;
+    let unsupported = [
+        params.checked.as_ref().map(|_| "checked"),
+        params.item_validate.as_ref().map(|_| "item_validate"),
+    ];
+
+    if let Some(name) = unsupported.into_iter().flatten().next() {
+        bail!(
+            &member.orig_ident,
+            "`args_struct` can't be used with `{name}` members, because \
+            `from_args()` doesn't call the setters that validate the value"
+        );
+    }
+
+    if member.params.one_of.is_some() {
+        bail!(
+            &member.orig_ident,
+            "`args_struct` can't be used with `one_of` members, because \
+            the fields of the args struct can't be mutually exclusive"
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use super::BuilderGenCtx;
+use crate::util::prelude::*;
+use syn::spanned::Spanned;
+
+impl BuilderGenCtx {
+    /// Generates a compile-time assertion requested with
+    /// `#[builder(assert_field_count = N)]`. The expected count can be any
+    /// constant expression, so the check is deferred to the compiler's const
+    /// evaluation instead of comparing it in the macro.
+    pub(crate) fn assert_field_count(&self) -> Option<TokenStream2> {
+        let expected = self.assert_field_count.as_ref()?;
+
+        // Every field of the struct is a member, including the skipped ones
+        let actual = self.members.len();
+
+        let message = format!(
+            "the struct has {actual} fields, which doesn't match the count \
+            specified in `#[builder(assert_field_count = ...)]`"
+        );
+
+        // The span makes the compiler point to the expected count if it doesn't match
+        Some(quote::quote_spanned! {expected.span()=>
+            const _: () = ::core::assert!(#actual == #expected, #message);
+        })
+    }
+}
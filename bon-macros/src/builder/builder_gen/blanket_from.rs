@@ -0,0 +1,136 @@
+use super::{BuilderGenCtx, Member};
+use crate::util::prelude::*;
+use quote::quote;
+
+impl BuilderGenCtx {
+    /// Generates an `impl<T: Into<Inner>> From<T>` for the struct with a single
+    /// member that has an `Into` setter. This collapses the builder into the
+    /// standard conversion for the newtype-like structs.
+    ///
+    /// The impl is blanket over the source type, so it conflicts with any other
+    /// `From` impl for the struct, and with `impl From<Struct> for Inner`, because
+    /// then the struct itself would satisfy the `Into<Inner>` bound, which
+    /// overlaps with the `impl<T> From<T> for T` from `core`.
+    pub(crate) fn blanket_from(&self) -> Result<Option<TokenStream2>> {
+        if !self.blanket_from.is_present() {
+            return Ok(None);
+        }
+
+        let mut members = self
+            .members
+            .iter()
+            .filter(|member| !matches!(member, Member::Skipped(_)));
+
+        let member = match (members.next(), members.next()) {
+            (Some(Member::Named(member)), None) => member,
+            (_, Some(member)) => bail!(
+                member.orig_ident(),
+                "`blanket_from` can be used only with the structs that have a single \
+                member with a setter, but this struct has more of them"
+            ),
+            (Some(member), None) => bail!(
+                member.orig_ident(),
+                "`blanket_from` can't be used with the `start_fn` and `finish_fn` members"
+            ),
+            (None, None) => bail!(
+                &self.start_func.ident,
+                "`blanket_from` can be used only with the structs that have a single \
+                member with a setter, but this struct has none"
+            ),
+        };
+
+        let params = &member.params;
+        let unsupported = [
+            params.checked.as_ref().map(|_| "checked"),
+            params.bitor.is_present().then(|| "bitor"),
+            params.collection.is_present().then(|| "collection"),
+        ];
+
+        if let Some(name) = unsupported.into_iter().flatten().next() {
+            bail!(
+                &member.orig_ident,
+                "`blanket_from` can't be used with `{name}` members, because the \
+                `From` conversion must accept the value as is"
+            );
+        }
+
+        if !member.param_into(&self.on_params)? {
+            bail!(
+                &member.orig_ident,
+                "`blanket_from` requires the setter of the member to accept `impl Into`; \
+                add `#[builder(into)]` to this member"
+            );
+        }
+
+        let inner_type = member.as_optional_norm_ty().unwrap_or(&member.norm_ty);
+
+        let value = if member.norm_ty.is_option() {
+            quote!(::core::option::Option::Some(value))
+        } else {
+            quote!(value)
+        };
+
+        let skipped_members_vars_decls = self
+            .members_in_init_order()?
+            .into_iter()
+            .filter(|member| matches!(member, Member::Skipped(_)))
+            .map(|member| {
+                let expr = self.member_expr(member)?;
+                let var_ident = member.orig_ident();
+                let ty = member.norm_ty();
+
+                Ok(quote! {
+                    let #var_ident: #ty = #expr;
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let output = match &self.finish_func.output {
+            syn::ReturnType::Type(_, ty) => ty,
+            syn::ReturnType::Default => bail!(
+                &self.finish_func.ident,
+                "`blanket_from` requires the finishing function to return a value"
+            ),
+        };
+
+        let source_var = self
+            .generics
+            .unique_ident(&quote::format_ident!("__Value"), &[]);
+
+        let generics_decl = &self.generics.decl_without_defaults;
+        let where_predicates = self
+            .generics
+            .where_clause_predicates()
+            .chain(&self.finish_func.where_predicates);
+        let member_var = &member.orig_ident;
+        let member_ty = &member.norm_ty;
+        let body = self.finish_func.body.generate(&self.members);
+
+        Ok(Some(quote! {
+            #[automatically_derived]
+            impl<
+                #(#generics_decl,)*
+                #source_var,
+            >
+            ::core::convert::From<#source_var> for #output
+            where
+                #source_var: ::core::convert::Into<#inner_type>,
+                #(#where_predicates,)*
+            {
+                #[inline(always)]
+                #[allow(
+                    // This is intentional. We want the conversion to compile away
+                    clippy::inline_always,
+                    // We normalize `Self` references intentionally to simplify code generation
+                    clippy::use_self,
+                )]
+                fn from(value: #source_var) -> Self {
+                    let value: #inner_type = ::core::convert::Into::into(value);
+                    let #member_var: #member_ty = #value;
+                    #(#skipped_members_vars_decls)*
+                    #body
+                }
+            }
+        }))
+    }
+}
@@ -0,0 +1,50 @@
+use super::BuilderGenCtx;
+use crate::util::prelude::*;
+use quote::quote;
+
+impl BuilderGenCtx {
+    /// The receiver of the finishing function. It's `&self` with
+    /// `#[builder(build_by_ref)]`, so the builder stays intact and may be
+    /// finished again, which is handy for the builders reused as templates.
+    pub(crate) fn finish_fn_receiver(&self) -> TokenStream2 {
+        if self.build_by_ref.is_present() {
+            quote!(&self)
+        } else {
+            quote!(self)
+        }
+    }
+
+    /// The expression that takes the value of the given field of the builder
+    /// in the finishing function. With `#[builder(build_by_ref)]` the value is
+    /// cloned, because the finishing function only borrows the builder.
+    pub(crate) fn finish_fn_take_field(&self, field: TokenStream2) -> TokenStream2 {
+        if self.build_by_ref.is_present() {
+            quote!(::core::clone::Clone::clone(&#field))
+        } else {
+            field
+        }
+    }
+
+    /// The bounds that require the values stored in the builder to implement
+    /// `Clone` with `#[builder(build_by_ref)]`. The state of every member is
+    /// bounded instead of the member's type directly, so that the unset
+    /// members don't require `Clone`. The methods that delegate to the
+    /// finishing function must include these bounds as well.
+    pub(crate) fn build_by_ref_bounds(&self) -> Vec<TokenStream2> {
+        if !self.build_by_ref.is_present() {
+            return vec![];
+        }
+
+        let named_members = self.named_members().map(|member| {
+            let member_type_var = &member.generic_var_ident;
+            quote!(#member_type_var: ::core::clone::Clone)
+        });
+
+        let start_fn_args = self.start_fn_args().map(|member| {
+            let ty = &member.base.norm_ty;
+            quote!(#ty: ::core::clone::Clone)
+        });
+
+        named_members.chain(start_fn_args).collect()
+    }
+}
@@ -5,8 +5,31 @@ use crate::util::prelude::*;
 use quote::quote;
 
 impl BuilderGenCtx {
-    pub(crate) fn builder_derives(&self) -> TokenStream2 {
-        let BuilderDerives { clone, debug } = &self.builder_type.derives;
+    pub(crate) fn builder_derives(&self) -> Result<TokenStream2> {
+        let BuilderDerives {
+            clone,
+            debug,
+            partial_eq,
+            eq,
+            hash,
+        } = &self.builder_type.derives;
+
+        let test_only_debug = &self.builder_type.test_only_debug;
+
+        if eq.is_present() && !partial_eq.is_present() {
+            bail!(
+                &eq.span(),
+                "`derive(Eq)` requires `derive(PartialEq)` to be specified as well",
+            );
+        }
+
+        if test_only_debug.is_present() && debug.is_present() {
+            bail!(
+                &test_only_debug.span(),
+                "`test_only_debug` can't be combined with `derive(Debug)`; the former \
+                already derives `Debug` for the builder, but only under `cfg(test)`",
+            );
+        }
 
         let mut tokens = TokenStream2::new();
 
@@ -18,7 +41,27 @@ impl BuilderGenCtx {
             tokens.extend(self.derive_debug());
         }
 
-        tokens
+        if partial_eq.is_present() {
+            tokens.extend(self.derive_partial_eq());
+        }
+
+        if eq.is_present() {
+            tokens.extend(self.derive_for_members(&quote!(::core::cmp::Eq), None));
+        }
+
+        if hash.is_present() {
+            tokens.extend(self.derive_hash());
+        }
+
+        if test_only_debug.is_present() {
+            let derive_debug = self.derive_debug();
+            tokens.extend(quote! {
+                #[cfg(test)]
+                #derive_debug
+            });
+        }
+
+        Ok(tokens)
     }
 
     fn builder_component_types(&self) -> impl Iterator<Item = &'_ syn::Type> {
@@ -53,23 +96,30 @@ impl BuilderGenCtx {
             }
         });
 
+        let clone_locations = self.locations_field_type().map(|_| {
+            quote! {
+                __private_locations: self.__private_locations,
+            }
+        });
+
         let builder_where_clause_predicates = self.generics.where_clause_predicates();
 
         let builder_component_types = self.builder_component_types();
+        let state_var = &self.generics.state_var;
 
         quote! {
             #[automatically_derived]
             impl <
                 #(#generics_decl,)*
-                ___State
+                #state_var
             >
             #clone for #builder_ident <
                 #(#generic_args,)*
-                ___State
+                #state_var
             >
             where
                 #(#builder_where_clause_predicates,)*
-                ___State: #clone,
+                #state_var: #clone,
             {
                 fn clone(&self) -> Self {
                     #(::bon::private::assert_clone::<#builder_component_types>();)*
@@ -77,6 +127,7 @@ impl BuilderGenCtx {
                         __private_phantom: ::core::marker::PhantomData,
                         #clone_receiver
                         #clone_start_fn_args
+                        #clone_locations
                         __private_named_members: self.__private_named_members.clone(),
                     }
                 }
@@ -103,7 +154,8 @@ impl BuilderGenCtx {
         let builder_ident_str = builder_ident.to_string();
 
         let state_type_vars = self
-            .named_members()
+            .state_members()
+            .into_iter()
             .map(|member| &member.generic_var_ident)
             .collect::<Vec<_>>();
 
@@ -167,4 +219,108 @@ impl BuilderGenCtx {
             }
         }
     }
+
+    /// Compares the receiver, the `start_fn` members and the named members one
+    /// by one. Unset named members are always equal to each other.
+    fn derive_partial_eq(&self) -> TokenStream2 {
+        let fields = self.compared_fields();
+
+        let body = quote! {
+            fn eq(&self, other: &Self) -> bool {
+                true #( && self.#fields == other.#fields )*
+            }
+        };
+
+        self.derive_for_members(&quote!(::core::cmp::PartialEq), Some(&body))
+    }
+
+    /// Hashes the same fields that are compared in the `PartialEq` impl. Unset
+    /// named members don't contribute to the hash, so the hash stays consistent
+    /// with `PartialEq`.
+    fn derive_hash(&self) -> TokenStream2 {
+        let fields = self.compared_fields();
+        let state_type_vars: Vec<_> = self
+            .named_members()
+            .map(|member| &member.generic_var_ident)
+            .collect();
+
+        let hasher = self
+            .generics
+            .unique_ident(&quote::format_ident!("__H"), &state_type_vars);
+
+        let body = quote! {
+            fn hash<#hasher: ::core::hash::Hasher>(&self, state: &mut #hasher) {
+                #( ::core::hash::Hash::hash(&self.#fields, state); )*
+            }
+        };
+
+        self.derive_for_members(&quote!(::core::hash::Hash), Some(&body))
+    }
+
+    /// Paths to the fields of the builder that hold the values of the members.
+    /// They are listed one by one instead of using the tuples as a whole,
+    /// because the traits from `std` are implemented only for short tuples.
+    fn compared_fields(&self) -> Vec<TokenStream2> {
+        let receiver = self.receiver().map(|_| quote!(__private_receiver));
+
+        let members = self.members.iter().filter_map(|member| match member {
+            Member::Named(member) => {
+                let index = &member.index;
+                Some(quote!(__private_named_members.#index))
+            }
+            Member::StartFnArg(member) => {
+                let index = &member.index;
+                Some(quote!(__private_start_fn_args.#index))
+            }
+            Member::FinishFnArg(_) | Member::Skipped(_) => None,
+        });
+
+        receiver.into_iter().chain(members).collect()
+    }
+
+    /// Implements the trait for the builder in every state, where the types of
+    /// all the compared fields implement the trait.
+    fn derive_for_members(
+        &self,
+        trait_path: &TokenStream2,
+        body: Option<&TokenStream2>,
+    ) -> TokenStream2 {
+        let generics_decl = &self.generics.decl_without_defaults;
+        let generic_args = &self.generics.args;
+        let builder_ident = &self.builder_type.ident;
+        let builder_where_clause_predicates = self.generics.where_clause_predicates();
+
+        let state_type_vars = self
+            .state_members()
+            .into_iter()
+            .map(|member| &member.generic_var_ident)
+            .collect::<Vec<_>>();
+
+        let receiver_ty = self
+            .receiver()
+            .map(|receiver| &receiver.without_self_keyword);
+
+        let start_fn_arg_types = self.start_fn_args().map(|member| &member.base.norm_ty);
+
+        let positional_types = receiver_ty.into_iter().chain(start_fn_arg_types);
+
+        quote! {
+            #[automatically_derived]
+            impl <
+                #(#generics_decl,)*
+                #(#state_type_vars,)*
+            >
+            #trait_path for #builder_ident <
+                #(#generic_args,)*
+                (#(#state_type_vars,)*)
+            >
+            where
+                #(#builder_where_clause_predicates,)*
+                #(#positional_types: #trait_path,)*
+                #(#state_type_vars: #trait_path,)*
+            {
+                #body
+            }
+        }
+    }
 }
@@ -0,0 +1,112 @@
+use super::{BuilderGenCtx, Member, NamedMember};
+use crate::util::prelude::*;
+use quote::quote;
+
+impl BuilderGenCtx {
+    /// Generates the `macro_rules!` macro requested with `#[builder(macro = name)]`
+    /// that builds the struct from the `member: value` pairs. It's a shortcut
+    /// for calling the setters and the finishing function, which is handy in
+    /// tests that construct a lot of values.
+    ///
+    /// The macro munches the pairs one by one, so it reports the unknown member
+    /// names with a clear error at the macro's expansion instead of the error
+    /// about the missing setter method.
+    pub(crate) fn builder_macro(&self) -> Result<Option<TokenStream2>> {
+        let macro_ident = match &self.builder_macro {
+            Some(ident) => ident,
+            None => return Ok(None),
+        };
+
+        if let Some(member) = self
+            .members
+            .iter()
+            .find(|member| matches!(member, Member::StartFnArg(_) | Member::FinishFnArg(_)))
+        {
+            bail!(
+                member.orig_ident(),
+                "`macro` can't be used with the `start_fn` and `finish_fn` members, \
+                because the macro accepts only the members with setters"
+            );
+        }
+
+        if let Some(member) = self
+            .named_members()
+            .find(|member| member.params.checked.is_some() || member.params.item_validate.is_some())
+        {
+            bail!(
+                &member.orig_ident,
+                "`macro` can't be used with the members that have fallible setters"
+            );
+        }
+
+        if let Some(unsafety) = &self.finish_func.unsafety {
+            bail!(
+                unsafety,
+                "`macro` can't be used with an `unsafe` finishing function"
+            );
+        }
+
+        let start_fn_owner = if let Some(on) = self.start_func_on() {
+            quote!(<#on>)
+        } else {
+            let mut path = self
+                .assoc_method_ctx
+                .as_ref()
+                .and_then(|ctx| ctx.self_ty.as_path())
+                .expect("BUG: the struct builder must have the struct's type path")
+                .path
+                .clone();
+
+            // The generic arguments of the struct are inferred
+            if let Some(segment) = path.segments.last_mut() {
+                segment.arguments = syn::PathArguments::None;
+            }
+
+            quote!(#path)
+        };
+
+        let setters: Vec<_> = self
+            .named_members()
+            .map(NamedMember::setter_method_core_name)
+            .collect();
+
+        let expected = setters
+            .iter()
+            .map(|setter| format!("`{}`", setter.raw_name()))
+            .join(", ");
+
+        let unknown_prefix = "unknown member `";
+        let unknown_suffix = format!("` in `{macro_ident}!`; expected one of: {expected}");
+
+        let start_fn_ident = &self.start_func.ident;
+        let finish_fn_ident = &self.finish_func.ident;
+
+        let rules = setters.iter().map(|setter| {
+            quote! {
+                (@set $builder:expr; #setter : $value:expr $(, $($rest:tt)*)?) => {
+                    #macro_ident!(@set $builder.#setter($value); $($($rest)*)?)
+                };
+            }
+        });
+
+        Ok(Some(quote! {
+            #[allow(unused_macros)]
+            macro_rules! #macro_ident {
+                (@set $builder:expr;) => {
+                    $builder.#finish_fn_ident()
+                };
+                #(#rules)*
+                (@set $builder:expr; $member:ident $($rest:tt)*) => {
+                    ::core::compile_error!(::core::concat!(
+                        #unknown_prefix,
+                        ::core::stringify!($member),
+                        #unknown_suffix
+                    ))
+                };
+                ($($members:tt)*) => {
+                    #macro_ident!(@set #start_fn_owner::#start_fn_ident(); $($members)*)
+                };
+            }
+        }))
+    }
+}
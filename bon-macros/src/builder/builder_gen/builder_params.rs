@@ -9,7 +9,8 @@ use syn::visit::Visit;
 fn parse_finish_fn(meta: &syn::Meta) -> Result<ItemParams> {
     ItemParamsParsing {
         meta,
-        allow_vis: false,
+        allow_vis: true,
+        allow_on: false,
         reject_self_mentions: Some("builder struct's impl block"),
     }
     .parse()
@@ -19,6 +20,7 @@ fn parse_builder_type(meta: &syn::Meta) -> Result<ItemParams> {
     ItemParamsParsing {
         meta,
         allow_vis: false,
+        allow_on: false,
         reject_self_mentions: Some("builder struct"),
     }
     .parse()
@@ -38,6 +40,71 @@ pub(crate) struct BuilderParams {
     /// Specifies the derives to apply to the builder.
     #[darling(default)]
     pub(crate) derive: BuilderDerives,
+
+    /// Generates an `as_set_fields()` method on the builder that returns
+    /// references to the members that are set in the current state
+    pub(crate) as_set_fields: darling::util::Flag,
+
+    /// Emits a `defmt::trace!` call in every setter when the `defmt` feature
+    /// of `bon` is enabled
+    pub(crate) defmt_trace: darling::util::Flag,
+
+    /// Records the location of the last setter call for every member and
+    /// generates a `field_location()` method on the builder that returns it
+    pub(crate) capture_locations: darling::util::Flag,
+
+    /// Generates additional `&mut self` setters for the optional members that
+    /// overwrite their values without changing the state of the builder
+    pub(crate) mut_setters: darling::util::Flag,
+
+    /// Generates the `reset_{member}()` methods for the required members that
+    /// revert their state to unset
+    pub(crate) resettable: darling::util::Flag,
+
+    /// Appends the list of setters to the docs of the start function
+    pub(crate) doc_setters: darling::util::Flag,
+
+    /// Generates a `json_schema()` function on the builder that describes
+    /// its members. Requires the `json-schema` feature of `bon`
+    pub(crate) json_schema: darling::util::Flag,
+
+    /// Generates a `REQUIRED_FIELDS` associated constant on the builder with
+    /// the names of the required members
+    pub(crate) required_fields: darling::util::Flag,
+
+    /// Additional `where` predicates required only by the finishing function
+    #[darling(default, with = parse_finish_where)]
+    pub(crate) finish_where: Vec<syn::WherePredicate>,
+
+    /// Generates an additional start function that returns the builder with
+    /// all optional members already set to their default values
+    pub(crate) start_with_defaults: darling::util::Flag,
+
+    /// Name of the trait to generate with the signatures of all setters
+    pub(crate) setter_trait: Option<syn::Ident>,
+
+    /// Derives `Debug` for the builder only under `cfg(test)`
+    pub(crate) test_only_debug: darling::util::Flag,
+}
+
+fn parse_finish_where(meta: &syn::Meta) -> Result<Vec<syn::WherePredicate>> {
+    let meta = match meta {
+        syn::Meta::List(meta) => meta,
+        _ => bail!(meta, "expected `finish_where(predicates...)` syntax"),
+    };
+
+    let predicates = meta.parse_args_with(
+        syn::punctuated::Punctuated::<syn::WherePredicate, syn::Token![,]>::parse_terminated,
+    )?;
+
+    if predicates.is_empty() {
+        bail!(
+            meta,
+            "expected at least one `where` predicate in `finish_where(...)`"
+        );
+    }
+
+    Ok(predicates.into_iter().collect())
 }
 
 #[derive(Debug, Clone, Default, FromMeta)]
@@ -47,6 +114,15 @@ pub(crate) struct BuilderDerives {
 
     #[darling(rename = "Debug")]
     pub(crate) debug: darling::util::Flag,
+
+    #[darling(rename = "PartialEq")]
+    pub(crate) partial_eq: darling::util::Flag,
+
+    #[darling(rename = "Eq")]
+    pub(crate) eq: darling::util::Flag,
+
+    #[darling(rename = "Hash")]
+    pub(crate) hash: darling::util::Flag,
 }
 
 #[derive(Debug)]
@@ -55,6 +131,14 @@ pub(crate) struct OnParams {
     pub(crate) into: darling::util::Flag,
 }
 
+impl OnParams {
+    /// Whether the rules configure different options, so they can't both
+    /// apply to the same member
+    pub(crate) fn conflicts_with(&self, other: &Self) -> bool {
+        self.into.is_present() != other.into.is_present()
+    }
+}
+
 impl Parse for OnParams {
     fn parse(input: syn::parse::ParseStream<'_>) -> syn::Result<Self> {
         let type_pattern = input.parse()?;
@@ -135,11 +219,15 @@ pub(crate) struct ItemParams {
     pub(crate) name: Option<syn::Ident>,
     pub(crate) vis: Option<syn::Visibility>,
     pub(crate) docs: Option<Vec<syn::Attribute>>,
+
+    /// Type to place the item on instead of the default one
+    pub(crate) on: Option<syn::Type>,
 }
 
 pub(crate) struct ItemParamsParsing<'a> {
     pub(crate) meta: &'a syn::Meta,
     pub(crate) allow_vis: bool,
+    pub(crate) allow_on: bool,
     pub(crate) reject_self_mentions: Option<&'static str>,
 }
 
@@ -153,6 +241,12 @@ impl ItemParamsParsing<'_> {
             }
         }
 
+        if !self.allow_on {
+            if let Some(on) = &params.on {
+                bail!(on, "the type to place this item on can't be overridden");
+            }
+        }
+
         if let Some(context) = self.reject_self_mentions {
             if let Some(docs) = &params.docs {
                 super::reject_self_mentions_in_docs(context, docs)?;
@@ -171,6 +265,7 @@ impl ItemParamsParsing<'_> {
                 name: Some(name),
                 vis: None,
                 docs: None,
+                on: None,
             });
         }
 
@@ -179,6 +274,9 @@ impl ItemParamsParsing<'_> {
             name: Option<syn::Ident>,
             vis: Option<syn::Visibility>,
             docs: Option<syn::Meta>,
+
+            #[darling(default, with = parse_type, map = Some)]
+            on: Option<syn::Type>,
         }
 
         let full = Full::from_meta(meta)?;
@@ -189,6 +287,7 @@ impl ItemParamsParsing<'_> {
                 name: None,
                 vis: None,
                 docs: None,
+                on: None,
             }
         );
 
@@ -216,8 +315,26 @@ impl ItemParamsParsing<'_> {
             name: full.name,
             vis: full.vis,
             docs,
+            on: full.on,
         };
 
         Ok(params)
     }
 }
+
+/// Accepts both a type written inline, e.g. `error = MyError` and the type
+/// written in a string literal, e.g. `error = "Box<dyn Error>"`. The latter
+/// is required for types that aren't valid expressions syntactically.
+pub(crate) fn parse_type(meta: &syn::Meta) -> Result<syn::Type> {
+    let value = &meta.require_name_value()?.value;
+
+    if let syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Str(str),
+        ..
+    }) = value
+    {
+        return Ok(str.parse()?);
+    }
+
+    Ok(syn::parse2(quote::quote!(#value))?)
+}
@@ -0,0 +1,95 @@
+use super::BuilderGenCtx;
+use crate::util::prelude::*;
+use quote::quote;
+
+pub(super) const METHOD_NAME: &str = "field_location";
+
+impl BuilderGenCtx {
+    /// The type of the builder's field that stores the locations of the setter
+    /// calls requested with `#[builder(capture_locations)]`. The array has an
+    /// element per named member in the order of the members' states.
+    pub(crate) fn locations_field_type(&self) -> Option<TokenStream2> {
+        if !self.builder_type.capture_locations.is_present() {
+            return None;
+        }
+
+        let len = self.named_members().count();
+
+        Some(quote! {
+            [
+                ::core::option::Option<&'static ::core::panic::Location<'static>>;
+                #len
+            ]
+        })
+    }
+
+    /// Initializer of the locations field for the builder in the initial state
+    pub(crate) fn locations_field_init(&self) -> Option<TokenStream2> {
+        if !self.builder_type.capture_locations.is_present() {
+            return None;
+        }
+
+        let len = self.named_members().count();
+
+        Some(quote! {
+            __private_locations: [::core::option::Option::None; #len],
+        })
+    }
+
+    /// Generates the `field_location()` method that returns the location of
+    /// the last setter call for the member with the given name. The setters
+    /// are `#[track_caller]`, so the location points to the user's code.
+    pub(crate) fn field_location(&self) -> Option<TokenStream2> {
+        if !self.builder_type.capture_locations.is_present() {
+            return None;
+        }
+
+        let arms = self.state_members().into_iter().map(|member| {
+            let name = member.norm_ident.raw_name();
+            let index = member.index.index as usize;
+            quote!(#name => #index)
+        });
+
+        let generics_decl = &self.generics.decl_without_defaults;
+        let generic_args = &self.generics.args;
+        let where_clause = &self.generics.where_clause;
+        let builder_ident = &self.builder_type.ident;
+        let state_var = &self.generics.state_var;
+        let vis = &self.vis;
+        let method_ident = syn::Ident::new(METHOD_NAME, Span::call_site());
+
+        Some(quote! {
+            #[automatically_derived]
+            impl<
+                #(#generics_decl,)*
+                #state_var
+            >
+            #builder_ident<
+                #(#generic_args,)*
+                #state_var
+            >
+            #where_clause
+            {
+                /// Returns the location of the last setter call for the member
+                /// with the given name. Returns `None` if the member wasn't set
+                /// yet, or if there is no member with such name.
+                #[allow(
+                    // Let's keep it as non-const for now to avoid restricting ourselves to only
+                    // const operations.
+                    clippy::missing_const_for_fn,
+                )]
+                #vis fn #method_ident(
+                    &self,
+                    name: &str,
+                ) -> ::core::option::Option<&'static ::core::panic::Location<'static>> {
+                    let index: usize = match name {
+                        #(#arms,)*
+                        _ => return ::core::option::Option::None,
+                    };
+
+                    self.__private_locations[index]
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,55 @@
+use super::{BuilderGenCtx, Member};
+use crate::util::prelude::*;
+use quote::quote;
+
+impl BuilderGenCtx {
+    /// Generates the associated constants of the struct for the members with
+    /// `#[builder(const_field = ...)]`. The finishing function initializes
+    /// such members with these constants, so their values are defined in a
+    /// single place.
+    pub(crate) fn const_fields(&self) -> Option<TokenStream2> {
+        let consts: Vec<_> = self
+            .members
+            .iter()
+            .filter_map(|member| match member {
+                Member::Skipped(member) => {
+                    let const_ident = member.const_ident.as_ref()?;
+                    let ty = &member.norm_ty;
+                    let value = member.value.as_ref().as_ref()?;
+                    let field = &member.ident;
+                    let vis = &self.vis;
+
+                    let docs = format!("The value of the constant field `{field}`");
+
+                    Some(quote! {
+                        #[doc = #docs]
+                        #vis const #const_ident: #ty = #value;
+                    })
+                }
+                _ => None,
+            })
+            .collect();
+
+        if consts.is_empty() {
+            return None;
+        }
+
+        let self_ty = &self
+            .assoc_method_ctx
+            .as_ref()
+            .expect("BUG: `const_field` members exist only in structs")
+            .self_ty;
+
+        let generics_decl = &self.generics.decl_without_defaults;
+        let where_clause = &self.generics.where_clause;
+
+        Some(quote! {
+            #[automatically_derived]
+            impl<#(#generics_decl,)*> #self_ty
+            #where_clause
+            {
+                #(#consts)*
+            }
+        })
+    }
+}
@@ -0,0 +1,239 @@
+use super::{BuilderGenCtx, Member};
+use crate::util::prelude::*;
+use quote::quote;
+use syn::visit::Visit;
+
+impl BuilderGenCtx {
+    /// Generates the methods requested with `#[builder(delegate(...))]`. Every
+    /// method finishes building and calls the method with the same name on the
+    /// built value.
+    ///
+    /// The builder doesn't have the built value until the finishing function is
+    /// called, so these methods consume the builder. This means they can return
+    /// only the values that don't borrow from the built value. Implementing `Deref`
+    /// for the builder instead would require building eagerly in every state
+    /// where all required members are set, which defeats the laziness of the
+    /// `skip` and `default` expressions.
+    pub(crate) fn delegate(&self) -> Result<Option<TokenStream2>> {
+        if self.delegate.is_empty() {
+            return Ok(None);
+        }
+
+        if let Some(member) = self.members.iter().find_map(Member::as_finish_fn_arg) {
+            bail!(
+                &member.ident,
+                "`delegate` methods can't be generated for the builder with \
+                `#[builder(finish_fn)]` members, because the methods don't accept \
+                the arguments of the finishing function"
+            );
+        }
+
+        let methods = self
+            .delegate
+            .iter()
+            .map(|sig| self.delegate_method(sig))
+            .collect::<Result<Vec<_>>>()?;
+
+        let generics_decl = &self.generics.decl_without_defaults;
+        let generic_args = &self.generics.args;
+        let where_clause = &self.generics.where_clause;
+        let builder_ident = &self.builder_type.ident;
+
+        let state_type_vars = self
+            .state_members()
+            .into_iter()
+            .map(|member| &member.generic_var_ident)
+            .collect::<Vec<_>>();
+
+        let allows = super::allow_warnings_on_member_types();
+
+        Ok(Some(quote! {
+            #allows
+            #[automatically_derived]
+            impl<
+                #(#generics_decl,)*
+                #(#state_type_vars,)*
+            >
+            #builder_ident<
+                #(#generic_args,)*
+                (#(#state_type_vars,)*)
+            >
+            #where_clause
+            {
+                #(#methods)*
+            }
+        }))
+    }
+
+    fn delegate_method(&self, sig: &syn::Signature) -> Result<TokenStream2> {
+        validate_delegate_signature(sig)?;
+
+        let mut inputs = sig.inputs.iter();
+
+        match inputs.next() {
+            Some(syn::FnArg::Receiver(receiver)) if receiver.colon_token.is_none() => {
+                validate_delegate_output(receiver, &sig.output)?;
+            }
+            _ => bail!(
+                sig,
+                "expected the method to have a `self`, `&self` or `&mut self` receiver"
+            ),
+        }
+
+        let params = inputs
+            .map(|input| match input {
+                syn::FnArg::Typed(typed) => match typed.pat.as_ref() {
+                    syn::Pat::Ident(pat) if pat.by_ref.is_none() && pat.subpat.is_none() => {
+                        Ok((&pat.ident, &typed.ty))
+                    }
+                    _ => bail!(&typed.pat, "expected a simple identifier pattern"),
+                },
+                syn::FnArg::Receiver(receiver) => bail!(receiver, "unexpected receiver"),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let params_decls = params.iter().map(|(ident, ty)| quote!(#ident: #ty));
+        let args = params.iter().map(|(ident, _)| ident);
+
+        let where_bounds = self.named_members().map(|member| {
+            let member_type_var = &member.generic_var_ident;
+            let into_set_trait = self.member_into_set_trait(member);
+            quote! {
+                #member_type_var: #into_set_trait
+            }
+        });
+
+        let method_ident = &sig.ident;
+        let method_generics = &sig.generics.params;
+        let method_where_predicates = sig
+            .generics
+            .where_clause
+            .iter()
+            .flat_map(|clause| &clause.predicates);
+
+        let output = &sig.output;
+        let unsafety = &sig.unsafety;
+        let finish_func_ident = &self.finish_func.ident;
+        let finish_where_predicates = &self.finish_func.where_predicates;
+        let one_of_bounds = self.one_of_finish_bounds();
+        let by_ref_bounds = self.build_by_ref_bounds();
+        let vis = self.finish_func.vis.as_ref().unwrap_or(&self.vis);
+
+        let method_call = quote!(self.#finish_func_ident().#method_ident(#(#args),*));
+        let body = if unsafety.is_some() {
+            quote!(unsafe { #method_call })
+        } else {
+            method_call
+        };
+
+        let docs = format!(
+            "Finishes building with [`Self::{finish_func_ident}()`] and calls \
+            `{}()` on the built value",
+            method_ident.raw_name()
+        );
+
+        Ok(quote! {
+            #[doc = #docs]
+            #[inline(always)]
+            #[allow(
+                // This is intentional. We want the builder syntax to compile away
+                clippy::inline_always,
+                // The safety docs are on the original method
+                clippy::missing_safety_doc,
+            )]
+            #vis #unsafety fn #method_ident<#method_generics>(
+                self,
+                #(#params_decls,)*
+            ) #output
+            where
+                #(#where_bounds,)*
+                #(#by_ref_bounds,)*
+                #(#finish_where_predicates,)*
+                #(#one_of_bounds)*
+                #(#method_where_predicates,)*
+            {
+                #body
+            }
+        })
+    }
+}
+
+fn validate_delegate_signature(sig: &syn::Signature) -> Result {
+    let unsupported = [
+        (sig.constness.map(|token| token.span), "const"),
+        (sig.asyncness.map(|token| token.span), "async"),
+        (sig.abi.as_ref().map(|abi| abi.extern_token.span), "extern"),
+        (
+            sig.variadic.as_ref().map(|variadic| variadic.dots.spans[0]),
+            "variadic",
+        ),
+    ];
+
+    for (span, name) in unsupported {
+        if let Some(span) = span {
+            bail!(&span, "{name} methods are not supported in `delegate(...)`");
+        }
+    }
+
+    Ok(())
+}
+
+/// The built value is a temporary that is dropped at the end of the generated
+/// method, so the method can't return a borrow of it. This is a best-effort
+/// check that looks for the references with elided lifetimes or with the
+/// lifetime of the receiver. The lifetimes hidden in the paths, like in
+/// `Iter<T>`, are reported by the compiler instead.
+fn validate_delegate_output(receiver: &syn::Receiver, output: &syn::ReturnType) -> Result {
+    let receiver_lifetime = match &receiver.reference {
+        Some((_, lifetime)) => lifetime.as_ref(),
+        None => return Ok(()),
+    };
+
+    let ty = match output {
+        syn::ReturnType::Type(_, ty) => ty,
+        syn::ReturnType::Default => return Ok(()),
+    };
+
+    let mut visitor = FindSelfBorrow {
+        receiver_lifetime,
+        found: false,
+    };
+
+    visitor.visit_type(ty);
+
+    if visitor.found {
+        bail!(
+            ty,
+            "`delegate` methods can't return borrows of `self`, because the \
+            built value is dropped at the end of the method; consider returning \
+            an owned value instead"
+        );
+    }
+
+    Ok(())
+}
+
+struct FindSelfBorrow<'a> {
+    receiver_lifetime: Option<&'a syn::Lifetime>,
+    found: bool,
+}
+
+impl Visit<'_> for FindSelfBorrow<'_> {
+    fn visit_type_reference(&mut self, reference: &syn::TypeReference) {
+        if reference.lifetime.is_none() {
+            self.found = true;
+        }
+        syn::visit::visit_type_reference(self, reference);
+    }
+
+    // The elided lifetimes in these types are higher-ranked, so they don't
+    // refer to the receiver
+    fn visit_type_bare_fn(&mut self, _: &syn::TypeBareFn) {}
+    fn visit_parenthesized_generic_arguments(&mut self, _: &syn::ParenthesizedGenericArguments) {}
+
+    fn visit_lifetime(&mut self, lifetime: &syn::Lifetime) {
+        if lifetime.ident == "_" || Some(lifetime) == self.receiver_lifetime {
+            self.found = true;
+        }
+    }
+}
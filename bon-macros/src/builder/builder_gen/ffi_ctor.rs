@@ -0,0 +1,186 @@
+use super::{BuilderGenCtx, Member};
+use crate::util::prelude::*;
+use quote::quote;
+
+impl BuilderGenCtx {
+    /// Generates a plain `extern "C"` function that accepts all members as
+    /// positional parameters and constructs the target value. This is meant
+    /// for C consumers that can't use the builder syntax.
+    pub(crate) fn ffi_ctor(&self) -> Result<Option<TokenStream2>> {
+        let ident = match &self.ffi_ctor {
+            Some(ident) => ident,
+            None => return Ok(None),
+        };
+
+        let generic_params = self
+            .generics
+            .decl_without_defaults
+            .iter()
+            .find(|param| !matches!(param, syn::GenericParam::Lifetime(_)));
+
+        if let Some(param) = generic_params {
+            bail!(
+                param,
+                "`ffi_ctor` can't be generated for a struct with type or const generic \
+                parameters, because `extern \"C\"` functions exported with `#[no_mangle]` \
+                can't be generic"
+            );
+        }
+
+        let params = self
+            .members
+            .iter()
+            .filter(|member| !matches!(member, Member::Skipped(_)))
+            .map(|member| {
+                let ty = member.norm_ty();
+
+                if let Some(unsafe_ty) = find_ffi_unsafe_ty(ty) {
+                    bail!(
+                        unsafe_ty,
+                        "this type is not FFI-safe, so `ffi_ctor` can't accept it as \
+                        a parameter; consider using `#[builder(skip)]` for this member \
+                        or a `#[repr(C)]` type instead"
+                    );
+                }
+
+                let ident = member.orig_ident();
+
+                Ok(quote!(#ident: #ty))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let skipped_members_vars_decls = self
+            .members_in_init_order()?
+            .into_iter()
+            .filter(|member| matches!(member, Member::Skipped(_)))
+            .map(|member| {
+                let expr = self.member_expr(member)?;
+                let var_ident = member.orig_ident();
+                let ty = member.norm_ty();
+
+                Ok(quote! {
+                    let #var_ident: #ty = #expr;
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let generics_decl = &self.generics.decl_without_defaults;
+        let where_predicates = self
+            .generics
+            .where_clause_predicates()
+            .chain(&self.finish_func.where_predicates);
+        let output = &self.finish_func.output;
+        let body = self.finish_func.body.generate(&self.members);
+        let vis = &self.vis;
+
+        let docs = format!(
+            "FFI-compatible constructor that accepts all members as positional \
+            parameters. It's an equivalent of using the builder returned from \
+            `{}()` with all members set.",
+            self.start_func.ident
+        );
+
+        Ok(Some(quote! {
+            #[doc = #docs]
+            // The attribute has the span of the macro, so the edition of this crate
+            // applies to it. This way it compiles in the crates with edition 2024
+            // without the `unsafe(...)` wrapper, which requires Rust 1.82
+            #[no_mangle]
+            #[inline(always)]
+            #[allow(
+                // This is intentional. We want the constructor to compile away
+                clippy::inline_always,
+                // The whole point of this function is to accept all members positionally
+                clippy::too_many_arguments,
+                clippy::fn_params_excessive_bools,
+                // We normalize `Self` references intentionally to simplify code generation
+                clippy::use_self,
+                // Let's keep it as non-const for now to avoid restricting ourselves to only
+                // const operations.
+                clippy::missing_const_for_fn,
+            )]
+            #vis extern "C" fn #ident<#(#generics_decl),*>(
+                #(#params,)*
+            ) #output
+            where
+                #(#where_predicates,)*
+            {
+                #(#skipped_members_vars_decls)*
+                #body
+            }
+        }))
+    }
+}
+
+/// This is a best-effort check for the types that are known not to be FFI-safe.
+/// User-defined types are assumed to be FFI-safe. The compiler's `improper_ctypes_definitions`
+/// lint is anyway going to complain about the ones that aren't.
+fn find_ffi_unsafe_ty(ty: &syn::Type) -> Option<&syn::Type> {
+    match ty {
+        syn::Type::Group(group) => find_ffi_unsafe_ty(&group.elem),
+        syn::Type::Paren(paren) => find_ffi_unsafe_ty(&paren.elem),
+
+        syn::Type::BareFn(bare_fn) if bare_fn.abi.is_none() => Some(ty),
+        syn::Type::BareFn(_) | syn::Type::Never(_) => None,
+
+        // Unit type `()` is fine, but other tuples have unspecified layout
+        syn::Type::Tuple(tuple) if tuple.elems.is_empty() => None,
+
+        syn::Type::Reference(syn::TypeReference { elem, .. })
+        | syn::Type::Ptr(syn::TypePtr { elem, .. }) => is_unsized_pointee(elem).then(|| ty),
+
+        syn::Type::Path(path) => {
+            let last_segment = path.path.segments.last()?;
+
+            let is_ffi_unsafe = FFI_UNSAFE_TYPES
+                .iter()
+                .any(|name| last_segment.ident == name);
+
+            if is_ffi_unsafe {
+                return Some(ty);
+            }
+
+            if last_segment.ident != "Option" {
+                return None;
+            }
+
+            // `Option<T>` is FFI-safe only if it's subject to the null pointer optimization
+            let inner = ty.option_type_param()?;
+
+            let has_niche = match inner {
+                syn::Type::Reference(_) => find_ffi_unsafe_ty(inner).is_none(),
+                syn::Type::BareFn(bare_fn) => bare_fn.abi.is_some(),
+                syn::Type::Path(path) => path
+                    .path
+                    .segments
+                    .last()
+                    .map(|segment| {
+                        segment.ident == "Box"
+                            || segment.ident == "NonNull"
+                            || segment.ident.to_string().starts_with("NonZero")
+                    })
+                    .unwrap_or(false),
+                _ => false,
+            };
+
+            (!has_niche).then(|| ty)
+        }
+
+        _ => Some(ty),
+    }
+}
+
+fn is_unsized_pointee(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Slice(_) | syn::Type::TraitObject(_) | syn::Type::ImplTrait(_) => true,
+        syn::Type::Paren(paren) => is_unsized_pointee(&paren.elem),
+        syn::Type::Group(group) => is_unsized_pointee(&group.elem),
+        syn::Type::Path(path) => path.path.is_ident("str"),
+        _ => false,
+    }
+}
+
+const FFI_UNSAFE_TYPES: &[&str] = &[
+    "String", "Vec", "VecDeque", "HashMap", "HashSet", "BTreeMap", "BTreeSet", "Rc", "Arc", "Cow",
+    "str", "char", "i128", "u128",
+];
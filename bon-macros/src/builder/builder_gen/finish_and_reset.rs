@@ -0,0 +1,139 @@
+use super::{BuilderGenCtx, Member};
+use crate::util::prelude::*;
+use quote::quote;
+
+impl BuilderGenCtx {
+    /// Generates a `{finish_fn}_and_reset()` method that finishes building and
+    /// also returns a new builder in the initial state. The new builder gets
+    /// the clones of the start function's arguments, so it's equivalent to the
+    /// builder returned from the start function called with the same arguments.
+    ///
+    /// The method delegates to the regular finishing function, so it's available
+    /// in the same states of the builder.
+    pub(crate) fn finish_and_reset(&self) -> Result<Option<TokenStream2>> {
+        if !self.finish_and_reset.is_present() {
+            return Ok(None);
+        }
+
+        let output = match &self.finish_func.output {
+            syn::ReturnType::Type(_, ty) => quote!(#ty),
+            syn::ReturnType::Default => quote!(()),
+        };
+
+        let finish_fn_members: Vec<_> = self
+            .members
+            .iter()
+            .filter_map(Member::as_finish_fn_arg)
+            .collect();
+
+        let finish_fn_params = finish_fn_members
+            .iter()
+            .map(|member| member.fn_input_param(&self.on_params))
+            .collect::<Result<Vec<_>>>()?;
+
+        let finish_fn_args = finish_fn_members.iter().map(|member| &member.ident);
+
+        let where_bounds = self
+            .named_members()
+            .map(|member| {
+                let member_type_var = &member.generic_var_ident;
+                let into_set_trait = self.member_into_set_trait(member);
+                quote! {
+                    #member_type_var: #into_set_trait
+                }
+            })
+            .chain(self.build_by_ref_bounds());
+
+        let start_fn_args_clone_bounds = self.start_fn_args().map(|member| {
+            let ty = &member.base.norm_ty;
+            quote!(#ty: ::core::clone::Clone)
+        });
+
+        let generics_decl = &self.generics.decl_without_defaults;
+        let generic_args = &self.generics.args;
+        let where_clause = &self.generics.where_clause;
+        let builder_ident = &self.builder_type.ident;
+        let finish_func_ident = &self.finish_func.ident;
+        let finish_where_predicates = &self.finish_func.where_predicates;
+        let one_of_bounds = self.one_of_finish_bounds();
+        let vis = self.finish_func.vis.as_ref().unwrap_or(&self.vis);
+        let reset = self.reset_builder();
+
+        let state_type_vars = self
+            .state_members()
+            .into_iter()
+            .map(|member| &member.generic_var_ident)
+            .collect::<Vec<_>>();
+
+        let method_ident = quote::format_ident!("{}_and_reset", finish_func_ident.raw_name());
+
+        let method_docs = format!(
+            "Same as [`Self::{finish_func_ident}()`], but also returns a new builder \
+            in the initial state, so that it can be reused to build another value"
+        );
+
+        let allows = super::allow_warnings_on_member_types();
+
+        Ok(Some(quote! {
+            #allows
+            #[automatically_derived]
+            impl<
+                #(#generics_decl,)*
+                #(#state_type_vars,)*
+            >
+            #builder_ident<
+                #(#generic_args,)*
+                (#(#state_type_vars,)*)
+            >
+            #where_clause
+            {
+                #[doc = #method_docs]
+                #[inline(always)]
+                #[allow(
+                    // This is intentional. We want the builder syntax to compile away
+                    clippy::inline_always,
+                )]
+                #[must_use = "building a value without using it is likely a bug"]
+                #vis fn #method_ident(
+                    self,
+                    #(#finish_fn_params,)*
+                ) -> (#output, #builder_ident<#(#generic_args,)*>)
+                where
+                    #(#where_bounds,)*
+                    #(#start_fn_args_clone_bounds,)*
+                    #(#finish_where_predicates,)*
+                    #(#one_of_bounds)*
+                {
+                    let reset = #reset;
+
+                    (self.#finish_func_ident(#(#finish_fn_args,)*), reset)
+                }
+            }
+        }))
+    }
+
+    /// The builder in the initial state that keeps the start function's
+    /// arguments of the builder that is being finished
+    fn reset_builder(&self) -> TokenStream2 {
+        let builder_ident = &self.builder_type.ident;
+        let state_literals = self.start_state_literals(false);
+        let locations_field_init = self.locations_field_init();
+
+        let start_fn_args_field_init = self.start_fn_args().next().map(|_| {
+            quote! {
+                __private_start_fn_args: ::core::clone::Clone::clone(
+                    &self.__private_start_fn_args
+                ),
+            }
+        });
+
+        quote! {
+            #builder_ident {
+                __private_phantom: ::core::marker::PhantomData,
+                #start_fn_args_field_init
+                #locations_field_init
+                __private_named_members: (#( #state_literals, )*)
+            }
+        }
+    }
+}
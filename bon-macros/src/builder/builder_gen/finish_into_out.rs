@@ -0,0 +1,170 @@
+use super::{BuilderGenCtx, Member};
+use crate::util::prelude::*;
+use quote::quote;
+
+impl BuilderGenCtx {
+    /// Generates a `{finish_fn}_into_out()` method that finishes building and
+    /// writes the built value into the provided `MaybeUninit` slot instead of
+    /// returning it. This is meant for FFI code that reports the results via
+    /// out-parameters.
+    ///
+    /// The method delegates to the regular finishing function, so it's available
+    /// in the same states of the builder.
+    pub(crate) fn finish_into_out(&self) -> Result<Option<TokenStream2>> {
+        if !self.finish_into_out.is_present() {
+            return Ok(None);
+        }
+
+        let finish_func_ident = &self.finish_func.ident;
+
+        let method_docs = format!(
+            "Same as [`Self::{finish_func_ident}()`], but writes the built value \
+            into the provided slot instead of returning it.\n\n\
+            The slot is fully initialized when this method returns, so it's safe \
+            to call [`MaybeUninit::assume_init()`](::core::mem::MaybeUninit::assume_init) \
+            on it afterwards. The method also returns a reference to the \
+            initialized value. If the slot was already initialized, the previous \
+            value is overwritten without being dropped."
+        );
+
+        self.finish_into_method(FinishIntoMethod {
+            attr: "finish_into_out",
+            suffix: "into_out",
+            param: "out",
+            param_ty: |output| quote!(&mut ::core::mem::MaybeUninit<#output>),
+            write_method: "write",
+            docs: method_docs,
+        })
+        .map(Some)
+    }
+
+    /// Generates the method that passes the value returned from the regular
+    /// finishing function into the slot provided as the last parameter.
+    /// The slot's write method must accept the value and return `&mut {output}`.
+    pub(super) fn finish_into_method(&self, method: FinishIntoMethod) -> Result<TokenStream2> {
+        let FinishIntoMethod {
+            attr,
+            suffix,
+            param,
+            param_ty,
+            write_method,
+            docs,
+        } = method;
+
+        let output = match &self.finish_func.output {
+            syn::ReturnType::Type(_, ty) => quote!(#ty),
+            syn::ReturnType::Default => quote!(()),
+        };
+
+        let finish_fn_members: Vec<_> = self
+            .members
+            .iter()
+            .filter_map(Member::as_finish_fn_arg)
+            .collect();
+
+        let method_ident = quote::format_ident!("{}_{suffix}", self.finish_func.ident.raw_name());
+
+        if let Some(member) = finish_fn_members
+            .iter()
+            .find(|member| member.ident == param)
+        {
+            bail!(
+                &member.ident,
+                "the name `{param}` is reserved for the parameter of the \
+                `{method_ident}()` method requested with `#[builder({attr})]`; \
+                consider renaming this member",
+            );
+        }
+
+        let finish_fn_params = finish_fn_members
+            .iter()
+            .map(|member| member.fn_input_param(&self.on_params))
+            .collect::<Result<Vec<_>>>()?;
+
+        let finish_fn_args = finish_fn_members.iter().map(|member| &member.ident);
+
+        let where_bounds = self.named_members().map(|member| {
+            let member_type_var = &member.generic_var_ident;
+            let into_set_trait = self.member_into_set_trait(member);
+            quote! {
+                #member_type_var: #into_set_trait
+            }
+        });
+
+        let generics_decl = &self.generics.decl_without_defaults;
+        let generic_args = &self.generics.args;
+        let where_clause = &self.generics.where_clause;
+        let builder_ident = &self.builder_type.ident;
+        let finish_func_ident = &self.finish_func.ident;
+        let finish_where_predicates = &self.finish_func.where_predicates;
+        let one_of_bounds = self.one_of_finish_bounds();
+        let by_ref_bounds = self.build_by_ref_bounds();
+        let vis = self.finish_func.vis.as_ref().unwrap_or(&self.vis);
+
+        let state_type_vars = self
+            .state_members()
+            .into_iter()
+            .map(|member| &member.generic_var_ident)
+            .collect::<Vec<_>>();
+
+        let allows = super::allow_warnings_on_member_types();
+
+        let param_ident = syn::Ident::new(param, Span::call_site());
+        let param_ty = param_ty(&output);
+        let write_method = syn::Ident::new(write_method, Span::call_site());
+
+        Ok(quote! {
+            #allows
+            #[automatically_derived]
+            impl<
+                #(#generics_decl,)*
+                #(#state_type_vars,)*
+            >
+            #builder_ident<
+                #(#generic_args,)*
+                (#(#state_type_vars,)*)
+            >
+            #where_clause
+            {
+                #[doc = #docs]
+                #[inline(always)]
+                #[allow(
+                    // This is intentional. We want the builder syntax to compile away
+                    clippy::inline_always,
+                )]
+                #vis fn #method_ident(
+                    self,
+                    #(#finish_fn_params,)*
+                    #param_ident: #param_ty,
+                ) -> &mut #output
+                where
+                    #(#where_bounds,)*
+                    #(#by_ref_bounds,)*
+                    #(#finish_where_predicates,)*
+                    #(#one_of_bounds)*
+                {
+                    #param_ident.#write_method(self.#finish_func_ident(#(#finish_fn_args,)*))
+                }
+            }
+        })
+    }
+}
+
+pub(super) struct FinishIntoMethod {
+    /// Name of the attribute that requested the method
+    pub(super) attr: &'static str,
+
+    /// Suffix appended to the name of the finishing function
+    pub(super) suffix: &'static str,
+
+    /// Name of the parameter that accepts the slot
+    pub(super) param: &'static str,
+
+    /// Type of the slot parameter given the output type
+    pub(super) param_ty: fn(&TokenStream2) -> TokenStream2,
+
+    /// Method of the slot that stores the value and returns a reference to it
+    pub(super) write_method: &'static str,
+
+    pub(super) docs: String,
+}
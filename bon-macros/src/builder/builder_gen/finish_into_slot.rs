@@ -0,0 +1,37 @@
+use super::finish_into_out::FinishIntoMethod;
+use super::BuilderGenCtx;
+use crate::util::prelude::*;
+use quote::quote;
+
+impl BuilderGenCtx {
+    /// Generates a `{finish_fn}_into_slot()` method that finishes building and
+    /// stores the built value in the provided `Option` slot. This is meant for
+    /// the object pools and arenas that manage the storage of the values
+    /// themselves, so the value doesn't need to be moved out of the method's
+    /// return value by the caller.
+    pub(crate) fn finish_into_slot(&self) -> Result<Option<TokenStream2>> {
+        if !self.finish_into_slot.is_present() {
+            return Ok(None);
+        }
+
+        let finish_func_ident = &self.finish_func.ident;
+
+        let method_docs = format!(
+            "Same as [`Self::{finish_func_ident}()`], but stores the built value \
+            in the provided slot instead of returning it.\n\n\
+            The slot is set to `Some` when this method returns. If the slot already \
+            contained a value, that value is dropped. The method also returns \
+            a reference to the stored value."
+        );
+
+        self.finish_into_method(FinishIntoMethod {
+            attr: "finish_into_slot",
+            suffix: "into_slot",
+            param: "slot",
+            param_ty: |output| quote!(&mut ::core::option::Option<#output>),
+            write_method: "insert",
+            docs: method_docs,
+        })
+        .map(Some)
+    }
+}
@@ -0,0 +1,111 @@
+use super::{BuilderGenCtx, Member};
+use crate::util::prelude::*;
+use quote::quote;
+use syn::visit::Visit;
+
+impl BuilderGenCtx {
+    /// Generates the impl of the `bon::Finish` trait for the builder in the
+    /// states where the finishing function can be called. It lets the generic
+    /// code finish building with any builder.
+    ///
+    /// The trait's method has no parameters and it's neither `async` nor `unsafe`,
+    /// so the builders with such finishing functions don't implement the trait.
+    /// The trait's impl is as visible as the builder, so it's also not generated
+    /// if the finishing function has a custom visibility, because that would
+    /// expose the finishing function beyond its visibility.
+    pub(crate) fn finish_trait(&self) -> Option<TokenStream2> {
+        let has_finish_fn_args = self
+            .members
+            .iter()
+            .any(|member| matches!(member, Member::FinishFnArg(_)));
+
+        if has_finish_fn_args
+            || self.finish_func.asyncness.is_some()
+            || self.finish_func.unsafety.is_some()
+            || self.finish_func.vis.is_some()
+        {
+            return None;
+        }
+
+        let output = match &self.finish_func.output {
+            syn::ReturnType::Type(_, ty) => ty,
+            syn::ReturnType::Default => return Some(self.finish_trait_impl(&quote!(()))),
+        };
+
+        // `impl Trait` isn't allowed in the associated types on stable Rust
+        let mut impl_trait = FindImplTrait(false);
+        impl_trait.visit_type(output);
+
+        if impl_trait.0 {
+            return None;
+        }
+
+        Some(self.finish_trait_impl(&quote!(#output)))
+    }
+
+    fn finish_trait_impl(&self, output: &TokenStream2) -> TokenStream2 {
+        let where_bounds = self.named_members().map(|member| {
+            let member_type_var = &member.generic_var_ident;
+            let into_set_trait = self.member_into_set_trait(member);
+            quote! {
+                #member_type_var: #into_set_trait
+            }
+        });
+
+        let generics_decl = &self.generics.decl_without_defaults;
+        let generic_args = &self.generics.args;
+        let where_predicates = self.generics.where_clause_predicates();
+        let finish_where_predicates = &self.finish_func.where_predicates;
+        let one_of_bounds = self.one_of_finish_bounds();
+        let by_ref_bounds = self.build_by_ref_bounds();
+        let builder_ident = &self.builder_type.ident;
+        let finish_func_ident = &self.finish_func.ident;
+
+        let state_type_vars = self
+            .state_members()
+            .into_iter()
+            .map(|member| &member.generic_var_ident)
+            .collect::<Vec<_>>();
+
+        let allows = super::allow_warnings_on_member_types();
+
+        quote! {
+            #allows
+            #[automatically_derived]
+            impl<
+                #(#generics_decl,)*
+                #(#state_type_vars,)*
+            >
+            ::bon::Finish for #builder_ident<
+                #(#generic_args,)*
+                (#(#state_type_vars,)*)
+            >
+            where
+                #(#where_predicates,)*
+                #(#where_bounds,)*
+                #(#by_ref_bounds,)*
+                #(#finish_where_predicates,)*
+                #(#one_of_bounds)*
+            {
+                type Output = #output;
+
+                #[inline(always)]
+                #[allow(
+                    // This is intentional. We want the builder syntax to compile away
+                    clippy::inline_always,
+                )]
+                fn finish(self) -> Self::Output {
+                    self.#finish_func_ident()
+                }
+            }
+        }
+    }
+}
+
+struct FindImplTrait(bool);
+
+impl Visit<'_> for FindImplTrait {
+    fn visit_type_impl_trait(&mut self, _: &syn::TypeImplTrait) {
+        self.0 = true;
+    }
+}
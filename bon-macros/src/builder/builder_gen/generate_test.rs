@@ -0,0 +1,153 @@
+use super::{BuilderGenCtx, Member};
+use crate::util::prelude::*;
+use quote::quote;
+use syn::visit_mut::VisitMut;
+
+pub(crate) struct GenerateTest {
+    /// The output type of the test and the function that wraps `()` into it
+    /// if the finishing function is fallible. The test propagates the error
+    /// with the `?` operator, so it fails if the default values are rejected.
+    pub(crate) fallible_output: Option<(syn::Type, syn::Path)>,
+}
+
+impl BuilderGenCtx {
+    /// Generates a `#[cfg(test)]` test requested with `#[builder(generate_test)]`
+    /// that builds the value with the [`Default`] values of all required members.
+    /// It guards against the changes in the builder's API that break its callers,
+    /// for example, when a member with a custom validation rejects the default.
+    ///
+    /// The test can't pick concrete types for the generic parameters of the
+    /// struct, so it's not supported for the generic structs. The lifetimes
+    /// are fine though, because they are inferred.
+    pub(crate) fn generate_test(&self) -> Result<Option<TokenStream2>> {
+        let generate_test = match &self.generate_test {
+            Some(generate_test) => generate_test,
+            None => return Ok(None),
+        };
+
+        let generic_param = self
+            .generics
+            .decl_without_defaults
+            .iter()
+            .find(|param| !matches!(param, syn::GenericParam::Lifetime(_)));
+
+        if let Some(param) = generic_param {
+            bail!(
+                param,
+                "`generate_test` can't be used with the structs that have type or const \
+                generic parameters, because the test can't choose concrete values for them"
+            );
+        }
+
+        let start_fn_owner = if let Some(on) = self.start_func_on() {
+            quote!(<#on>)
+        } else {
+            let mut path = self
+                .assoc_method_ctx
+                .as_ref()
+                .and_then(|ctx| ctx.self_ty.as_path())
+                .expect("BUG: the struct builder must have the struct's type path")
+                .path
+                .clone();
+
+            // The lifetimes of the struct are inferred
+            if let Some(segment) = path.segments.last_mut() {
+                segment.arguments = syn::PathArguments::None;
+            }
+
+            quote!(#path)
+        };
+
+        let default_of = |ty: &syn::Type| {
+            let mut ty = ty.clone();
+            EraseLifetimes.visit_type_mut(&mut ty);
+            quote!(<#ty as ::core::default::Default>::default())
+        };
+
+        let start_fn_args = self
+            .start_fn_args()
+            .map(|member| default_of(&member.base.norm_ty));
+
+        let finish_fn_args = self
+            .members
+            .iter()
+            .filter_map(Member::as_finish_fn_arg)
+            .map(|member| default_of(&member.norm_ty));
+
+        // Exactly one member of every `one_of` group must be set, so the test
+        // sets the first one of them
+        let mut one_of_groups = vec![];
+
+        let setter_calls = self
+            .named_members()
+            .filter(|member| match &member.params.one_of {
+                Some(group) if !one_of_groups.contains(&group) => {
+                    one_of_groups.push(group);
+                    true
+                }
+                Some(_) => false,
+                None => !member.is_optional(),
+            })
+            .map(|member| {
+                let setter = member.setter_method_core_name();
+
+                // The type of the setter's parameter is inferred unless it's an `impl Into`
+                let value = if member.param_into(&self.on_params)? {
+                    default_of(member.as_optional_norm_ty().unwrap_or(&member.norm_ty))
+                } else {
+                    quote!(::core::default::Default::default())
+                };
+
+                let maybe_unwrap = member.params.checked.as_ref().map(|_| quote!(.unwrap()));
+
+                Ok(quote!(.#setter(#value) #maybe_unwrap))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let start_fn_ident = &self.start_func.ident;
+        let finish_fn_ident = &self.finish_func.ident;
+
+        let test_ident = quote::format_ident!(
+            "{}_builds_from_default_values",
+            self.builder_type.ident.pascal_to_snake_case().raw_name()
+        );
+
+        let build = quote! {
+            #start_fn_owner::#start_fn_ident(#(#start_fn_args),*)
+                #(#setter_calls)*
+                .#finish_fn_ident(#(#finish_fn_args),*)
+        };
+
+        let test = match &generate_test.fallible_output {
+            Some((output, ok)) => quote! {
+                fn #test_ident() -> #output {
+                    let _ = #build?;
+                    #ok(())
+                }
+            },
+            None => quote! {
+                fn #test_ident() {
+                    let _ = #build;
+                }
+            },
+        };
+
+        Ok(Some(quote! {
+            #[cfg(test)]
+            #[test]
+            #test
+        }))
+    }
+}
+
+/// Replaces the lifetimes of the struct with `'_` to make them inferred in
+/// the test's body, where they aren't declared
+struct EraseLifetimes;
+
+impl VisitMut for EraseLifetimes {
+    fn visit_lifetime_mut(&mut self, lifetime: &mut syn::Lifetime) {
+        if lifetime.ident != "static" {
+            *lifetime = syn::Lifetime::new("'_", lifetime.span());
+        }
+    }
+}
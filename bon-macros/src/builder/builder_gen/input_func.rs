@@ -1,7 +1,7 @@
 use super::builder_params::BuilderParams;
 use super::{
-    generic_param_to_arg, AssocMethodCtx, AssocMethodReceiverCtx, BuilderGenCtx, FinishFunc,
-    FinishFuncBody, Generics, Member, MemberOrigin, RawMember, StartFunc,
+    generic_param_to_arg, setters_list_docs, AssocMethodCtx, AssocMethodReceiverCtx, BuilderGenCtx,
+    FinishFunc, FinishFuncBody, Generics, Member, MemberOrigin, RawMember, StartFunc,
 };
 use crate::builder::builder_gen::builder_params::ItemParams;
 use crate::builder::builder_gen::BuilderType;
@@ -311,10 +311,14 @@ impl FuncInputCtx {
             })
             .collect::<Result<Vec<_>>>()?;
 
-        let members = Member::from_raw(MemberOrigin::FnArg, members)?;
+        let mut members = Member::from_raw(MemberOrigin::FnArg, members)?;
+
+        Member::reject_conflicting_on_params(&members, &self.params.base.on)?;
 
         let generics = self.generics();
 
+        Member::resolve_generic_var_collisions(&mut members, &generics);
+
         let finish_func_body = FnCallBody {
             sig: self.adapted_func()?.sig,
             impl_ctx: self.impl_ctx.clone(),
@@ -332,8 +336,9 @@ impl FuncInputCtx {
 
         let ItemParams {
             name: finish_func_ident,
-            vis: _,
+            vis: finish_func_vis,
             docs: finish_func_docs,
+            on: _,
         } = self.params.base.finish_fn;
 
         let finish_func_ident = finish_func_ident.unwrap_or_else(|| {
@@ -353,12 +358,15 @@ impl FuncInputCtx {
 
         let finish_func = FinishFunc {
             ident: finish_func_ident,
+            vis: finish_func_vis,
             unsafety: self.norm_func.sig.unsafety,
             asyncness: self.norm_func.sig.asyncness,
             must_use: get_must_use_attribute(&self.norm_func.attrs)?,
             body: Box::new(finish_func_body),
             output: self.norm_func.sig.output,
             attrs: finish_func_docs,
+            where_predicates: self.params.base.finish_where,
+            turbofish_generics: vec![],
         };
 
         let fn_allows = self
@@ -375,6 +383,17 @@ impl FuncInputCtx {
             .chain(fn_allows)
             .collect();
 
+        let mut start_func_docs: Vec<_> = self
+            .norm_func
+            .attrs
+            .into_iter()
+            .filter(<_>::is_doc)
+            .collect();
+
+        if self.params.base.doc_setters.is_present() {
+            start_func_docs.extend(setters_list_docs(&members));
+        }
+
         let start_func = StartFunc {
             ident: start_func_ident,
 
@@ -382,12 +401,7 @@ impl FuncInputCtx {
             // It's supposed to be the same as the original function's visibility.
             vis: None,
 
-            attrs: self
-                .norm_func
-                .attrs
-                .into_iter()
-                .filter(<_>::is_doc)
-                .collect(),
+            attrs: start_func_docs,
 
             // Override on the start fn to use the the generics from the
             // target function itself. We don't need to duplicate the generics
@@ -396,12 +410,24 @@ impl FuncInputCtx {
                 Vec::from_iter(self.norm_func.sig.generics.params),
                 self.norm_func.sig.generics.where_clause,
             )),
+
+            on: None,
+            with_defaults: self.params.base.start_with_defaults,
         };
 
         let builder_type = BuilderType {
             ident: builder_ident,
             derives: self.params.base.derive,
+            as_set_fields: self.params.base.as_set_fields,
+            defmt_trace: self.params.base.defmt_trace,
+            capture_locations: self.params.base.capture_locations,
+            mut_setters: self.params.base.mut_setters,
+            resettable: self.params.base.resettable,
+            json_schema: self.params.base.json_schema,
+            required_fields: self.params.base.required_fields,
             docs: self.params.base.builder_type.docs,
+            setter_trait: self.params.base.setter_trait,
+            test_only_debug: self.params.base.test_only_debug,
         };
 
         let ctx = BuilderGenCtx {
@@ -418,6 +444,21 @@ impl FuncInputCtx {
             builder_type,
             start_func,
             finish_func,
+
+            ffi_ctor: None,
+            args_struct: None,
+            view: None,
+            finish_and_reset: darling::util::Flag::default(),
+            finish_into_out: darling::util::Flag::default(),
+            finish_into_slot: darling::util::Flag::default(),
+            build_by_ref: darling::util::Flag::default(),
+            delegate: vec![],
+            removed_setters: vec![],
+            blanket_from: darling::util::Flag::default(),
+            generate_test: None,
+            arbitrary: darling::util::Flag::default(),
+            builder_macro: None,
+            assert_field_count: None,
         };
 
         Ok(ctx)
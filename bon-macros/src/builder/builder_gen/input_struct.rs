@@ -1,12 +1,15 @@
-use super::builder_params::{BuilderParams, ItemParams, ItemParamsParsing};
+use super::args_struct::ArgsStruct;
+use super::builder_params::{parse_type, BuilderParams, ItemParams, ItemParamsParsing};
+use super::generate_test::GenerateTest;
 use super::{
-    AssocMethodCtx, BuilderGenCtx, FinishFunc, FinishFuncBody, Generics, Member, MemberOrigin,
-    RawMember, StartFunc,
+    setters_list_docs, AssocMethodCtx, BuilderGenCtx, FinishFunc, FinishFuncBody, Generics, Member,
+    MemberOrigin, RawMember, StartFunc,
 };
 use crate::builder::builder_gen::BuilderType;
 use crate::util::prelude::*;
 use darling::FromMeta;
 use quote::quote;
+use syn::spanned::Spanned;
 use syn::visit_mut::VisitMut;
 
 #[derive(Debug, FromMeta)]
@@ -16,12 +19,249 @@ pub(crate) struct StructInputParams {
 
     #[darling(default, with = parse_start_fn)]
     start_fn: ItemParams,
+
+    /// Name of the `extern "C"` constructor function to generate
+    ffi_ctor: Option<syn::Ident>,
+
+    /// Path to a trait with the `fn finalize(&mut self)` method that is
+    /// invoked on the constructed value before returning it from the builder
+    finalize: Option<syn::Path>,
+
+    /// Path to a function that constructs the struct from its members
+    /// instead of the struct literal
+    finish_with: Option<syn::Path>,
+
+    /// Generic type that wraps the output of the finishing function, for
+    /// example, `AppResult` turns `build() -> T` into `build() -> AppResult<T>`
+    #[darling(default, with = parse_build_result_wrapper, map = Some)]
+    build_result_wrapper: Option<BuildResultWrapper>,
+
+    /// Function that validates the invariants of the whole struct after it's
+    /// constructed. The finishing function returns an error if it fails
+    #[darling(default, with = parse_invariant, map = Some)]
+    invariant: Option<Invariant>,
+
+    /// Name of the struct with all members to generate for the `from_args` constructor
+    args_struct: Option<syn::Ident>,
+
+    /// Path to the type that borrows the members of the builder, which
+    /// is returned from the additional `{finish_fn}_view(&self)` method
+    view: Option<syn::Path>,
+
+    /// Generates the `{finish_fn}_and_reset()` method that returns the built
+    /// value together with a new builder in the initial state
+    finish_and_reset: darling::util::Flag,
+
+    /// Generates the `{finish_fn}_into_out()` method that writes the built
+    /// value into the provided `MaybeUninit` slot
+    finish_into_out: darling::util::Flag,
+
+    /// Generates the `{finish_fn}_into_slot()` method that stores the built
+    /// value in the provided `Option` slot
+    finish_into_slot: darling::util::Flag,
+
+    /// Makes the finishing function take the builder by reference and clone
+    /// the values of the members, so the builder can be reused
+    build_by_ref: darling::util::Flag,
+
+    /// Adds a type parameter to the finishing function for every type parameter
+    /// of the struct, so they can be specified with a turbofish
+    finish_turbofish: darling::util::Flag,
+
+    /// Generates `impl<T: Into<Inner>> From<T>` for the struct with a single
+    /// member that has an `Into` setter
+    blanket_from: darling::util::Flag,
+
+    /// Generates a `#[cfg(test)]` test that builds the struct with the default
+    /// values of all required members
+    generate_test: darling::util::Flag,
+
+    /// Generates the `from_arbitrary()` method that builds the struct from
+    /// the values produced by the `arbitrary` crate
+    arbitrary: darling::util::Flag,
+
+    /// Name of the `macro_rules!` macro to generate that builds the struct
+    /// from the `member: value` pairs
+    #[darling(rename = "r#macro")]
+    builder_macro: Option<syn::Ident>,
+
+    /// Number of fields that the struct must have. It's checked at compile time
+    assert_field_count: Option<syn::Expr>,
+
+    /// Makes the setters have the visibility of the corresponding fields
+    /// instead of the builder's visibility
+    field_setter_vis: darling::util::Flag,
+
+    /// Signatures of the methods of the struct to generate on the builder.
+    /// Each of them finishes building and calls the method on the built value
+    #[darling(default, with = parse_delegate)]
+    delegate: Vec<syn::Signature>,
+
+    /// Names and types of the setters of the removed members. They are
+    /// generated as no-op `#[deprecated]` setters to ease the migration
+    #[darling(default, with = parse_deprecated_removed_setter)]
+    deprecated_removed_setter: Vec<syn::Field>,
+
+    /// Name of the child module to place the builder and all other generated
+    /// items into
+    module: Option<syn::Ident>,
+
+    /// The `cfg` predicate that all generated items are gated behind
+    #[darling(default, with = parse_cfg, map = Some)]
+    cfg: Option<Cfg>,
+}
+
+#[derive(Debug)]
+pub(crate) struct Cfg {
+    pub(crate) predicate: syn::Meta,
+
+    /// Emit `#[cfg_attr(docsrs, doc(cfg(...)))]` on the generated items to make
+    /// docs.rs render the badge with the predicate. It requires the unstable
+    /// `doc_cfg` feature enabled in the user's crate, so it's opt-in.
+    pub(crate) doc_cfg: bool,
+}
+
+#[derive(Debug, FromMeta)]
+struct BuildResultWrapper {
+    /// Path to the generic wrapper type without the generic arguments
+    ty: syn::Path,
+
+    /// Path to the function that wraps the built value. Defaults to `{ty}::Ok`
+    ok: Option<syn::Path>,
+}
+
+fn parse_build_result_wrapper(meta: &syn::Meta) -> Result<BuildResultWrapper> {
+    let meta = match meta {
+        syn::Meta::NameValue(meta) => meta,
+        _ => return BuildResultWrapper::from_meta(meta),
+    };
+
+    let ty = match &meta.value {
+        syn::Expr::Path(path) if path.qself.is_none() && path.attrs.is_empty() => path.path.clone(),
+        _ => bail!(
+            &meta.value,
+            "expected a path to the wrapper type, for example, \
+            `build_result_wrapper = AppResult`"
+        ),
+    };
+
+    Ok(BuildResultWrapper { ty, ok: None })
+}
+
+#[derive(Debug, FromMeta)]
+struct Invariant {
+    /// Function of type `fn(&T) -> Result<(), E>` that validates the struct
+    with: syn::Expr,
+
+    /// Error type returned from the finishing function. The error returned by
+    /// the validation function is converted into this type with the `From` trait.
+    /// It's not specified if the error type is defined by `build_result_wrapper`
+    #[darling(default, with = parse_type, map = Some)]
+    error: Option<syn::Type>,
+}
+
+fn parse_invariant(meta: &syn::Meta) -> Result<Invariant> {
+    match meta {
+        syn::Meta::NameValue(meta) => Ok(Invariant {
+            with: meta.value.clone(),
+            error: None,
+        }),
+        _ => Invariant::from_meta(meta),
+    }
+}
+
+fn parse_cfg(meta: &syn::Meta) -> Result<Cfg> {
+    let syntax_error = || {
+        err!(
+            meta,
+            "expected `cfg(predicate)` or `cfg(predicate, doc_cfg)` syntax"
+        )
+    };
+
+    let list = match meta {
+        syn::Meta::List(list) => list,
+        _ => return Err(syntax_error()),
+    };
+
+    let mut args = list
+        .parse_args_with(
+            syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated,
+        )?
+        .into_iter();
+
+    let predicate = args.next().ok_or_else(syntax_error)?;
+
+    let doc_cfg = match args.next() {
+        None => false,
+        Some(syn::Meta::Path(path)) if path.is_ident("doc_cfg") => true,
+        Some(arg) => bail!(
+            &arg,
+            "expected `doc_cfg` after the `cfg` predicate; `cfg` accepts a single \
+            predicate, so use `all(...)` or `any(...)` to combine several of them"
+        ),
+    };
+
+    if let Some(arg) = args.next() {
+        bail!(&arg, "unexpected argument after `doc_cfg`");
+    }
+
+    Ok(Cfg { predicate, doc_cfg })
+}
+
+fn parse_delegate(meta: &syn::Meta) -> Result<Vec<syn::Signature>> {
+    let meta = match meta {
+        syn::Meta::List(meta) => meta,
+        _ => bail!(
+            meta,
+            "expected `delegate(fn method(&self) -> T, ...)` syntax"
+        ),
+    };
+
+    let signatures = meta.parse_args_with(
+        syn::punctuated::Punctuated::<syn::Signature, syn::Token![,]>::parse_terminated,
+    )?;
+
+    if signatures.is_empty() {
+        bail!(
+            meta,
+            "expected at least one method signature in `delegate(...)`"
+        );
+    }
+
+    Ok(Vec::from_iter(signatures))
+}
+
+fn parse_deprecated_removed_setter(meta: &syn::Meta) -> Result<Vec<syn::Field>> {
+    let meta = match meta {
+        syn::Meta::List(meta) => meta,
+        _ => bail!(
+            meta,
+            "expected `deprecated_removed_setter(member: Type, ...)` syntax"
+        ),
+    };
+
+    let fields = meta.parse_args_with(|input: syn::parse::ParseStream<'_>| {
+        syn::punctuated::Punctuated::<syn::Field, syn::Token![,]>::parse_terminated_with(
+            input,
+            syn::Field::parse_named,
+        )
+    })?;
+
+    if fields.is_empty() {
+        bail!(
+            meta,
+            "expected at least one `member: Type` pair in `deprecated_removed_setter(...)`"
+        );
+    }
+
+    Ok(Vec::from_iter(fields))
 }
 
 fn parse_start_fn(meta: &syn::Meta) -> Result<ItemParams> {
     ItemParamsParsing {
         meta,
         allow_vis: true,
+        allow_on: true,
         reject_self_mentions: None,
     }
     .parse()
@@ -47,7 +287,8 @@ impl StructInputParams {
                     );
                 }
 
-                let meta = darling::ast::NestedMeta::parse_meta_list(meta.tokens.clone())?;
+                let tokens = escape_macro_keyword(meta.tokens.clone());
+                let meta = darling::ast::NestedMeta::parse_meta_list(tokens)?;
 
                 Ok(meta)
             })
@@ -59,6 +300,20 @@ impl StructInputParams {
     }
 }
 
+/// The `macro` parameter's name is a keyword, which `syn` doesn't accept as
+/// the name of a nested attribute, so it's replaced with the raw identifier
+fn escape_macro_keyword(tokens: TokenStream2) -> TokenStream2 {
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            proc_macro2::TokenTree::Ident(ident) if ident == "macro" => {
+                syn::Ident::new_raw("macro", ident.span()).into()
+            }
+            token => token,
+        })
+        .collect()
+}
+
 pub(crate) struct StructInputCtx {
     orig_struct: syn::ItemStruct,
     norm_struct: syn::ItemStruct,
@@ -67,6 +322,14 @@ pub(crate) struct StructInputCtx {
 }
 
 impl StructInputCtx {
+    pub(crate) fn module(&self) -> Option<&syn::Ident> {
+        self.params.module.as_ref()
+    }
+
+    pub(crate) fn cfg(&self) -> Option<&Cfg> {
+        self.params.cfg.as_ref()
+    }
+
     pub(crate) fn new(orig_struct: syn::ItemStruct) -> Result<Self> {
         let params = StructInputParams::parse(&orig_struct)?;
 
@@ -97,9 +360,121 @@ impl StructInputCtx {
         })
     }
 
+    /// The `build_result_wrapper` and `invariant` change the output of the
+    /// finishing function, so they are incompatible with the items that
+    /// require it to return the struct itself
+    fn validate_fallible_output(&self) -> Result {
+        let params = &self.params;
+
+        if let Some(invariant) = &params.invariant {
+            match (&invariant.error, &params.build_result_wrapper) {
+                (Some(error), Some(_)) => bail!(
+                    error,
+                    "the `error` of `invariant` can't be specified together with \
+                    `build_result_wrapper`; the invariant's error is converted into \
+                    the error of the wrapper with the `?` operator instead"
+                ),
+                (None, None) => bail!(
+                    &invariant.with,
+                    "`invariant` requires the error type of the finishing function; \
+                    specify it with `invariant(with = ..., error = ErrorType)` or \
+                    use `build_result_wrapper`"
+                ),
+                _ => {}
+            }
+        }
+
+        let fallible = [
+            params
+                .build_result_wrapper
+                .as_ref()
+                .map(|wrapper| (wrapper.ty.span(), "build_result_wrapper")),
+            params
+                .invariant
+                .as_ref()
+                .map(|invariant| (invariant.with.span(), "invariant")),
+        ];
+
+        let incompatible = [
+            params.delegate.first().map(|_| "delegate"),
+            params.blanket_from.is_present().then(|| "blanket_from"),
+            params.ffi_ctor.as_ref().map(|_| "ffi_ctor"),
+            params.args_struct.as_ref().map(|_| "args_struct"),
+        ];
+
+        for (span, attr) in fallible.into_iter().flatten() {
+            if let Some(name) = incompatible.iter().flatten().next() {
+                bail!(
+                    &span,
+                    "`{attr}` can't be used together with `{name}`, \
+                    because `{name}` requires the finishing function to return \
+                    the struct itself"
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The `ffi_ctor` returns the struct by value across the FFI boundary,
+    /// so the struct must have a layout that C understands. The layout of the
+    /// structs with the default representation is unspecified.
+    fn validate_ffi_ctor_repr(&self) -> Result {
+        let ffi_ctor = match &self.params.ffi_ctor {
+            Some(ffi_ctor) => ffi_ctor,
+            None => return Ok(()),
+        };
+
+        let has_ffi_repr = self
+            .orig_struct
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("repr"))
+            .filter_map(|attr| {
+                attr.parse_args_with(
+                    syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated,
+                )
+                .ok()
+            })
+            .flatten()
+            .any(|meta| meta.path().is_ident("C") || meta.path().is_ident("transparent"));
+
+        if !has_ffi_repr {
+            bail!(
+                ffi_ctor,
+                "`ffi_ctor` requires the struct to have `#[repr(C)]` or \
+                `#[repr(transparent)]`, because the constructor returns the \
+                struct by value, and the layout of the struct with the default \
+                representation is unspecified"
+            );
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn into_builder_gen_ctx(self) -> Result<BuilderGenCtx> {
+        self.validate_fallible_output()?;
+        self.validate_ffi_ctor_repr()?;
+
+        let finish_turbofish = &self.params.finish_turbofish;
+
+        if finish_turbofish.is_present() && self.norm_struct.generics.type_params().next().is_none()
+        {
+            bail!(
+                &finish_turbofish.span(),
+                "`finish_turbofish` requires the struct to have type parameters, \
+                because only they can be specified with a turbofish at the \
+                finishing function"
+            );
+        }
+
         let builder_type = {
-            let ItemParams { name, vis: _, docs } = self.params.base.builder_type;
+            let ItemParams {
+                name,
+                vis: _,
+                docs,
+                on: _,
+            } = self.params.base.builder_type;
 
             let builder_ident = name.unwrap_or_else(|| {
                 quote::format_ident!("{}Builder", self.norm_struct.ident.raw_name())
@@ -108,7 +483,16 @@ impl StructInputCtx {
             BuilderType {
                 derives: self.params.base.derive.clone(),
                 ident: builder_ident,
+                as_set_fields: self.params.base.as_set_fields,
+                defmt_trace: self.params.base.defmt_trace,
+                capture_locations: self.params.base.capture_locations,
+                mut_setters: self.params.base.mut_setters,
+                resettable: self.params.base.resettable,
+                json_schema: self.params.base.json_schema,
+                required_fields: self.params.base.required_fields,
                 docs,
+                setter_trait: self.params.base.setter_trait.clone(),
+                test_only_debug: self.params.base.test_only_debug,
             }
         };
 
@@ -142,21 +526,157 @@ impl StructInputCtx {
             })
             .collect::<Result<Vec<_>>>()?;
 
-        let members = Member::from_raw(MemberOrigin::StructField, members)?;
+        let mut members = Member::from_raw(MemberOrigin::StructField, members)?;
+
+        Member::reject_conflicting_on_params(&members, &self.params.base.on)?;
+
+        if let (Some(name), Some(_)) = (&self.params.builder_macro, &self.params.module) {
+            bail!(
+                name,
+                "`macro` can't be used together with `module`, because the macro \
+                defined in the child module isn't available outside of it"
+            );
+        }
+
+        if self.params.finish_with.is_none() {
+            let by_ref_member = members
+                .iter()
+                .filter_map(Member::as_named)
+                .find(|member| member.params.finish_by_ref.is_present());
+
+            if let Some(member) = by_ref_member {
+                bail!(
+                    &member.params.finish_by_ref.span(),
+                    "`finish_by_ref` attribute requires `#[builder(finish_with = ...)]` \
+                    on the struct; the struct literal can't borrow the member",
+                );
+            }
+        }
 
         let generics = Generics::new(
             self.norm_struct.generics.params.iter().cloned().collect(),
             self.norm_struct.generics.where_clause.clone(),
         );
 
+        Member::resolve_generic_var_collisions(&mut members, &generics);
+
+        // The setters inherit the visibility of the fields if requested, but they
+        // can't be more visible than the builder. Only the setters that are less
+        // visible than the builder get an override.
+        if self.params.field_setter_vis.is_present() {
+            let builder_vis = &self.norm_struct.vis;
+
+            for member in members.iter_mut().filter_map(Member::as_named_mut) {
+                let field = norm_fields
+                    .named
+                    .iter()
+                    .find(|field| field.ident.as_ref() == Some(&member.orig_ident));
+
+                let field_vis = match field {
+                    Some(field) => field.vis.clone(),
+                    None => continue,
+                };
+
+                let mut setter_vis = field_vis.capped_by(builder_vis);
+
+                if quote!(#setter_vis).to_string() == quote!(#builder_vis).to_string() {
+                    continue;
+                }
+
+                if self.params.module.is_some() {
+                    setter_vis = setter_vis.into_equivalent_in_child_module()?;
+                }
+
+                member.setter_vis = Some(setter_vis);
+            }
+        }
+
+        let args_struct = match &self.params.args_struct {
+            Some(ident) => {
+                let mut fields_vis = vec![];
+
+                for field in &norm_fields.named {
+                    let mut vis = field.vis.clone();
+
+                    // The args struct is placed in the child module together
+                    // with the builder
+                    if self.params.module.is_some() {
+                        vis = vis.into_equivalent_in_child_module()?;
+                    }
+
+                    fields_vis.extend(field.ident.clone().map(|ident| (ident, vis)));
+                }
+
+                Some(ArgsStruct {
+                    ident: ident.clone(),
+                    fields_vis,
+                })
+            }
+            None => None,
+        };
+
+        let struct_ty = &self.struct_ty;
+        let invariant_error = self
+            .params
+            .invariant
+            .as_ref()
+            .and_then(|invariant| invariant.error.as_ref());
+
+        let output = match (&self.params.build_result_wrapper, invariant_error) {
+            (Some(wrapper), _) => {
+                let wrapper_ty = &wrapper.ty;
+                syn::parse_quote!(-> #wrapper_ty<#struct_ty>)
+            }
+            (None, Some(error)) => {
+                syn::parse_quote!(-> ::core::result::Result<#struct_ty, #error>)
+            }
+            (None, None) => syn::parse_quote!(-> #struct_ty),
+        };
+
+        let wrap_with = match (&self.params.build_result_wrapper, invariant_error) {
+            (Some(wrapper), _) => {
+                let ty = &wrapper.ty;
+                Some(
+                    wrapper
+                        .ok
+                        .clone()
+                        .unwrap_or_else(|| syn::parse_quote!(#ty::Ok)),
+                )
+            }
+            (None, Some(_)) => Some(syn::parse_quote!(::core::result::Result::Ok)),
+            (None, None) => None,
+        };
+
+        // The generated test returns the same wrapper as the finishing function
+        // but with the unit type to propagate the error with the `?` operator
+        let generate_test = self.params.generate_test.is_present().then(|| {
+            let fallible_output = match (&self.params.build_result_wrapper, invariant_error) {
+                (Some(wrapper), _) => {
+                    let wrapper_ty = &wrapper.ty;
+                    Some(syn::parse_quote!(#wrapper_ty<()>))
+                }
+                (None, Some(error)) => Some(syn::parse_quote!(::core::result::Result<(), #error>)),
+                (None, None) => None,
+            };
+
+            GenerateTest {
+                fallible_output: fallible_output.zip(wrap_with.clone()),
+            }
+        });
+
         let finish_func_body = StructLiteralBody {
             struct_ident: self.norm_struct.ident.clone(),
+            finalize: self.params.finalize,
+            finish_with: self.params.finish_with,
+            invariant: self.params.invariant.map(|invariant| invariant.with),
+            wrap_with,
         };
 
         let ItemParams {
             name: start_func_ident,
             vis: start_func_vis,
             docs: start_func_docs,
+            on: start_func_on,
         } = self.params.start_fn;
 
         let start_func_ident = start_func_ident
@@ -164,23 +684,56 @@ impl StructInputCtx {
 
         let ItemParams {
             name: finish_func_ident,
-            vis: _,
+            vis: finish_func_vis,
             docs: finish_func_docs,
+            on: _,
         } = self.params.base.finish_fn;
 
         let finish_func_ident =
             finish_func_ident.unwrap_or_else(|| syn::Ident::new("build", start_func_ident.span()));
 
-        let struct_ty = &self.struct_ty;
+        let mut vis = self.norm_struct.vis;
+        let mut start_func_vis = start_func_vis;
+        let mut finish_func_vis = finish_func_vis;
+
+        // The generated items are placed in a child module, so their visibility
+        // must be widened to stay the same relative to the module of the struct
+        if self.params.module.is_some() {
+            vis = vis.into_equivalent_in_child_module()?;
+            start_func_vis = start_func_vis
+                .map(VisibilityExt::into_equivalent_in_child_module)
+                .transpose()?;
+            finish_func_vis = finish_func_vis
+                .map(VisibilityExt::into_equivalent_in_child_module)
+                .transpose()?;
+        }
+
+        // The turbofish parameters are opt-in, because they add generic parameters
+        // with trait bounds to the signature of the finishing function. This may
+        // break the type inference in the code that uses the finishing function
+        // as a function item, e.g. `.map(ExampleBuilder::build)`
+        let turbofish_generics = if self.params.finish_turbofish.is_present() {
+            self.norm_struct
+                .generics
+                .type_params()
+                .map(|param| param.ident.clone())
+                .collect()
+        } else {
+            vec![]
+        };
+
         let finish_func = FinishFunc {
             ident: finish_func_ident,
+            vis: finish_func_vis,
             unsafety: None,
             asyncness: None,
             must_use: Some(syn::parse_quote! {
                 #[must_use = "building a struct without using it is likely a bug"]
             }),
             body: Box::new(finish_func_body),
-            output: syn::parse_quote!(-> #struct_ty),
+            output,
+            where_predicates: self.params.base.finish_where,
+            turbofish_generics,
             attrs: finish_func_docs.unwrap_or_else(|| {
                 vec![syn::parse_quote! {
                     /// Finishes building and returns the requested object
@@ -188,7 +741,7 @@ impl StructInputCtx {
             }),
         };
 
-        let start_func_docs = start_func_docs.unwrap_or_else(|| {
+        let mut start_func_docs = start_func_docs.unwrap_or_else(|| {
             let docs = format!(
                 "Create an instance of [`{}`] using the builder syntax",
                 self.norm_struct.ident
@@ -197,11 +750,17 @@ impl StructInputCtx {
             vec![syn::parse_quote!(#[doc = #docs])]
         });
 
+        if self.params.base.doc_setters.is_present() {
+            start_func_docs.extend(setters_list_docs(&members));
+        }
+
         let start_func = StartFunc {
             ident: start_func_ident,
             vis: start_func_vis,
             attrs: start_func_docs,
             generics: None,
+            on: start_func_on,
+            with_defaults: self.params.base.start_with_defaults,
         };
 
         let assoc_method_ctx = Some(AssocMethodCtx {
@@ -225,11 +784,26 @@ impl StructInputCtx {
 
             assoc_method_ctx,
             generics,
-            vis: self.norm_struct.vis,
+            vis,
 
             builder_type,
             start_func,
             finish_func,
+
+            ffi_ctor: self.params.ffi_ctor,
+            args_struct,
+            view: self.params.view,
+            finish_and_reset: self.params.finish_and_reset,
+            finish_into_out: self.params.finish_into_out,
+            finish_into_slot: self.params.finish_into_slot,
+            build_by_ref: self.params.build_by_ref,
+            delegate: self.params.delegate,
+            removed_setters: self.params.deprecated_removed_setter,
+            blanket_from: self.params.blanket_from,
+            generate_test,
+            arbitrary: self.params.arbitrary,
+            builder_macro: self.params.builder_macro,
+            assert_field_count: self.params.assert_field_count,
         };
 
         Ok(ctx)
@@ -238,19 +812,72 @@ impl StructInputCtx {
 
 struct StructLiteralBody {
     struct_ident: syn::Ident,
+    finalize: Option<syn::Path>,
+    finish_with: Option<syn::Path>,
+    invariant: Option<syn::Expr>,
+    wrap_with: Option<syn::Path>,
 }
 
 impl FinishFuncBody for StructLiteralBody {
     fn generate(&self, member_exprs: &[Member]) -> TokenStream2 {
-        let Self { struct_ident } = self;
+        let Self {
+            struct_ident,
+            finalize,
+            finish_with,
+            invariant,
+            wrap_with,
+        } = self;
 
         // The variables with values of members are in scope for this expression.
         let member_vars = member_exprs.iter().map(Member::orig_ident);
 
-        quote! {
-            #struct_ident {
-                #(#member_vars,)*
+        let value = match finish_with {
+            Some(finish_with) => {
+                let args = member_exprs.iter().map(|member| {
+                    let var = member.orig_ident();
+                    let by_ref = member
+                        .as_named()
+                        .map_or(false, |member| member.params.finish_by_ref.is_present());
+
+                    if by_ref {
+                        quote!(&#var)
+                    } else {
+                        quote!(#var)
+                    }
+                });
+
+                quote!(#finish_with(#(#args),*))
             }
+            None => quote! {
+                #struct_ident {
+                    #(#member_vars,)*
+                }
+            },
+        };
+
+        let value = match finalize {
+            Some(finalize) => quote! {{
+                let mut __value = #value;
+                #finalize::finalize(&mut __value);
+                __value
+            }},
+            None => value,
+        };
+
+        // The error of the invariant's check is converted into the error
+        // of the finishing function with the `?` operator
+        let value = match invariant {
+            Some(invariant) => quote! {{
+                let __value = #value;
+                (#invariant)(&__value)?;
+                __value
+            }},
+            None => value,
+        };
+
+        match wrap_with {
+            Some(wrap_with) => quote!(#wrap_with(#value)),
+            None => value,
         }
     }
 }
@@ -0,0 +1,167 @@
+use super::{BuilderGenCtx, Member};
+use crate::util::prelude::*;
+use quote::quote;
+use std::fmt::Write;
+
+struct SchemaProperty<'a> {
+    name: String,
+    ty: &'a syn::Type,
+    is_required: bool,
+    docs: &'a [syn::Attribute],
+}
+
+impl BuilderGenCtx {
+    /// Generates a `json_schema()` function on the builder that returns a JSON
+    /// Schema of its members. All the information in the schema is known at
+    /// code generation time, so the schema is embedded as a string literal.
+    ///
+    /// The function is generated via a macro from the runtime crate, because
+    /// only the runtime crate knows whether its `json-schema` feature is enabled.
+    pub(crate) fn json_schema(&self) -> Option<TokenStream2> {
+        if !self.builder_type.json_schema.is_present() {
+            return None;
+        }
+
+        let properties = self.members.iter().filter_map(|member| {
+            let property = match member {
+                Member::Named(member) => SchemaProperty {
+                    name: member.orig_ident.raw_name(),
+                    ty: member.as_optional_norm_ty().unwrap_or(&member.norm_ty),
+                    is_required: !member.is_optional(),
+                    docs: &member.docs,
+                },
+                Member::StartFnArg(member) => SchemaProperty {
+                    name: member.base.ident.raw_name(),
+                    ty: &member.base.norm_ty,
+                    is_required: true,
+                    docs: &[],
+                },
+                Member::FinishFnArg(member) => SchemaProperty {
+                    name: member.ident.raw_name(),
+                    ty: &member.norm_ty,
+                    is_required: true,
+                    docs: &[],
+                },
+                Member::Skipped(_) => return None,
+            };
+
+            Some(property)
+        });
+
+        let properties = properties.collect();
+
+        let schema = schema_json(&self.builder_type.ident.raw_name(), properties);
+
+        let generics_decl = &self.generics.decl_without_defaults;
+        let generic_args = &self.generics.args;
+        let where_clause = &self.generics.where_clause;
+        let builder_ident = &self.builder_type.ident;
+        let vis = &self.vis;
+        let allows = super::allow_warnings_on_member_types();
+
+        Some(quote! {
+            #allows
+            #[automatically_derived]
+            impl<#(#generics_decl,)*> #builder_ident<#(#generic_args,)*>
+            #where_clause
+            {
+                ::bon::__json_schema_fn!(#vis, #schema);
+            }
+        })
+    }
+}
+
+fn schema_json(title: &str, properties: Vec<SchemaProperty<'_>>) -> String {
+    let mut json_properties = vec![];
+    let mut required = vec![];
+
+    for property in properties {
+        let rust_ty = property.ty.to_code_string();
+
+        let mut json_property = String::from("{");
+
+        if let Some(json_ty) = json_type(&rust_ty) {
+            write!(json_property, r#""type":{},"#, json_string(json_ty)).unwrap();
+        }
+
+        write!(json_property, r#""x-rust-type":{}"#, json_string(&rust_ty)).unwrap();
+
+        if let Some(description) = description(property.docs) {
+            write!(
+                json_property,
+                r#","description":{}"#,
+                json_string(&description)
+            )
+            .unwrap();
+        }
+
+        json_property.push('}');
+
+        json_properties.push(format!("{}:{json_property}", json_string(&property.name)));
+
+        if property.is_required {
+            required.push(json_string(&property.name));
+        }
+    }
+
+    format!(
+        r#"{{"$schema":"https://json-schema.org/draft/2020-12/schema","title":{},"type":"object","properties":{{{}}},"required":[{}]}}"#,
+        json_string(title),
+        json_properties.join(","),
+        required.join(","),
+    )
+}
+
+/// Maps the well-known Rust types to their JSON counterparts. Other types
+/// are described only with the `x-rust-type` keyword.
+fn json_type(rust_ty: &str) -> Option<&'static str> {
+    let json_ty = match rust_ty.trim_start_matches("::core::primitive::") {
+        "bool" => "boolean",
+        "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64" | "u128"
+        | "usize" => "integer",
+        "f32" | "f64" => "number",
+        "char" | "String" | "&str" => "string",
+        // References to `str` with an explicit lifetime, e.g. `&'a str`
+        ty if ty.starts_with("&'") && ty.ends_with(" str") => "string",
+        _ => return None,
+    };
+
+    Some(json_ty)
+}
+
+fn description(docs: &[syn::Attribute]) -> Option<String> {
+    let lines: Vec<_> = docs
+        .iter()
+        .filter_map(|attr| match attr.as_doc()? {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(lit),
+                ..
+            }) => Some(lit.value().trim().to_owned()),
+            _ => None,
+        })
+        .collect();
+
+    let description = lines.join("\n").trim().to_owned();
+
+    (!description.is_empty()).then(|| description)
+}
+
+fn json_string(value: &str) -> String {
+    let mut output = String::with_capacity(value.len() + 2);
+    output.push('"');
+
+    for char in value.chars() {
+        match char {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            char if char.is_control() => write!(output, "\\u{:04x}", char as u32).unwrap(),
+            char => output.push(char),
+        }
+    }
+
+    output.push('"');
+    output
+}
@@ -1,19 +1,21 @@
-use super::{MemberOrigin, MemberParams, NamedMember, PositionalFnArgMember};
+use super::{Member, MemberOrigin, MemberParams, NamedMember, PositionalFnArgMember};
 use crate::builder::builder_gen::builder_params::OnParams;
 use crate::util::prelude::*;
 use quote::{quote, ToTokens};
 
 impl NamedMember {
     pub(crate) fn param_into(&self, on_params: &[OnParams]) -> Result<bool> {
+        is_into_enabled(self.origin, &self.params, self.on_scrutinee(), on_params)
+    }
+
+    /// The type that is matched with the type patterns of `#[builder(on(...))]`
+    fn on_scrutinee(&self) -> &syn::Type {
         // For optional named members the target of the `Into` conversion is the type
         // inside of the `Option<T>`, not the `Option<T>` itself because we generate
         // a setter that accepts `T` itself. It also makes this logic stable regardless
         // if `Option<T>` is used or the member of type `T` has `#[builder(default)]` on it.
-        let scrutinee = self
-            .as_optional_with_ty(&self.orig_ty)
-            .unwrap_or(&self.orig_ty);
-
-        is_into_enabled(self.origin, &self.params, scrutinee, on_params)
+        self.as_optional_with_ty(&self.orig_ty)
+            .unwrap_or(&self.orig_ty)
     }
 
     pub(crate) fn setter_method_core_name(&self) -> &syn::Ident {
@@ -23,12 +25,15 @@ impl NamedMember {
 
 impl PositionalFnArgMember {
     pub(crate) fn param_into(&self, on_params: &[OnParams]) -> Result<bool> {
+        is_into_enabled(self.origin, &self.params, self.on_scrutinee(), on_params)
+    }
+
+    /// The type that is matched with the type patterns of `#[builder(on(...))]`
+    fn on_scrutinee(&self) -> &syn::Type {
         // Positional members are never optional. Users must always specify them, so there
         // is no need for us to look into the `Option<T>` generic parameter, because the
         // `Option<T>` itself is the target of the into conversion, not the `T` inside it.
-        let scrutinee = self.orig_ty.as_ref();
-
-        is_into_enabled(self.origin, &self.params, scrutinee, on_params)
+        &self.orig_ty
     }
 
     pub(crate) fn fn_input_param(&self, on_params: &[OnParams]) -> Result<TokenStream2> {
@@ -61,6 +66,49 @@ impl PositionalFnArgMember {
     }
 }
 
+impl Member {
+    /// Rejects members that match the type patterns of several `#[builder(on(...))]`
+    /// rules that configure conflicting options. It's not obvious which of such
+    /// rules applies to the member. The rules that agree with each other are
+    /// redundant, but harmless, so they are accepted.
+    pub(crate) fn reject_conflicting_on_params(members: &[Self], on_params: &[OnParams]) -> Result {
+        for member in members {
+            let (origin, ident, scrutinee) = match member {
+                Self::Named(me) => (me.origin, &me.orig_ident, me.on_scrutinee()),
+                Self::StartFnArg(me) => (me.base.origin, &me.base.ident, me.base.on_scrutinee()),
+                Self::FinishFnArg(me) => (me.origin, &me.ident, me.on_scrutinee()),
+                Self::Skipped(_) => continue,
+            };
+
+            let mut matched: Vec<&OnParams> = vec![];
+
+            for params in on_params {
+                if !scrutinee.matches(&params.type_pattern)? {
+                    continue;
+                }
+
+                if let Some(first) = matched.iter().find(|first| first.conflicts_with(params)) {
+                    bail!(
+                        &params.type_pattern,
+                        "the {origin} `{}` matches the type patterns of multiple \
+                        `#[builder(on(...))]` rules that configure conflicting options: \
+                        `on({}, ...)` and `on({}, ...)`; it's ambiguous which of them \
+                        applies to this member, so make the type patterns of these \
+                        rules not overlap",
+                        ident.raw_name(),
+                        first.type_pattern.to_code_string(),
+                        params.type_pattern.to_code_string(),
+                    );
+                }
+
+                matched.push(params);
+            }
+        }
+
+        Ok(())
+    }
+}
+
 fn is_into_enabled(
     origin: MemberOrigin,
     member_params: &MemberParams,
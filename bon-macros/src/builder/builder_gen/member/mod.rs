@@ -1,12 +1,15 @@
 mod into_conversion;
 mod params;
 
+use super::Generics;
 use crate::util::prelude::*;
 use darling::util::SpannedValue;
 use darling::FromAttributes;
 use params::MemberParams;
 use quote::quote;
 use std::fmt;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
 
 #[derive(Debug, Clone, Copy)]
 pub(crate) enum MemberOrigin {
@@ -51,7 +54,10 @@ pub(crate) struct NamedMember {
     /// Specifies what syntax the member comes from.
     pub(crate) origin: MemberOrigin,
 
-    /// Index of the member relative to other regular members. The index is 0-based.
+    /// Index of the member's state in the builder's state tuple. The index is 0-based.
+    /// The states are sorted by the names of the members rather than by their
+    /// declaration order, so that reordering the members doesn't change the type
+    /// of the builder.
     pub(crate) index: syn::Index,
 
     /// Original name of the member is used as the name of the builder field and
@@ -83,6 +89,11 @@ pub(crate) struct NamedMember {
 
     /// Parameters configured by the user explicitly via attributes
     pub(crate) params: MemberParams,
+
+    /// Overrides the visibility of the setters, which is the same as the
+    /// builder's visibility by default. It's set with `#[builder(field_setter_vis)]`
+    /// for the fields that are less visible than the builder.
+    pub(crate) setter_vis: Option<syn::Visibility>,
 }
 
 /// Member that was marked with `#[builder(pos = start_fn)]`
@@ -121,6 +132,12 @@ pub(crate) struct SkippedMember {
 
     /// Value to assign to the member
     pub(crate) value: SpannedValue<Option<syn::Expr>>,
+
+    /// Name of the associated constant of the struct that holds the value
+    /// of the member with `#[builder(const_field = ...)]`
+    pub(crate) const_ident: Option<syn::Ident>,
+    /// Name of the member that must be evaluated before this one
+    pub(crate) init_after: Option<syn::Ident>,
 }
 
 impl NamedMember {
@@ -137,12 +154,110 @@ impl NamedMember {
             }
         }
 
+        if self.params.into_option.is_present() && !self.is_optional() {
+            bail!(
+                &self.params.into_option.span(),
+                "`into_option` can be used only with optional members, i.e. members \
+                of type `Option<T>` or members with `#[builder(default)]`",
+            );
+        }
+
+        if let Some(message) = &self.params.required_message {
+            if self.is_optional() {
+                bail!(
+                    message,
+                    "`required_message` can be used only with required members; \
+                    optional members don't need to be set before finishing building",
+                );
+            }
+        }
+
+        if self.params.collection.is_present() && self.collection_item_ty().is_none() {
+            bail!(
+                &self.params.collection.span(),
+                "`collection` can be used only with members of type `Vec<T>` \
+                or `Option<Vec<T>>`",
+            );
+        }
+
+        if self.params.borrow.is_present() && self.borrowed_input_ty().is_none() {
+            bail!(
+                &self.params.borrow.span(),
+                "`borrow` can be used only with members of type `Cow<'a, B>` \
+                or `Option<Cow<'a, B>>`",
+            );
+        }
+
+        if let Some(group) = &self.params.one_of {
+            if !self.norm_ty.is_option() {
+                bail!(
+                    group,
+                    "`one_of` can be used only with members of type `Option<T>`, \
+                    because all members of the group except one stay unset",
+                );
+            }
+        }
+
         Ok(())
     }
 
+    /// The type of the setter's input of the member with `#[builder(borrow)]`.
+    /// It's `&'a B` for the member of type `Cow<'a, B>`.
+    pub(crate) fn borrowed_input_ty(&self) -> Option<syn::Type> {
+        let ty = self.as_optional_norm_ty().unwrap_or(&self.norm_ty);
+        let segment = ty.as_path()?.path.segments.last()?;
+
+        if segment.ident != "Cow" {
+            return None;
+        }
+
+        let args = match &segment.arguments {
+            syn::PathArguments::AngleBracketed(args) => &args.args,
+            _ => return None,
+        };
+
+        match (args.first()?, args.iter().nth(1)?) {
+            (syn::GenericArgument::Lifetime(lifetime), syn::GenericArgument::Type(ty)) => {
+                Some(syn::parse_quote!(&#lifetime #ty))
+            }
+            _ => None,
+        }
+    }
+
+    /// The call that transforms the value of the optional member of type
+    /// `Option<T>` with the function from `#[builder(setter_body = ...)]`
+    pub(crate) fn maybe_map_setter_body(&self, inner_type: &syn::Type) -> Option<TokenStream2> {
+        let body = self.params.setter_body.as_ref()?;
+
+        Some(quote! {
+            .map(|value: #inner_type| {
+                ::bon::private::apply_setter_body::<#inner_type>(value, #body)
+            })
+        })
+    }
+
+    /// Returns `true` if the setter of the member combines the new value with
+    /// the previous one instead of overwriting it, i.e. the member has
+    /// `#[builder(bitor)]` or `#[builder(collection)]`.
+    pub(crate) fn is_accumulating(&self) -> bool {
+        self.params.bitor.is_present() || self.params.collection.is_present()
+    }
+
+    /// The type of the items of the member with `#[builder(collection)]`
+    pub(crate) fn collection_item_ty(&self) -> Option<&syn::Type> {
+        let ty = self.as_optional_norm_ty()?;
+        if !ty.is_last_segment("Vec") {
+            return None;
+        }
+        ty.type_param("Vec")
+    }
+
     fn as_optional_with_ty<'a>(&'a self, ty: &'a syn::Type) -> Option<&'a syn::Type> {
-        ty.option_type_param()
-            .or_else(|| (self.params.default.is_some()).then(|| ty))
+        // The accumulating members start from the default value, so they are
+        // optional even without an explicit `#[builder(default)]`
+        let has_default = self.params.default.is_some() || self.is_accumulating();
+
+        ty.option_type_param().or_else(|| has_default.then(|| ty))
     }
 
     pub(crate) fn as_optional_norm_ty(&self) -> Option<&syn::Type> {
@@ -220,9 +335,11 @@ impl Member {
             output.push(Self::FinishFnArg(member));
         }
 
-        let mut named_count = 0;
+        // Names of the associated constants generated for `const_field` members
+        // mapped to the names of the members
+        let mut const_idents: Vec<(syn::Ident, syn::Ident)> = vec![];
 
-        for (member, params) in members {
+        for (member, mut params) in members {
             let RawMember {
                 attrs,
                 ident: orig_ident,
@@ -235,6 +352,41 @@ impl Member {
                     ident: orig_ident,
                     norm_ty,
                     value,
+                    const_ident: None,
+                    init_after: params.init_after,
+                }));
+                continue;
+            }
+
+            if let Some(const_field) = params.const_field {
+                let value = const_field.value;
+                let span = value.span();
+
+                let const_ident = match const_field.name {
+                    Some(name) => name,
+                    None => derive_const_ident(&orig_ident)?,
+                };
+
+                let duplicate = const_idents.iter().find(|(other, _)| *other == const_ident);
+
+                if let Some((_, other_member)) = duplicate {
+                    bail!(
+                        &const_ident,
+                        "the associated constant `{const_ident}` for this member collides \
+                        with the associated constant for the member `{other_member}`; \
+                        specify a different name explicitly with \
+                        `#[builder(const_field(name = NAME, value = ...))]`"
+                    );
+                }
+
+                const_idents.push((const_ident.clone(), orig_ident.clone()));
+
+                output.push(Self::Skipped(SkippedMember {
+                    ident: orig_ident,
+                    norm_ty,
+                    value: SpannedValue::new(Some(value), span),
+                    const_ident: Some(const_ident),
+                    init_after: None,
                 }));
                 continue;
             }
@@ -254,6 +406,14 @@ impl Member {
                 );
             }
 
+            // Keep the builder in agreement with `serde` about which fields are
+            // optional. An explicit `#[builder(default)]` takes precedence.
+            // `Option` fields are already optional, so they are left as is.
+            let is_struct_field = matches!(origin, MemberOrigin::StructField);
+            if is_struct_field && params.default.is_none() && !norm_ty.is_option() {
+                params.default = serde_default(attrs);
+            }
+
             // XXX: docs are collected only for named members. There is no obvious
             // place where to put the docs for positional and skipped members.
             //
@@ -277,7 +437,8 @@ impl Member {
             let norm_ident_pascal = norm_ident.snake_to_pascal_case();
 
             let me = NamedMember {
-                index: named_count.into(),
+                // The final index is assigned below once all members are known
+                index: 0.into(),
                 origin,
                 generic_var_ident: quote::format_ident!("__{}", norm_ident_pascal),
                 norm_ident_pascal,
@@ -287,12 +448,26 @@ impl Member {
                 orig_ty,
                 params,
                 docs,
+                setter_vis: None,
             };
 
             me.validate()?;
 
             output.push(Self::Named(me));
-            named_count += 1;
+        }
+
+        let mut named_members: Vec<_> = output
+            .iter_mut()
+            .filter_map(|member| match member {
+                Self::Named(member) => Some(member),
+                _ => None,
+            })
+            .collect();
+
+        named_members.sort_by_key(|member| member.norm_ident.raw_name());
+
+        for (index, member) in named_members.into_iter().enumerate() {
+            member.index = index.into();
         }
 
         Ok(output)
@@ -300,6 +475,23 @@ impl Member {
 }
 
 impl Member {
+    /// Renames the generic type variables of the named members that collide with
+    /// the generic params of the builder or with each other. For example, the
+    /// member `value` of a struct that has a type parameter `__Value` gets
+    /// the `__Value_` variable instead.
+    pub(super) fn resolve_generic_var_collisions(members: &mut [Self], generics: &Generics) {
+        let mut reserved = vec![generics.state_var.clone()];
+
+        for member in members.iter_mut().filter_map(Self::as_named_mut) {
+            member.generic_var_ident = generics.unique_ident(
+                &member.generic_var_ident,
+                &reserved.iter().collect::<Vec<_>>(),
+            );
+
+            reserved.push(member.generic_var_ident.clone());
+        }
+    }
+
     pub(crate) fn norm_ty(&self) -> &syn::Type {
         match self {
             Self::Named(me) => &me.norm_ty,
@@ -318,6 +510,15 @@ impl Member {
         }
     }
 
+    /// Name of the member requested with `#[builder(init_after = ...)]`
+    pub(crate) fn init_after(&self) -> Option<&syn::Ident> {
+        match self {
+            Self::Named(me) => me.params.init_after.as_ref(),
+            Self::Skipped(me) => me.init_after.as_ref(),
+            Self::StartFnArg(_) | Self::FinishFnArg(_) => None,
+        }
+    }
+
     pub(crate) fn as_named(&self) -> Option<&NamedMember> {
         match self {
             Self::Named(me) => Some(me),
@@ -325,6 +526,13 @@ impl Member {
         }
     }
 
+    pub(crate) fn as_named_mut(&mut self) -> Option<&mut NamedMember> {
+        match self {
+            Self::Named(me) => Some(me),
+            _ => None,
+        }
+    }
+
     pub(crate) fn as_start_fn_arg(&self) -> Option<&StartFnArgMember> {
         match self {
             Self::StartFnArg(me) => Some(me),
@@ -340,6 +548,40 @@ impl Member {
     }
 }
 
+/// Finds `#[serde(default)]` or `#[serde(default = "path")]` among the attributes
+/// of the field. The attributes that can't be parsed are ignored, because it's
+/// the job of `serde` to report errors in them.
+fn serde_default(attrs: &[syn::Attribute]) -> Option<SpannedValue<Option<syn::Expr>>> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("serde"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .find_map(|meta| match &meta {
+            syn::Meta::Path(path) if path.is_ident("default") => {
+                Some(SpannedValue::new(None, meta.span()))
+            }
+            syn::Meta::NameValue(meta) if meta.path.is_ident("default") => {
+                let path = match &meta.value {
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(lit),
+                        ..
+                    }) => lit.parse::<syn::ExprPath>().ok()?,
+                    _ => return None,
+                };
+
+                Some(SpannedValue::new(
+                    Some(syn::parse_quote!(#path())),
+                    meta.span(),
+                ))
+            }
+            _ => None,
+        })
+}
+
 impl PositionalFnArgMember {
     fn new(origin: MemberOrigin, member: RawMember<'_>, params: MemberParams) -> Self {
         let RawMember {
@@ -358,3 +600,24 @@ impl PositionalFnArgMember {
         }
     }
 }
+
+/// The name of the associated constant for the `const_field` member is the name
+/// of the member in `SCREAMING_SNAKE_CASE` with the leading underscores removed.
+fn derive_const_ident(member_ident: &syn::Ident) -> Result<syn::Ident> {
+    let name = member_ident
+        .raw_name()
+        .trim_start_matches('_')
+        .to_uppercase();
+
+    syn::parse_str::<syn::Ident>(&name).map_err(|_| {
+        err!(
+            member_ident,
+            "the name `{name}` derived from the name of this member \
+            is not a valid identifier for an associated constant; \
+            specify the name explicitly with \
+            `#[builder(const_field(name = NAME, value = ...))]`"
+        )
+    })?;
+
+    Ok(syn::Ident::new(&name, member_ident.span()))
+}
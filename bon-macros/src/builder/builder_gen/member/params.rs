@@ -1,6 +1,8 @@
 use super::MemberOrigin;
+use crate::builder::builder_gen::builder_params::parse_type;
 use crate::util::prelude::*;
 use darling::util::SpannedValue;
+use darling::FromMeta;
 use std::fmt;
 use syn::spanned::Spanned;
 
@@ -10,6 +12,10 @@ pub(crate) struct MemberParams {
     /// Enables an `Into` conversion for the setter method.
     pub(crate) into: darling::util::Flag,
 
+    /// Makes the setter of an optional member accept `impl Into<Option<T>>`,
+    /// so that both `T` and `Option<T>` can be passed to it.
+    pub(crate) into_option: darling::util::Flag,
+
     /// Assign a default value to the member it it's not specified.
     ///
     /// An optional expression can be provided to set the value for the member,
@@ -32,16 +38,103 @@ pub(crate) struct MemberParams {
     /// gets its own setter methods.
     pub(crate) start_fn: darling::util::Flag,
     pub(crate) finish_fn: darling::util::Flag,
+
+    /// Validate the value in the setter method. The setter returns a `Result`
+    /// with the builder in the `Ok` variant in this case.
+    pub(crate) checked: Option<SpannedValue<CheckedParams>>,
+
+    /// Pass the member by reference to the `finish_with` function instead
+    /// of moving it.
+    pub(crate) finish_by_ref: darling::util::Flag,
+
+    /// Generate an additional `{member}_via` setter that accepts a closure
+    /// and invokes it immediately to compute the value.
+    pub(crate) via_fn: darling::util::Flag,
+
+    /// Custom compile error message reported when the required member
+    /// isn't set before finishing building.
+    pub(crate) required_message: Option<syn::LitStr>,
+
+    /// Make the setter accumulate the values with the `|` operator instead
+    /// of overwriting them. The setter may be called several times in this case.
+    pub(crate) bitor: darling::util::Flag,
+
+    /// Make the setter push a single item into the `Vec` member instead of
+    /// overwriting the whole collection. The setter may be called several times
+    /// in this case.
+    pub(crate) collection: darling::util::Flag,
+
+    /// Validate every item pushed into the `collection` member. The setter is
+    /// named `try_{member}` and returns a `Result` with the builder in the `Ok`
+    /// variant in this case.
+    pub(crate) item_validate: Option<SpannedValue<CheckedParams>>,
+
+    /// Make the setter of the `Cow<'a, B>` member accept `&'a B` and store
+    /// it as `Cow::Borrowed` without cloning.
+    pub(crate) borrow: darling::util::Flag,
+
+    /// Name of the group of mutually exclusive members. Exactly one member
+    /// of the group must be set before finishing building.
+    #[darling(with = parse_one_of, map = Some)]
+    pub(crate) one_of: Option<syn::Ident>,
+
+    /// Function of type `fn(T) -> T` that transforms the value in the setter
+    /// before storing it in the builder.
+    pub(crate) setter_body: Option<syn::Expr>,
+
+    /// Skip generating a setter for this member and initialize it with the
+    /// associated constant of the struct with the given value.
+    #[darling(with = parse_const_field, map = Some)]
+    pub(crate) const_field: Option<ConstFieldParams>,
+
+    /// Name of the other member that must be evaluated before this member in
+    /// the finishing function, so that the `default` or `skip` expression of
+    /// this member can reference it regardless of the declaration order.
+    pub(crate) init_after: Option<syn::Ident>,
+}
+
+#[derive(Debug, Clone, darling::FromMeta)]
+pub(crate) struct CheckedParams {
+    /// Function of type `fn(&T) -> Result<(), E>` that validates the value.
+    pub(crate) with: syn::Expr,
+
+    /// Error type returned from the setter. The error returned by the validation
+    /// function is converted into this type with the `From` trait.
+    #[darling(with = parse_type)]
+    pub(crate) error: syn::Type,
+}
+
+#[derive(Debug, Clone, darling::FromMeta)]
+pub(crate) struct ConstFieldParams {
+    /// Name of the associated constant. By default, it's derived from the
+    /// name of the member.
+    pub(crate) name: Option<syn::Ident>,
+
+    /// Value of the associated constant
+    pub(crate) value: syn::Expr,
 }
 
 #[derive(PartialEq, Eq, Clone, Copy)]
 enum ParamName {
     Default,
     Into,
+    IntoOption,
     Name,
     Skip,
     StartFn,
     FinishFn,
+    Checked,
+    FinishByRef,
+    ViaFn,
+    RequiredMessage,
+    BitOr,
+    Collection,
+    ItemValidate,
+    Borrow,
+    OneOf,
+    SetterBody,
+    ConstField,
+    InitAfter,
 }
 
 impl fmt::Display for ParamName {
@@ -49,10 +142,23 @@ impl fmt::Display for ParamName {
         let str = match self {
             Self::Default => "default",
             Self::Into => "into",
+            Self::IntoOption => "into_option",
             Self::Name => "name",
             Self::Skip => "skip",
             Self::StartFn => "start_fn",
             Self::FinishFn => "finish_fn",
+            Self::Checked => "checked",
+            Self::FinishByRef => "finish_by_ref",
+            Self::ViaFn => "via_fn",
+            Self::RequiredMessage => "required_message",
+            Self::BitOr => "bitor",
+            Self::Collection => "collection",
+            Self::ItemValidate => "item_validate",
+            Self::Borrow => "borrow",
+            Self::OneOf => "one_of",
+            Self::SetterBody => "setter_body",
+            Self::ConstField => "const_field",
+            Self::InitAfter => "init_after",
         };
         f.write_str(str)
     }
@@ -88,20 +194,46 @@ impl MemberParams {
     fn specified_param_names(&self) -> impl Iterator<Item = ParamName> {
         let Self {
             into,
+            into_option,
             default,
             skip,
             name,
             finish_fn,
             start_fn,
+            checked,
+            finish_by_ref,
+            via_fn,
+            required_message,
+            bitor,
+            collection,
+            item_validate,
+            borrow,
+            one_of,
+            setter_body,
+            const_field,
+            init_after,
         } = self;
 
         let attrs = [
             (default.is_some(), ParamName::Default),
             (name.is_some(), ParamName::Name),
             (into.is_present(), ParamName::Into),
+            (into_option.is_present(), ParamName::IntoOption),
             (skip.is_some(), ParamName::Skip),
             (start_fn.is_present(), ParamName::StartFn),
             (finish_fn.is_present(), ParamName::FinishFn),
+            (checked.is_some(), ParamName::Checked),
+            (finish_by_ref.is_present(), ParamName::FinishByRef),
+            (via_fn.is_present(), ParamName::ViaFn),
+            (required_message.is_some(), ParamName::RequiredMessage),
+            (bitor.is_present(), ParamName::BitOr),
+            (collection.is_present(), ParamName::Collection),
+            (item_validate.is_some(), ParamName::ItemValidate),
+            (borrow.is_present(), ParamName::Borrow),
+            (one_of.is_some(), ParamName::OneOf),
+            (setter_body.is_some(), ParamName::SetterBody),
+            (const_field.is_some(), ParamName::ConstField),
+            (init_after.is_some(), ParamName::InitAfter),
         ];
 
         attrs
@@ -127,6 +259,104 @@ impl MemberParams {
             )?;
         }
 
+        if self.into_option.is_present() {
+            self.validate_mutually_allowed(
+                ParamName::IntoOption,
+                self.into_option.span(),
+                &[
+                    ParamName::Default,
+                    ParamName::Name,
+                    ParamName::Checked,
+                    ParamName::InitAfter,
+                ],
+            )?;
+        }
+
+        if self.bitor.is_present() {
+            self.validate_mutually_allowed(
+                ParamName::BitOr,
+                self.bitor.span(),
+                &[
+                    ParamName::Default,
+                    ParamName::Into,
+                    ParamName::Name,
+                    ParamName::ViaFn,
+                    ParamName::FinishByRef,
+                    ParamName::InitAfter,
+                ],
+            )?;
+        }
+
+        if self.collection.is_present() {
+            self.validate_mutually_allowed(
+                ParamName::Collection,
+                self.collection.span(),
+                &[
+                    ParamName::Default,
+                    ParamName::Into,
+                    ParamName::Name,
+                    ParamName::ItemValidate,
+                    ParamName::InitAfter,
+                ],
+            )?;
+        }
+
+        if self.borrow.is_present() {
+            self.validate_mutually_allowed(
+                ParamName::Borrow,
+                self.borrow.span(),
+                &[
+                    ParamName::Default,
+                    ParamName::Name,
+                    ParamName::Checked,
+                    ParamName::InitAfter,
+                ],
+            )?;
+        }
+
+        if let Some(group) = &self.one_of {
+            self.validate_mutually_allowed(
+                ParamName::OneOf,
+                group.span(),
+                &[
+                    ParamName::Into,
+                    ParamName::Name,
+                    ParamName::Checked,
+                    ParamName::Borrow,
+                    ParamName::InitAfter,
+                ],
+            )?;
+        }
+
+        if let Some(setter_body) = &self.setter_body {
+            self.validate_mutually_allowed(
+                ParamName::SetterBody,
+                setter_body.span(),
+                &[
+                    ParamName::Default,
+                    ParamName::Into,
+                    ParamName::IntoOption,
+                    ParamName::Name,
+                    ParamName::Checked,
+                    ParamName::FinishByRef,
+                    ParamName::ViaFn,
+                    ParamName::RequiredMessage,
+                    ParamName::Borrow,
+                    ParamName::OneOf,
+                    ParamName::InitAfter,
+                ],
+            )?;
+        }
+
+        if let Some(item_validate) = &self.item_validate {
+            if !self.collection.is_present() {
+                bail!(
+                    &item_validate.span(),
+                    "`item_validate` can be used only together with `collection`",
+                );
+            }
+        }
+
         if let Some(skip) = &self.skip {
             match origin {
                 MemberOrigin::FnArg => {
@@ -148,7 +378,35 @@ impl MemberParams {
                 );
             }
 
-            self.validate_mutually_allowed(ParamName::Skip, skip.span(), &[])?;
+            self.validate_mutually_allowed(ParamName::Skip, skip.span(), &[ParamName::InitAfter])?;
+        }
+
+        if let Some(const_field) = &self.const_field {
+            match origin {
+                MemberOrigin::FnArg => {
+                    bail!(
+                        &const_field.value,
+                        "`const_field` attribute is not supported on function arguments. \
+                        Use a local constant instead.",
+                    );
+                }
+                MemberOrigin::StructField => {}
+            }
+
+            self.validate_mutually_allowed(ParamName::ConstField, const_field.value.span(), &[])?;
+        }
+
+        if self.finish_by_ref.is_present() {
+            match origin {
+                MemberOrigin::FnArg => {
+                    bail!(
+                        &self.finish_by_ref.span(),
+                        "`finish_by_ref` attribute is not supported on function arguments; \
+                        it can be used only with `#[builder(finish_with = ...)]` on structs",
+                    );
+                }
+                MemberOrigin::StructField => {}
+            }
         }
 
         Ok(())
@@ -162,3 +420,27 @@ fn parse_optional_expression(meta: &syn::Meta) -> Result<SpannedValue<Option<syn
         syn::Meta::NameValue(nv) => Ok(SpannedValue::new(Some(nv.value.clone()), nv.span())),
     }
 }
+
+fn parse_const_field(meta: &syn::Meta) -> Result<ConstFieldParams> {
+    match meta {
+        syn::Meta::NameValue(nv) => Ok(ConstFieldParams {
+            name: None,
+            value: nv.value.clone(),
+        }),
+        syn::Meta::List(_) => Ok(ConstFieldParams::from_meta(meta)?),
+        syn::Meta::Path(_) => bail!(
+            meta,
+            "expected `const_field = expression` or \
+            `const_field(name = NAME, value = expression)` syntax"
+        ),
+    }
+}
+
+fn parse_one_of(meta: &syn::Meta) -> Result<syn::Ident> {
+    let meta = match meta {
+        syn::Meta::List(meta) => meta,
+        _ => bail!(meta, "expected `one_of(group_name)` syntax"),
+    };
+
+    Ok(meta.parse_args()?)
+}
@@ -0,0 +1,175 @@
+use super::member::NamedMember;
+use super::BuilderGenCtx;
+use crate::util::prelude::*;
+use std::collections::BTreeMap;
+
+/// A method of the builder that isn't a setter of a member, but lives in the
+/// same namespace as the setters.
+struct ReservedMethod {
+    name: String,
+
+    /// Describes where the method comes from in the error message
+    origin: String,
+
+    /// Suggests how to rename the method instead of the setter
+    rename_hint: &'static str,
+}
+
+impl BuilderGenCtx {
+    /// All inherent methods of the builder share the same namespace, so we need
+    /// to make sure the setters don't collide with each other and with the other
+    /// generated methods. Otherwise, the compiler reports a cryptic "duplicate
+    /// definitions" error.
+    pub(crate) fn validate_method_names(&self) -> Result {
+        let reserved = self.reserved_methods();
+
+        // The names of the setters that were already generated for the
+        // previous members mapped to the names of the members
+        let mut setters = BTreeMap::new();
+
+        for member in self.named_members() {
+            let core_name = member.setter_method_core_name();
+
+            for setter in member_setter_names(member, self) {
+                if let Some(method) = reserved.iter().find(|method| method.name == setter) {
+                    bail!(
+                        core_name,
+                        "the setter `{setter}` for this member collides with {}; \
+                        consider renaming the setter with `#[builder(name = new_name)]`{}",
+                        method.origin,
+                        method.rename_hint,
+                    );
+                }
+
+                let other = setters.insert(setter.clone(), core_name.raw_name());
+
+                if let Some(other) = other {
+                    bail!(
+                        core_name,
+                        "the setter `{setter}` for this member collides with \
+                        the setter of the member `{other}`; consider renaming \
+                        one of the setters with `#[builder(name = new_name)]`",
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn reserved_methods(&self) -> Vec<ReservedMethod> {
+        let finish_fn = self.finish_func.ident.raw_name();
+        let finish_fn_hint = " or renaming the finishing function with \
+            `#[builder(finish_fn = new_name)]`";
+
+        let mut methods = vec![ReservedMethod {
+            name: finish_fn.clone(),
+            origin: format!("the finishing function `{finish_fn}` of the builder"),
+            rename_hint: finish_fn_hint,
+        }];
+
+        let finish_fn_methods = [
+            (self.view.is_some(), "view", "view = ..."),
+            (
+                self.finish_into_out.is_present(),
+                "into_out",
+                "finish_into_out",
+            ),
+            (
+                self.finish_into_slot.is_present(),
+                "into_slot",
+                "finish_into_slot",
+            ),
+            (
+                self.finish_and_reset.is_present(),
+                "and_reset",
+                "finish_and_reset",
+            ),
+        ];
+
+        for (enabled, suffix, attr) in finish_fn_methods {
+            if !enabled {
+                continue;
+            }
+
+            let name = format!("{finish_fn}_{suffix}");
+
+            methods.push(ReservedMethod {
+                origin: format!("the `{name}()` method requested with `#[builder({attr})]`"),
+                name,
+                rename_hint: finish_fn_hint,
+            });
+        }
+
+        let other_methods = [
+            (
+                self.builder_type.as_set_fields.is_present(),
+                "as_set_fields",
+                "as_set_fields",
+            ),
+            (
+                self.builder_type.capture_locations.is_present(),
+                super::capture_locations::METHOD_NAME,
+                "capture_locations",
+            ),
+        ];
+
+        for (enabled, name, attr) in other_methods {
+            if !enabled {
+                continue;
+            }
+
+            methods.push(ReservedMethod {
+                name: name.to_owned(),
+                origin: format!("the `{name}()` method requested with `#[builder({attr})]`"),
+                rename_hint: "",
+            });
+        }
+
+        methods.extend(self.delegate.iter().map(|sig| {
+            let name = sig.ident.raw_name();
+
+            ReservedMethod {
+                origin: format!("the `{name}()` method requested with `#[builder(delegate(...))]`"),
+                name,
+                rename_hint: " or removing the method from `delegate(...)`",
+            }
+        }));
+
+        methods
+    }
+}
+
+/// Names of all setters generated for the member, including the setters
+/// requested with the builder-level attributes.
+fn member_setter_names(member: &NamedMember, ctx: &BuilderGenCtx) -> Vec<String> {
+    let core_name = member.setter_method_core_name().raw_name();
+    let params = &member.params;
+
+    let mut names = vec![];
+
+    if params.collection.is_present() && params.item_validate.is_some() {
+        names.push(format!("try_{core_name}"));
+    } else {
+        names.push(core_name.clone());
+    }
+
+    if member.is_optional() && !member.is_accumulating() && params.one_of.is_none() {
+        names.push(format!("maybe_{core_name}"));
+
+        if ctx.builder_type.mut_setters.is_present() {
+            names.push(format!("set_{core_name}"));
+            names.push(format!("set_maybe_{core_name}"));
+        }
+    }
+
+    if params.via_fn.is_present() {
+        names.push(format!("{core_name}_via"));
+    }
+
+    if !member.is_optional() && ctx.builder_type.resettable.is_present() {
+        names.push(format!("reset_{core_name}"));
+    }
+
+    names
+}
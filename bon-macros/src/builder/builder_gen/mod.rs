@@ -1,7 +1,31 @@
+mod arbitrary;
+mod args_struct;
+mod assert_field_count;
+mod blanket_from;
+mod build_by_ref;
 mod builder_derives;
+mod builder_macro;
 mod builder_params;
+mod capture_locations;
+mod const_fields;
+mod delegate;
+mod ffi_ctor;
+mod finish_and_reset;
+mod finish_into_out;
+mod finish_into_slot;
+mod finish_trait;
+mod generate_test;
+mod json_schema;
 mod member;
+mod method_names;
+mod mut_setters;
+mod one_of;
+mod removed_setters;
+mod reset_setters;
+mod set_fields;
 mod setter_methods;
+mod setter_trait;
+mod view;
 
 pub(crate) mod input_func;
 pub(crate) mod input_struct;
@@ -43,11 +67,63 @@ pub(crate) struct BuilderGenCtx {
     builder_type: BuilderType,
     start_func: StartFunc,
     finish_func: FinishFunc,
+
+    /// Name of the `extern "C"` constructor function to generate if requested
+    ffi_ctor: Option<syn::Ident>,
+
+    /// The struct with all members to generate for the `from_args`
+    /// constructor if requested
+    args_struct: Option<args_struct::ArgsStruct>,
+
+    /// Path to the view type to generate the borrowing finishing method for
+    /// if requested
+    view: Option<syn::Path>,
+
+    /// Generate the `{finish_fn}_and_reset()` method that also returns
+    /// a new builder in the initial state
+    finish_and_reset: darling::util::Flag,
+
+    /// Generate the `{finish_fn}_into_out()` method that writes the built
+    /// value into a `MaybeUninit` slot
+    finish_into_out: darling::util::Flag,
+
+    /// Generate the `{finish_fn}_into_slot()` method that stores the built
+    /// value in an `Option` slot
+    finish_into_slot: darling::util::Flag,
+
+    /// Generate the finishing function that takes the builder by reference
+    /// and clones the members' values
+    build_by_ref: darling::util::Flag,
+
+    /// Signatures of the methods of the built value to generate on the builder
+    delegate: Vec<syn::Signature>,
+
+    /// Names and types of the removed members to generate the no-op
+    /// `#[deprecated]` setters for
+    removed_setters: Vec<syn::Field>,
+
+    /// Generate a blanket `From` impl for the struct with a single member
+    blanket_from: darling::util::Flag,
+
+    /// Generate a test that builds the value with the default values of members
+    generate_test: Option<generate_test::GenerateTest>,
+
+    /// Generate a method that builds the value from the `arbitrary` crate's input
+    arbitrary: darling::util::Flag,
+
+    /// Name of the `macro_rules!` macro that builds the value from `member: value` pairs
+    builder_macro: Option<syn::Ident>,
+
+    /// Expected number of fields of the struct to check at compile time
+    assert_field_count: Option<syn::Expr>,
 }
 
 struct FinishFunc {
     ident: syn::Ident,
 
+    /// If present overrides the visibility of the builder
+    vis: Option<syn::Visibility>,
+
     /// Additional attributes to apply to the item
     attrs: Vec<syn::Attribute>,
 
@@ -57,6 +133,13 @@ struct FinishFunc {
     must_use: Option<syn::Attribute>,
     body: Box<dyn FinishFuncBody>,
     output: syn::ReturnType,
+
+    /// Additional `where` predicates that apply only to the finishing function
+    where_predicates: Vec<syn::WherePredicate>,
+
+    /// Type parameters of the builder that can be specified with a turbofish
+    /// at the finishing function
+    turbofish_generics: Vec<syn::Ident>,
 }
 
 struct StartFunc {
@@ -70,6 +153,51 @@ struct StartFunc {
 
     /// If present overrides the automatic visibility
     vis: Option<syn::Visibility>,
+
+    /// Type to generate the start fn on instead of the struct itself
+    on: Option<syn::Type>,
+
+    /// Generate an additional `{start_fn}_with_defaults` start function
+    with_defaults: darling::util::Flag,
+}
+
+/// Generates the `# Setters` section for the docs of the start function requested
+/// with `#[builder(doc_setters)]`. It lists the setters of every member and
+/// tells whether the member is required or optional.
+fn setters_list_docs(members: &[Member]) -> Vec<syn::Attribute> {
+    let items = members.iter().filter_map(Member::as_named).map(|member| {
+        let name = member.setter_method_core_name().raw_name();
+        let mut setters = if member.params.item_validate.is_some() {
+            vec![format!("`try_{name}()`")]
+        } else {
+            vec![format!("`{name}()`")]
+        };
+
+        if member.is_optional() && !member.is_accumulating() && member.params.one_of.is_none() {
+            setters.push(format!("`maybe_{name}()`"));
+        }
+
+        if member.params.via_fn.is_present() {
+            setters.push(format!("`{name}_via()`"));
+        }
+
+        let kind = match &member.params.one_of {
+            Some(group) => format!("one of `{group}`"),
+            None if member.is_optional() => "optional".to_owned(),
+            None => "required".to_owned(),
+        };
+
+        format!("- {} ({kind})", setters.join(", "))
+    });
+
+    let lines = ["", "# Setters", ""]
+        .into_iter()
+        .map(ToOwned::to_owned)
+        .chain(items);
+
+    lines
+        .map(|line| syn::parse_quote!(#[doc = #line]))
+        .collect()
 }
 
 struct BuilderType {
@@ -77,8 +205,35 @@ struct BuilderType {
 
     derives: BuilderDerives,
 
+    /// Generate an `as_set_fields()` method that borrows the set members
+    as_set_fields: darling::util::Flag,
+
+    /// Log every setter call with `defmt::trace!`
+    defmt_trace: darling::util::Flag,
+
+    /// Record the location of the last setter call for every member
+    capture_locations: darling::util::Flag,
+
+    /// Generate the `&mut self` setters for the optional members
+    mut_setters: darling::util::Flag,
+
+    /// Generate the `reset_{member}()` methods for the required members
+    resettable: darling::util::Flag,
+
+    /// Generate a `json_schema()` function that describes the members
+    json_schema: darling::util::Flag,
+
+    /// Generate a `REQUIRED_FIELDS` constant with the names of the required members
+    required_fields: darling::util::Flag,
+
     /// Optional docs override
     docs: Option<Vec<syn::Attribute>>,
+
+    /// Name of the trait with the setters' signatures to generate if requested
+    setter_trait: Option<syn::Ident>,
+
+    /// Derive `Debug` for the builder only under `cfg(test)`
+    test_only_debug: darling::util::Flag,
 }
 
 pub(crate) trait FinishFuncBody {
@@ -103,6 +258,10 @@ struct Generics {
     /// Mirrors the `decl` representing how generic params should be represented
     /// when these parameters are passed through as arguments in a turbofish.
     args: Vec<syn::GenericArgument>,
+
+    /// The generic parameter of the builder type that holds the states of
+    /// the members. It doesn't collide with any of the generic params above.
+    state_var: syn::Ident,
 }
 
 impl Generics {
@@ -132,12 +291,37 @@ impl Generics {
             .map(generic_param_to_arg)
             .collect();
 
-        Self {
+        let mut me = Self {
             where_clause,
             decl_with_defaults,
             decl_without_defaults,
             args,
+            state_var: quote::format_ident!("___State"),
+        };
+
+        me.state_var = me.unique_ident(&me.state_var, &[]);
+        me
+    }
+
+    /// Appends underscores to the given identifier until it doesn't collide
+    /// with any of the type or const generic params nor with the `reserved`
+    /// identifiers. The macro generates its own generic params in the same
+    /// namespace as the params declared by the user, so it must avoid clashes.
+    fn unique_ident(&self, ident: &syn::Ident, reserved: &[&syn::Ident]) -> syn::Ident {
+        let is_taken = |candidate: &syn::Ident| {
+            reserved.contains(&candidate)
+                || self.decl_with_defaults.iter().any(|param| match param {
+                    syn::GenericParam::Type(param) => param.ident == *candidate,
+                    syn::GenericParam::Const(param) => param.ident == *candidate,
+                    syn::GenericParam::Lifetime(_) => false,
+                })
+        };
+
+        let mut ident = ident.clone();
+        while is_taken(&ident) {
+            ident = syn::Ident::new(&format!("{}_", ident.raw_name()), ident.span());
         }
+        ident
     }
 
     fn where_clause_predicates(&self) -> impl Iterator<Item = &syn::WherePredicate> {
@@ -150,6 +334,10 @@ impl Generics {
 
 pub(crate) struct MacroOutput {
     pub(crate) start_func: syn::ItemFn,
+
+    /// The start function requested with `#[builder(start_with_defaults)]`.
+    /// It must be placed next to the main start function.
+    pub(crate) start_func_with_defaults: Option<syn::ItemFn>,
     pub(crate) other_items: TokenStream2,
 }
 
@@ -162,15 +350,47 @@ impl BuilderGenCtx {
         self.members.iter().filter_map(Member::as_named)
     }
 
+    /// Named members in the order of their states in the builder's state tuple
+    fn state_members(&self) -> Vec<&NamedMember> {
+        let mut members: Vec<_> = self.named_members().collect();
+        members.sort_by_key(|member| member.index.index);
+        members
+    }
+
     fn start_fn_args(&self) -> impl Iterator<Item = &StartFnArgMember> {
         self.members.iter().filter_map(Member::as_start_fn_arg)
     }
 
     pub(crate) fn output(self) -> Result<MacroOutput> {
+        self.validate_method_names()?;
+
         let mut start_func = self.start_func()?;
+        let mut start_func_with_defaults = self.start_func_with_defaults()?;
         let builder_decl = self.builder_decl();
         let builder_impl = self.builder_impl()?;
-        let builder_derives = self.builder_derives();
+        let required_fields = self.required_fields();
+        let json_schema = self.json_schema();
+        let set_fields = self.set_fields();
+        let field_location = self.field_location();
+        let mut_setters = self.mut_setters()?;
+        let reset_setters = self.reset_setters()?;
+        let builder_derives = self.builder_derives()?;
+        let setter_trait = self.setter_trait()?;
+        let ffi_ctor = self.ffi_ctor()?;
+        let args_struct = self.args_struct()?;
+        let view = self.view();
+        let finish_and_reset = self.finish_and_reset()?;
+        let finish_into_out = self.finish_into_out()?;
+        let finish_into_slot = self.finish_into_slot()?;
+        let finish_trait = self.finish_trait();
+        let delegate = self.delegate()?;
+        let removed_setters = self.removed_setters()?;
+        let blanket_from = self.blanket_from()?;
+        let generate_test = self.generate_test()?;
+        let arbitrary = self.arbitrary()?;
+        let builder_macro = self.builder_macro()?;
+        let assert_field_count = self.assert_field_count();
+        let const_fields = self.const_fields();
 
         // -- Postprocessing --
         // Here we parse all items back and add the `allow` attributes to them.
@@ -178,20 +398,61 @@ impl BuilderGenCtx {
             #builder_decl
             #builder_derives
             #builder_impl
+            #required_fields
+            #json_schema
+            #set_fields
+            #field_location
+            #mut_setters
+            #reset_setters
+            #setter_trait
+            #ffi_ctor
+            #args_struct
+            #view
+            #finish_and_reset
+            #finish_into_out
+            #finish_into_slot
+            #finish_trait
+            #delegate
+            #removed_setters
+            #blanket_from
+            #generate_test
+            #arbitrary
+            #builder_macro
+            #assert_field_count
+            #const_fields
         };
 
         let mut other_items = other_items.items;
 
+        let mut allow_attrs = self.allow_attrs;
+
+        // The member names are used as the names of setters and variables in the
+        // generated code, so the members that have `#[allow(non_snake_case)]` on
+        // them would trigger this lint in the generated code otherwise.
+        let has_non_snake_case_members = self
+            .members
+            .iter()
+            .any(|member| !member.orig_ident().is_snake_case());
+
+        if has_non_snake_case_members {
+            allow_attrs.push(syn::parse_quote!(#[allow(non_snake_case)]));
+        }
+
         for item in &mut other_items {
             if let Some(attrs) = item.attrs_mut() {
-                attrs.extend(self.allow_attrs.iter().cloned());
+                attrs.extend(allow_attrs.iter().cloned());
             }
         }
 
-        start_func.attrs.extend(self.allow_attrs);
+        if let Some(func) = &mut start_func_with_defaults {
+            func.attrs.extend(allow_attrs.iter().cloned());
+        }
+
+        start_func.attrs.extend(allow_attrs);
 
         Ok(MacroOutput {
             start_func,
+            start_func_with_defaults,
             other_items: quote!(#(#other_items)*),
         })
     }
@@ -204,7 +465,8 @@ impl BuilderGenCtx {
         let generic_args = &self.generics.args;
         let where_clause = &self.generics.where_clause;
         let state_type_vars = self
-            .named_members()
+            .state_members()
+            .into_iter()
             .map(|member| &member.generic_var_ident)
             .collect::<Vec<_>>();
 
@@ -216,6 +478,12 @@ impl BuilderGenCtx {
             .named_members()
             .map(|member| self.members_label(member));
 
+        let required_message_traits = self
+            .named_members()
+            .filter_map(|member| self.required_message_trait(member));
+
+        let one_of_traits = self.one_of_traits()?;
+
         let vis = &self.vis;
 
         Ok(quote! {
@@ -227,6 +495,9 @@ impl BuilderGenCtx {
                 #vis struct #named_members_labels;
             )*
 
+            #(#required_message_traits)*
+            #one_of_traits
+
             #allows
             #[automatically_derived]
             impl<
@@ -245,6 +516,55 @@ impl BuilderGenCtx {
         })
     }
 
+    /// Generates the `REQUIRED_FIELDS` associated constant on the builder requested
+    /// with `#[builder(required_fields)]`. It lives in a separate `impl` block for
+    /// the initial state of the builder, so that the state type parameter falls back
+    /// to its default, and users can reference the constant as
+    /// `{Builder}::REQUIRED_FIELDS` without specifying the state.
+    fn required_fields(&self) -> Option<TokenStream2> {
+        if !self.builder_type.required_fields.is_present() {
+            return None;
+        }
+
+        let generics_decl = &self.generics.decl_without_defaults;
+        let generic_args = &self.generics.args;
+        let where_clause = &self.generics.where_clause;
+        let builder_ident = &self.builder_type.ident;
+        let vis = &self.vis;
+
+        let required_fields = self
+            .members
+            .iter()
+            .filter(|member| match member {
+                Member::Named(member) => !member.is_optional(),
+                Member::StartFnArg(_) | Member::FinishFnArg(_) => true,
+                Member::Skipped(_) => false,
+            })
+            .map(|member| member.orig_ident().raw_name());
+
+        let allows = allow_warnings_on_member_types();
+
+        Some(quote! {
+            #allows
+            #[automatically_derived]
+            impl<#(#generics_decl,)*> #builder_ident<#(#generic_args,)*>
+            #where_clause
+            {
+                /// Names of the members that must be specified to finish building
+                /// in the order of their declaration. Optional and skipped members
+                /// aren't included.
+                #vis const REQUIRED_FIELDS: &'static [&'static str] = &[
+                    #(#required_fields,)*
+                ];
+            }
+        })
+    }
+
+    /// Type that the start fn should be placed on instead of the struct itself
+    pub(crate) fn start_func_on(&self) -> Option<&syn::Type> {
+        self.start_func.on.as_ref()
+    }
+
     fn start_func_generics(&self) -> &Generics {
         self.start_func.generics.as_ref().unwrap_or(&self.generics)
     }
@@ -285,9 +605,44 @@ impl BuilderGenCtx {
     }
 
     fn start_func(&self) -> Result<syn::ItemFn> {
+        self.start_func_in_state(false)
+    }
+
+    /// Generates the additional start function requested with
+    /// `#[builder(start_with_defaults)]`. It returns the builder where all
+    /// optional members are already set. Their values are left as `None`, so
+    /// the default values are computed by the finishing function the same way
+    /// as if the optional members were never set.
+    fn start_func_with_defaults(&self) -> Result<Option<syn::ItemFn>> {
+        if !self.start_func.with_defaults.is_present() {
+            return Ok(None);
+        }
+
+        self.start_func_in_state(true).map(Some)
+    }
+
+    /// Values of the members' states in the builder returned from the start
+    /// function. If `with_defaults` is `true`, then the optional members are
+    /// already set to `None`, except for the `one_of` members, because only one
+    /// member of their group can be set.
+    fn start_state_literals(&self, with_defaults: bool) -> Vec<TokenStream2> {
+        self.state_members()
+            .into_iter()
+            .map(|member| {
+                if !member.is_optional() {
+                    quote!(::bon::private::Unset(::bon::private::Required))
+                } else if with_defaults && member.params.one_of.is_none() {
+                    quote!(::bon::private::Set(::core::option::Option::None))
+                } else {
+                    quote!(::bon::private::Unset(::bon::private::Optional))
+                }
+            })
+            .collect()
+    }
+
+    fn start_func_in_state(&self, with_defaults: bool) -> Result<syn::ItemFn> {
         let builder_ident = &self.builder_type.ident;
 
-        let docs = &self.start_func.attrs;
         let vis = self.start_func.vis.as_ref().unwrap_or(&self.vis);
 
         let start_func_ident = &self.start_func.ident;
@@ -319,13 +674,46 @@ impl BuilderGenCtx {
             quote! { #receiver, }
         });
 
-        let unset_state_literals = self.named_members().map(|member| {
-            if member.is_optional() {
-                quote!(::bon::private::Unset(::bon::private::Optional))
+        let state_literals = self.start_state_literals(with_defaults);
+
+        let (start_func_ident, docs, state_type) = if with_defaults {
+            let ident = quote::format_ident!("{}_with_defaults", start_func_ident.raw_name());
+
+            let self_prefix = if self.assoc_method_ctx.is_some() {
+                "Self::"
             } else {
-                quote!(::bon::private::Unset(::bon::private::Required))
-            }
-        });
+                ""
+            };
+
+            let docs = format!(
+                "Same as [`{self_prefix}{start_func_ident}()`], but all optional members \
+                are already set to their default values, so only the required members \
+                are left to be set"
+            );
+
+            let state_types = self.state_members().into_iter().map(|member| {
+                if !member.is_optional() {
+                    quote!(::bon::private::Unset<::bon::private::Required>)
+                } else if member.params.one_of.is_none() {
+                    let ty = member.set_state_type_param();
+                    quote!(::bon::private::Set<#ty>)
+                } else {
+                    quote!(::bon::private::Unset<::bon::private::Optional>)
+                }
+            });
+
+            (
+                ident,
+                vec![syn::parse_quote!(#[doc = #docs])],
+                Some(quote!((#(#state_types,)*))),
+            )
+        } else {
+            (
+                start_func_ident.clone(),
+                self.start_func.attrs.clone(),
+                None,
+            )
+        };
 
         let start_fn_params = self
             .start_fn_args()
@@ -344,9 +732,18 @@ impl BuilderGenCtx {
         });
 
         let ide_hints = self.ide_hints();
+        let locations_field_init = self.locations_field_init();
+
+        // The builder type isn't `#[must_use]` to avoid duplicate warnings
+        // with the setters, so the functions that create it have the attribute
+        let must_use_message = format!(
+            "the builder does nothing until you call `{}()` on it to finish building",
+            self.finish_func.ident
+        );
 
         let func = quote! {
             #(#docs)*
+            #[must_use = #must_use_message]
             #[inline(always)]
             #[allow(
                 // This is intentional. We want the builder syntax to compile away
@@ -360,7 +757,7 @@ impl BuilderGenCtx {
             #vis fn #start_func_ident<#(#generics_decl),*>(
                 #receiver
                 #(#start_fn_params,)*
-            ) -> #builder_ident<#(#generic_args,)*>
+            ) -> #builder_ident<#(#generic_args,)* #state_type>
             #where_clause
             {
                 #ide_hints
@@ -369,7 +766,8 @@ impl BuilderGenCtx {
                     __private_phantom: ::core::marker::PhantomData,
                     #receiver_field_init
                     #start_fn_args_field_init
-                    __private_named_members: (#( #unset_state_literals, )*)
+                    #locations_field_init
+                    __private_named_members: (#( #state_literals, )*)
                 }
             }
         };
@@ -401,6 +799,8 @@ impl BuilderGenCtx {
                 quote!(::core::marker::PhantomData<#ty>)
             });
 
+        let state_var = &self.generics.state_var;
+
         quote! {
             ::core::marker::PhantomData<(
                 // There is an interesting quirk with lifetimes in Rust, which is the
@@ -426,9 +826,9 @@ impl BuilderGenCtx {
                 // explanation for it, I just didn't care to research it yet ¯\_(ツ)_/¯.
                 #(#types,)*
 
-                // A special case of zero members requires storing `___State` in phantom data
+                // A special case of zero members requires storing the state in phantom data
                 // otherwise it would be reported as an unused type parameter.
-                ::core::marker::PhantomData<___State>
+                ::core::marker::PhantomData<#state_var>
             )>
         }
     }
@@ -439,6 +839,7 @@ impl BuilderGenCtx {
         let generics_decl = &self.generics.decl_with_defaults;
         let where_clause = &self.generics.where_clause;
         let phantom_data = self.phantom_data();
+        let state_var = &self.generics.state_var;
 
         let private_field_doc = "\
             Please don't touch this field. It's an implementation \
@@ -456,10 +857,12 @@ impl BuilderGenCtx {
             }
         });
 
-        let must_use_message = format!(
-            "the builder does nothing until you call `{}()` on it to finish building",
-            self.finish_func.ident
-        );
+        let locations_field = self.locations_field_type().map(|ty| {
+            quote! {
+                #[doc = #private_field_doc]
+                __private_locations: #ty,
+            }
+        });
 
         let docs = self.builder_type.docs.clone().unwrap_or_else(|| {
             let doc = format!(
@@ -478,7 +881,7 @@ impl BuilderGenCtx {
         let initial_state_type_alias_ident =
             quote::format_ident!("__{}InitialState", builder_ident.raw_name());
 
-        let unset_state_types = self.named_members().map(|member| {
+        let unset_state_types = self.state_members().into_iter().map(|member| {
             if member.is_optional() {
                 quote!(::bon::private::Unset<::bon::private::Optional>)
             } else {
@@ -508,7 +911,6 @@ impl BuilderGenCtx {
             #[doc(hidden)]
             #vis type #initial_state_type_alias_ident = (#(#unset_state_types,)*);
 
-            #[must_use = #must_use_message]
             #(#docs)*
             #allows
             #[allow(
@@ -523,7 +925,7 @@ impl BuilderGenCtx {
             )]
             #vis struct #builder_ident<
                 #(#generics_decl,)*
-                ___State = #initial_state_type_alias_ident
+                #state_var = #initial_state_type_alias_ident
             >
             #where_clause
             {
@@ -536,9 +938,10 @@ impl BuilderGenCtx {
 
                 #receiver_field
                 #start_fn_arg_types_field
+                #locations_field
 
                 #[doc = #private_field_doc]
-                __private_named_members: ___State
+                __private_named_members: #state_var
             }
         }
     }
@@ -547,6 +950,16 @@ impl BuilderGenCtx {
         let member = match member {
             Member::Named(member) => member,
             Member::Skipped(member) => {
+                if let Some(const_ident) = &member.const_ident {
+                    let self_ty = self
+                        .assoc_method_ctx
+                        .as_ref()
+                        .map(|ctx| &ctx.self_ty)
+                        .expect("BUG: `const_field` members exist only in structs");
+
+                    return Ok(quote! { <#self_ty>::#const_ident });
+                }
+
                 let expr = member
                     .value
                     .as_ref()
@@ -558,14 +971,36 @@ impl BuilderGenCtx {
             }
             Member::StartFnArg(member) => {
                 let index = &member.index;
-                return Ok(quote! { self.__private_start_fn_args.#index });
+                return Ok(
+                    self.finish_fn_take_field(quote! { self.__private_start_fn_args.#index })
+                );
             }
             Member::FinishFnArg(member) => {
                 return member.maybe_into_ident_expr(&self.on_params);
             }
         };
 
-        let maybe_default = member
+        let maybe_default = self.member_default_fallback(member)?;
+
+        let index = &member.index;
+        let member_type_var = &member.generic_var_ident;
+        let into_set_trait = self.member_into_set_trait(member);
+        let field = self.finish_fn_take_field(quote!(self.__private_named_members.#index));
+
+        let expr = quote! {
+            <#member_type_var as #into_set_trait>::into_set(#field)
+            #maybe_default
+        };
+
+        Ok(expr)
+    }
+
+    /// Generates the method call that turns the `Option<T>` value of an optional
+    /// member into the `T` by falling back to the default value if it's `None`.
+    /// Returns `None` for the members of type `Option<T>`, because they don't
+    /// need any fallback.
+    fn member_default_fallback(&self, member: &NamedMember) -> Result<Option<TokenStream2>> {
+        member
             .as_optional_norm_ty()
             // For `Option` members we don't need any `unwrap_or_[else/default]`.
             // The implementation of `From<Unset> for Set<Option<T>>` already
@@ -587,21 +1022,7 @@ impl BuilderGenCtx {
                     })
                     .unwrap_or_else(|| Ok(quote! { .unwrap_or_default() }))
             })
-            .transpose()?;
-
-        let index = &member.index;
-        let set_state_type_param = member.set_state_type_param();
-        let member_label = self.members_label(member);
-
-        let expr = quote! {
-            ::bon::private::IntoSet::<
-                #set_state_type_param,
-                #member_label
-            >::into_set(self.__private_named_members.#index)
-            #maybe_default
-        };
-
-        Ok(expr)
+            .transpose()
     }
 
     /// Name of the dummy struct that is generated just to give a name for
@@ -614,10 +1035,159 @@ impl BuilderGenCtx {
         )
     }
 
-    fn finish_method(&self) -> Result<TokenStream2> {
-        let members_vars_decls = self
+    /// The trait that transitions the member's state into its value in the
+    /// finishing function. Members with a custom `required_message` use a
+    /// dedicated trait generated by [`Self::required_message_trait`] that
+    /// reports the message when the member isn't set.
+    fn member_into_set_trait(&self, member: &NamedMember) -> TokenStream2 {
+        let set_state_type_param = member.set_state_type_param();
+
+        if member.params.required_message.is_some() {
+            let trait_ident = self.required_message_trait_ident(member);
+            return quote!(#trait_ident<#set_state_type_param>);
+        }
+
+        let member_label = self.members_label(member);
+        quote!(::bon::private::IntoSet<#set_state_type_param, #member_label>)
+    }
+
+    fn required_message_trait_ident(&self, member: &NamedMember) -> syn::Ident {
+        quote::format_ident!("{}__Required", self.members_label(member))
+    }
+
+    /// Generates the replacement for the `IntoSet` trait for the member with
+    /// a custom `required_message`. The `#[diagnostic::on_unimplemented]` message
+    /// can't be specified dynamically, so every such member needs its own trait.
+    fn required_message_trait(&self, member: &NamedMember) -> Option<TokenStream2> {
+        let message = member.params.required_message.as_ref()?;
+
+        // Curly braces are interpreted as format parameters by the compiler
+        let message = message.value().replace('{', "{{").replace('}', "}}");
+        let label = format!("the member `{}` was not set", self.members_label(member));
+
+        let trait_ident = self.required_message_trait_ident(member);
+        let vis = &self.vis;
+
+        Some(quote! {
+            #[::bon::private::rustversion::attr(
+                since(1.78.0),
+                diagnostic::on_unimplemented(message = #message, label = #label)
+            )]
+            #[allow(non_camel_case_types)]
+            #[doc(hidden)]
+            #vis trait #trait_ident<T> {
+                fn into_set(self) -> T;
+            }
+
+            #[automatically_derived]
+            impl<T> #trait_ident<T> for ::bon::private::Set<T> {
+                #[inline(always)]
+                fn into_set(self) -> T {
+                    self.0
+                }
+            }
+        })
+    }
+
+    /// Members in the order of their evaluation in the finishing function.
+    /// It's the order of their declaration except that every member with
+    /// `#[builder(init_after = other)]` is moved right after the `other` member
+    /// (after the members that precede it in the declaration order and also
+    /// have `init_after = other`).
+    fn members_in_init_order(&self) -> Result<Vec<&Member>> {
+        let find = |ident: &syn::Ident| {
+            self.members
+                .iter()
+                .position(|member| member.orig_ident() == ident)
+        };
+
+        // Index of the member that every member must be initialized after
+        let deps = self
             .members
             .iter()
+            .map(|member| {
+                member
+                    .init_after()
+                    .map(|dep| match find(dep) {
+                        Some(index) => Ok(index),
+                        None => bail!(dep, "`init_after` references an unknown member `{dep}`"),
+                    })
+                    .transpose()
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        fn push_with_dependents(index: usize, deps: &[Option<usize>], order: &mut Vec<usize>) {
+            order.push(index);
+
+            for (dependent, _) in deps
+                .iter()
+                .enumerate()
+                .filter(|(_, dep)| **dep == Some(index))
+            {
+                push_with_dependents(dependent, deps, order);
+            }
+        }
+
+        let mut order = Vec::with_capacity(self.members.len());
+
+        for (index, _) in deps.iter().enumerate().filter(|(_, dep)| dep.is_none()) {
+            push_with_dependents(index, &deps, &mut order);
+        }
+
+        // The members that weren't reached from the members without `init_after`
+        // depend on a cycle. Follow the chain of dependencies to report it.
+        if let Some(first) = (0..self.members.len()).find(|index| !order.contains(index)) {
+            let mut chain = vec![first];
+            let mut current = first;
+
+            while let Some(next) = deps[current] {
+                if chain.contains(&next) {
+                    chain.push(next);
+                    break;
+                }
+                chain.push(next);
+                current = next;
+            }
+
+            let cycle = chain
+                .iter()
+                .map(|&index| format!("`{}`", self.members[index].orig_ident()))
+                .join(" -> ");
+
+            bail!(
+                self.members[first]
+                    .init_after()
+                    .expect("BUG: the members without `init_after` are always ordered"),
+                "`init_after` forms a cycle: {cycle}"
+            );
+        }
+
+        Ok(order
+            .into_iter()
+            .map(|index| &self.members[index])
+            .collect())
+    }
+
+    fn finish_method(&self) -> Result<TokenStream2> {
+        // The members are evaluated in the order of their declaration unless
+        // `init_after` overrides it. This is a documented guarantee, so the side
+        // effects in `skip` and `default` expressions happen in a predictable
+        // sequence.
+        //
+        // Every member is bound to a local variable before the final value is
+        // constructed. If some `default` expression panics, then the variables
+        // of the members evaluated before it, and the members still stored in
+        // the builder, are dropped during unwinding.
+        //
+        // The members are moved out of the builder, which is consumed by value.
+        // The builder doesn't implement `Drop`, and it has no `unsafe` storage,
+        // so the compiler guarantees that every member is moved into the final
+        // value exactly once, and the rest of the builder is dropped without it.
+        // With `build_by_ref` the members are cloned instead, and the builder
+        // keeps owning the original values.
+        let members_vars_decls = self
+            .members_in_init_order()?
+            .into_iter()
             .map(|member| {
                 let expr = self.member_expr(member)?;
                 let var_ident = member.orig_ident();
@@ -643,19 +1213,19 @@ impl BuilderGenCtx {
         let unsafety = &self.finish_func.unsafety;
         let must_use = &self.finish_func.must_use;
         let attrs = &self.finish_func.attrs;
-        let vis = &self.vis;
+        let vis = self.finish_func.vis.as_ref().unwrap_or(&self.vis);
         let finish_func_ident = &self.finish_func.ident;
         let output = &self.finish_func.output;
+        let finish_where_predicates = &self.finish_func.where_predicates;
+        let one_of_bounds = self.one_of_finish_bounds();
+        let by_ref_bounds = self.build_by_ref_bounds();
+        let receiver = self.finish_fn_receiver();
 
         let where_bounds = self.named_members().map(|member| {
             let member_type_var = &member.generic_var_ident;
-            let set_state_type_param = member.set_state_type_param();
-            let member_label = self.members_label(member);
+            let into_set_trait = self.member_into_set_trait(member);
             quote! {
-                #member_type_var: ::bon::private::IntoSet<
-                    #set_state_type_param,
-                    #member_label
-                >
+                #member_type_var: #into_set_trait
             }
         });
 
@@ -666,6 +1236,40 @@ impl BuilderGenCtx {
             .map(|member| member.fn_input_param(&self.on_params))
             .collect::<Result<Vec<_>>>()?;
 
+        // Every turbofish parameter is bound to be equal to the corresponding
+        // type parameter of the builder in both directions. This way the compiler
+        // infers one from the other whichever of them is known.
+        let mut reserved: Vec<_> = self
+            .named_members()
+            .map(|member| member.generic_var_ident.clone())
+            .collect();
+
+        let turbofish_params = self
+            .finish_func
+            .turbofish_generics
+            .iter()
+            .map(|param| {
+                let witness = quote::format_ident!("__{}", param.raw_name());
+                let witness = self
+                    .generics
+                    .unique_ident(&witness, &reserved.iter().collect::<Vec<_>>());
+
+                reserved.push(witness.clone());
+                (param, witness)
+            })
+            .collect::<Vec<_>>();
+
+        let turbofish_decl = turbofish_params
+            .iter()
+            .map(|(_, witness)| quote!(#witness: ?::core::marker::Sized));
+
+        let turbofish_bounds = turbofish_params.iter().map(|(param, witness)| {
+            quote! {
+                #param: ::bon::private::TypeEq<#witness>,
+                #witness: ::bon::private::TypeEq<#param>
+            }
+        });
+
         Ok(quote! {
             #(#attrs)*
             #[inline(always)]
@@ -680,12 +1284,16 @@ impl BuilderGenCtx {
                 clippy::future_not_send,
             )]
             #must_use
-            #vis #asyncness #unsafety fn #finish_func_ident(
-                self,
+            #vis #asyncness #unsafety fn #finish_func_ident<#(#turbofish_decl),*>(
+                #receiver,
                 #(#finish_fn_params,)*
             ) #output
             where
                 #(#where_bounds,)*
+                #(#by_ref_bounds,)*
+                #(#turbofish_bounds,)*
+                #(#finish_where_predicates,)*
+                #(#one_of_bounds)*
             {
                 #(#members_vars_decls)*
                 #body
@@ -693,6 +1301,28 @@ impl BuilderGenCtx {
         })
     }
 
+    /// The type of the builder after the setter for the given member is called
+    fn setter_next_state(&self, member: &NamedMember) -> TokenStream2 {
+        let builder_ident = &self.builder_type.ident;
+        let generic_args = &self.generics.args;
+
+        let state_types = self.state_members().into_iter().map(|other_member| {
+            if other_member.orig_ident == member.orig_ident {
+                let ty = member.set_state_type_param();
+                quote!(::bon::private::Set<#ty>)
+            } else {
+                other_member.generic_var_ident.to_token_stream()
+            }
+        });
+
+        quote! {
+            #builder_ident<
+                #(#generic_args,)*
+                (#(#state_types,)*)
+            >
+        }
+    }
+
     fn setter_methods(&self) -> Result<(TokenStream2, TokenStream2)> {
         let generics_decl = &self.generics.decl_without_defaults;
         let generic_args = &self.generics.args;
@@ -700,7 +1330,8 @@ impl BuilderGenCtx {
         let where_clause = &self.generics.where_clause;
 
         let state_type_vars = self
-            .named_members()
+            .state_members()
+            .into_iter()
             .map(|member| &member.generic_var_ident)
             .collect::<Vec<_>>();
 
@@ -719,23 +1350,8 @@ impl BuilderGenCtx {
         let setters = self
             .named_members()
             .map(|member| {
-                let state_types = self.named_members().map(|other_member| {
-                    if other_member.orig_ident == member.orig_ident {
-                        let ty = member.set_state_type_param();
-                        quote!(::bon::private::Set<#ty>)
-                    } else {
-                        other_member.generic_var_ident.to_token_stream()
-                    }
-                });
-
                 let member_pascal = &member.norm_ident_pascal;
-
-                let next_state = quote! {
-                    #builder_ident<
-                        #(#generic_args,)*
-                        (#(#state_types,)*)
-                    >
-                };
+                let next_state = self.setter_next_state(member);
 
                 let return_type = SettersReturnType {
                     doc_true: quote!(<Self as #next_state_trait_ident>::#member_pascal),
@@ -790,6 +1406,9 @@ impl BuilderGenCtx {
     }
 }
 
+/// Converts a generic parameter declaration into the argument that references it.
+/// Bounds and default values are dropped, and const params are turned into const
+/// arguments, so `<'a, T: Clone = u32, const N: usize = 3>` becomes `<'a, T, N>`.
 pub(crate) fn generic_param_to_arg(param: &syn::GenericParam) -> syn::GenericArgument {
     match param {
         syn::GenericParam::Lifetime(param) => {
@@ -0,0 +1,173 @@
+use super::{BuilderGenCtx, NamedMember};
+use crate::util::prelude::*;
+use quote::quote;
+
+impl BuilderGenCtx {
+    /// Generates the `&mut self` setters requested with `#[builder(mut_setters)]`.
+    ///
+    /// The setter of a required member must change the type of the builder to
+    /// record that the member is set, so only the regular consuming setters are
+    /// available for them. The optional members get the additional `set_{member}()`
+    /// and `set_maybe_{member}()` setters that overwrite the value of the member
+    /// that is already set. They don't change the type of the builder, so they
+    /// are available only in the states where the member is set, for example,
+    /// on the builder returned from the `{start_fn}_with_defaults()` function.
+    pub(crate) fn mut_setters(&self) -> Result<Option<TokenStream2>> {
+        if !self.builder_type.mut_setters.is_present() {
+            return Ok(None);
+        }
+
+        let methods = self
+            .named_members()
+            .filter(|member| {
+                member.is_optional() && !member.is_accumulating() && member.params.one_of.is_none()
+            })
+            .map(|member| self.member_mut_setters(member))
+            .collect::<Result<Vec<_>>>()?;
+
+        let generics_decl = &self.generics.decl_without_defaults;
+        let generic_args = &self.generics.args;
+        let where_clause = &self.generics.where_clause;
+        let builder_ident = &self.builder_type.ident;
+
+        let state_type_vars = self
+            .state_members()
+            .into_iter()
+            .map(|member| &member.generic_var_ident)
+            .collect::<Vec<_>>();
+
+        let allows = super::allow_warnings_on_member_types();
+
+        Ok(Some(quote! {
+            #allows
+            #[automatically_derived]
+            impl<
+                #(#generics_decl,)*
+                #(#state_type_vars,)*
+            >
+            #builder_ident<
+                #(#generic_args,)*
+                (#(#state_type_vars,)*)
+            >
+            #where_clause
+            {
+                #(#methods)*
+            }
+        }))
+    }
+
+    fn member_mut_setters(&self, member: &NamedMember) -> Result<TokenStream2> {
+        let inner_type = member
+            .as_optional_norm_ty()
+            .expect("BUG: only optional members have `&mut self` setters");
+
+        let has_into = member.param_into(&self.on_params)?;
+
+        let (inner_type_param, maybe_map_conv_call) = if has_into {
+            (quote!(impl Into<#inner_type>), quote!(.map(Into::into)))
+        } else {
+            (quote!(#inner_type), quote!())
+        };
+
+        let maybe_map_setter_body = member.maybe_map_setter_body(inner_type);
+
+        let (return_type, maybe_check, ret) = match &member.params.checked {
+            Some(checked) => {
+                let check = &checked.with;
+                let error = &checked.error;
+                (
+                    quote!(::core::result::Result<&mut Self, #error>),
+                    quote! {
+                        if let ::core::option::Option::Some(value) = &value {
+                            (#check)(value)?;
+                        }
+                    },
+                    quote!(::core::result::Result::Ok(self)),
+                )
+            }
+            None => (quote!(&mut Self), quote!(), quote!(self)),
+        };
+
+        let core_name = member.setter_method_core_name().raw_name();
+
+        // Preserve the original identifier span to make IDE's "go to definition" work correctly
+        let span = member.setter_method_core_name().span();
+        let set_ident = syn::Ident::new(&format!("set_{core_name}"), span);
+        let set_maybe_ident = syn::Ident::new(&format!("set_maybe_{core_name}"), span);
+
+        let member_type_var = &member.generic_var_ident;
+        let index = &member.index;
+        let vis = member.setter_vis.as_ref().unwrap_or(&self.vis);
+
+        let set_state_type_param = member.set_state_type_param();
+        let member_mut_bound = quote! {
+            #member_type_var: ::bon::private::MemberMut<#set_state_type_param>
+        };
+
+        let track_caller = self
+            .builder_type
+            .capture_locations
+            .is_present()
+            .then(|| quote!(#[track_caller]));
+
+        let maybe_record_location = self.locations_field_type().map(|_| {
+            let index = member.index.index as usize;
+            quote! {
+                self.__private_locations[#index] = ::core::option::Option::Some(
+                    ::core::panic::Location::caller()
+                );
+            }
+        });
+
+        let set_docs = format!(
+            "Same as [`Self::{core_name}()`], but overwrites the value of the member \
+            that is already set in place instead of changing the state of the builder."
+        );
+
+        let set_maybe_docs = format!(
+            "Same as [`Self::{set_ident}()`], but accepts an `Option` as input. \
+            Passing `None` resets the member to its default value."
+        );
+
+        Ok(quote! {
+            #[doc = #set_maybe_docs]
+            #[inline(always)]
+            #[allow(
+                // This is intentional. We want the builder syntax to compile away
+                clippy::inline_always,
+                clippy::impl_trait_in_params,
+            )]
+            #track_caller
+            #vis fn #set_maybe_ident(
+                &mut self,
+                value: Option<#inner_type_param>,
+            ) -> #return_type
+            where
+                #member_mut_bound
+            {
+                let value: Option<#inner_type> = value #maybe_map_conv_call #maybe_map_setter_body;
+                #maybe_check
+                #maybe_record_location
+                *::bon::private::MemberMut::member_mut(
+                    &mut self.__private_named_members.#index
+                ) = value;
+                #ret
+            }
+
+            #[doc = #set_docs]
+            #[inline(always)]
+            #[allow(
+                // This is intentional. We want the builder syntax to compile away
+                clippy::inline_always,
+                clippy::impl_trait_in_params,
+            )]
+            #track_caller
+            #vis fn #set_ident(&mut self, value: #inner_type_param) -> #return_type
+            where
+                #member_mut_bound
+            {
+                self.#set_maybe_ident(::core::option::Option::Some(value))
+            }
+        })
+    }
+}
@@ -0,0 +1,167 @@
+use super::{BuilderGenCtx, NamedMember};
+use crate::util::prelude::*;
+use quote::quote;
+
+impl BuilderGenCtx {
+    /// Groups of the members with `#[builder(one_of(group))]` in the order
+    /// of the first appearance of every group
+    fn one_of_groups(&self) -> Vec<(&syn::Ident, Vec<&NamedMember>)> {
+        let mut groups: Vec<(&syn::Ident, Vec<&NamedMember>)> = vec![];
+
+        for member in self.named_members() {
+            let group = match &member.params.one_of {
+                Some(group) => group,
+                None => continue,
+            };
+
+            match groups.iter_mut().find(|(name, _)| *name == group) {
+                Some((_, members)) => members.push(member),
+                None => groups.push((group, vec![member])),
+            }
+        }
+
+        groups
+    }
+
+    fn one_of_set_trait_ident(&self, group: &syn::Ident) -> syn::Ident {
+        quote::format_ident!(
+            "{}__{}__OneOf",
+            self.builder_type.ident.raw_name(),
+            group.raw_name()
+        )
+    }
+
+    fn one_of_unset_trait_ident(&self, group: &syn::Ident) -> syn::Ident {
+        quote::format_ident!(
+            "{}__{}__OneOfUnset",
+            self.builder_type.ident.raw_name(),
+            group.raw_name()
+        )
+    }
+
+    /// Generates the traits that enforce that exactly one member of every
+    /// `one_of` group is set. The `#[diagnostic::on_unimplemented]` message
+    /// can't be specified dynamically, so every group needs its own traits.
+    ///
+    /// The `OneOfUnset` trait bounds the states of the other members of the
+    /// group in the setters, which makes setting the second member of the group
+    /// a compile error. The `OneOf` trait is implemented for the tuples of the
+    /// states of the group's members where exactly one member is set, and it
+    /// bounds the finishing function, which makes finishing without setting
+    /// any of the members a compile error.
+    pub(crate) fn one_of_traits(&self) -> Result<TokenStream2> {
+        let vis = &self.vis;
+
+        self.one_of_groups()
+            .into_iter()
+            .map(|(group, members)| {
+                if members.len() < 2 {
+                    bail!(
+                        group,
+                        "the `one_of` group must have at least two members; \
+                        use a regular `Option<T>` member if it has just one"
+                    );
+                }
+
+                let names = members
+                    .iter()
+                    .map(|member| format!("`{}`", member.setter_method_core_name()))
+                    .join(", ");
+
+                let set_message = format!(
+                    "can't finish building yet; one of the members of the \
+                    `one_of({group})` group must be set: {names}"
+                );
+                let set_label =
+                    format!("none of the members of the `one_of({group})` group were set");
+
+                let unset_message = format!(
+                    "can't set more than one member of the `one_of({group})` group: {names}"
+                );
+                let unset_label =
+                    format!("a member of the `one_of({group})` group was already set");
+
+                let set_trait_ident = self.one_of_set_trait_ident(group);
+                let unset_trait_ident = self.one_of_unset_trait_ident(group);
+
+                let set_impls = (0..members.len()).map(|set_index| {
+                    let states = (0..members.len()).map(|index| {
+                        if index == set_index {
+                            quote!(::bon::private::Set<T>)
+                        } else {
+                            quote!(::bon::private::Unset<::bon::private::Optional>)
+                        }
+                    });
+
+                    quote! {
+                        #[automatically_derived]
+                        impl<T> #set_trait_ident for (#(#states,)*) {}
+                    }
+                });
+
+                Ok(quote! {
+                    #[::bon::private::rustversion::attr(
+                        since(1.78.0),
+                        diagnostic::on_unimplemented(
+                            message = #set_message,
+                            label = #set_label,
+                        )
+                    )]
+                    #[allow(non_camel_case_types)]
+                    #[doc(hidden)]
+                    #vis trait #set_trait_ident {}
+
+                    #(#set_impls)*
+
+                    #[::bon::private::rustversion::attr(
+                        since(1.78.0),
+                        diagnostic::on_unimplemented(
+                            message = #unset_message,
+                            label = #unset_label,
+                        )
+                    )]
+                    #[allow(non_camel_case_types)]
+                    #[doc(hidden)]
+                    #vis trait #unset_trait_ident {}
+
+                    #[automatically_derived]
+                    impl<T> #unset_trait_ident for ::bon::private::Unset<T> {}
+                })
+            })
+            .collect()
+    }
+
+    /// Bounds of the finishing functions that require exactly one member
+    /// of every `one_of` group to be set
+    pub(crate) fn one_of_finish_bounds(&self) -> Vec<TokenStream2> {
+        self.one_of_groups()
+            .into_iter()
+            .map(|(group, members)| {
+                let trait_ident = self.one_of_set_trait_ident(group);
+                let vars = members.iter().map(|member| &member.generic_var_ident);
+                quote!((#(#vars,)*): #trait_ident,)
+            })
+            .collect()
+    }
+
+    /// Bounds of the setters of the `one_of` member that require the other
+    /// members of its group to be unset
+    pub(crate) fn one_of_setter_bounds(&self, member: &NamedMember) -> TokenStream2 {
+        let group = match &member.params.one_of {
+            Some(group) => group,
+            None => return quote!(),
+        };
+
+        let trait_ident = self.one_of_unset_trait_ident(group);
+
+        let vars = self
+            .named_members()
+            .filter(|other| other.params.one_of.as_ref() == Some(group))
+            .filter(|other| other.generic_var_ident != member.generic_var_ident)
+            .map(|other| &other.generic_var_ident);
+
+        quote! {
+            #(#vars: #trait_ident,)*
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use super::BuilderGenCtx;
+use crate::util::prelude::*;
+use quote::quote;
+
+impl BuilderGenCtx {
+    /// Generates the no-op `#[deprecated]` setters requested with
+    /// `#[builder(deprecated_removed_setter(...))]` for the members that were
+    /// removed from the struct. They keep the old call sites compiling with
+    /// a warning during the transition period.
+    ///
+    /// The stub setters don't change the state of the builder, so they are
+    /// available in all states, and they may be called any number of times.
+    pub(crate) fn removed_setters(&self) -> Result<Option<TokenStream2>> {
+        if self.removed_setters.is_empty() {
+            return Ok(None);
+        }
+
+        let methods = self
+            .removed_setters
+            .iter()
+            .map(|field| self.removed_setter(field))
+            .collect::<Result<Vec<_>>>()?;
+
+        let generics_decl = &self.generics.decl_without_defaults;
+        let generic_args = &self.generics.args;
+        let where_clause = &self.generics.where_clause;
+        let builder_ident = &self.builder_type.ident;
+        let state_var = &self.generics.state_var;
+
+        Ok(Some(quote! {
+            #[automatically_derived]
+            impl<
+                #(#generics_decl,)*
+                #state_var
+            >
+            #builder_ident<
+                #(#generic_args,)*
+                #state_var
+            >
+            #where_clause
+            {
+                #(#methods)*
+            }
+        }))
+    }
+
+    fn removed_setter(&self, field: &syn::Field) -> Result<TokenStream2> {
+        let ident = field
+            .ident
+            .as_ref()
+            .expect("BUG: the removed setters are parsed as named fields");
+
+        let name = ident.raw_name();
+
+        if !matches!(field.vis, syn::Visibility::Inherited) {
+            bail!(
+                &field.vis,
+                "the removed setters have the visibility of the builder, so \
+                the visibility can't be specified for them"
+            );
+        }
+
+        let collision = self
+            .named_members()
+            .find(|member| member.setter_method_core_name().raw_name() == name);
+
+        if let Some(member) = collision {
+            bail!(
+                ident,
+                "the removed setter `{name}` collides with the setter of the existing \
+                member `{}`",
+                member.orig_ident,
+            );
+        }
+
+        if name == self.finish_func.ident.raw_name() {
+            bail!(
+                ident,
+                "the removed setter `{name}` collides with the finishing function"
+            );
+        }
+
+        let ty = &field.ty;
+        let attrs = &field.attrs;
+        let vis = &self.vis;
+
+        let note = format!(
+            "the member `{name}` was removed; this setter does nothing and \
+            will be deleted in a future version"
+        );
+
+        let must_use = self.setter_must_use();
+
+        Ok(quote! {
+            #(#attrs)*
+            #[deprecated(note = #note)]
+            #must_use
+            #[inline(always)]
+            #[allow(
+                // This is intentional. We want the builder syntax to compile away
+                clippy::inline_always,
+                clippy::impl_trait_in_params,
+                clippy::needless_pass_by_value,
+            )]
+            #vis fn #ident(self, _value: #ty) -> Self {
+                self
+            }
+        })
+    }
+}
@@ -0,0 +1,145 @@
+use super::{BuilderGenCtx, NamedMember};
+use crate::util::prelude::*;
+use quote::{quote, ToTokens};
+
+impl BuilderGenCtx {
+    /// Generates the `reset_{member}()` methods requested with `#[builder(resettable)]`
+    /// for the required members. Every method reverts the state of its member to
+    /// unset and keeps the states of the other members as they were, so that the
+    /// member can be set again.
+    ///
+    /// The methods are available in any state of the member. Resetting the member
+    /// that isn't set is a no-op.
+    pub(crate) fn reset_setters(&self) -> Result<Option<TokenStream2>> {
+        if !self.builder_type.resettable.is_present() {
+            return Ok(None);
+        }
+
+        let methods = self
+            .named_members()
+            .filter(|member| !member.is_optional())
+            .map(|member| self.reset_setter(member))
+            .collect::<Result<Vec<_>>>()?;
+
+        let generics_decl = &self.generics.decl_without_defaults;
+        let generic_args = &self.generics.args;
+        let where_clause = &self.generics.where_clause;
+        let builder_ident = &self.builder_type.ident;
+
+        let state_type_vars = self
+            .state_members()
+            .into_iter()
+            .map(|member| &member.generic_var_ident)
+            .collect::<Vec<_>>();
+
+        let allows = super::allow_warnings_on_member_types();
+
+        Ok(Some(quote! {
+            #allows
+            #[automatically_derived]
+            impl<
+                #(#generics_decl,)*
+                #(#state_type_vars,)*
+            >
+            #builder_ident<
+                #(#generic_args,)*
+                (#(#state_type_vars,)*)
+            >
+            #where_clause
+            {
+                #(#methods)*
+            }
+        }))
+    }
+
+    fn reset_setter(&self, member: &NamedMember) -> Result<TokenStream2> {
+        let core_name = member.setter_method_core_name();
+
+        // Preserve the original identifier span to make IDE's "go to definition" work correctly
+        let method_ident =
+            syn::Ident::new(&format!("reset_{}", core_name.raw_name()), core_name.span());
+
+        if let Some(other) = self
+            .named_members()
+            .find(|other| other.setter_method_core_name().raw_name() == method_ident.raw_name())
+        {
+            bail!(
+                other.setter_method_core_name(),
+                "the setter for this member collides with the `{method_ident}()` method \
+                requested with `#[builder(resettable)]`; consider renaming the setter \
+                with `#[builder(name = new_name)]`"
+            );
+        }
+
+        let builder_ident = &self.builder_type.ident;
+        let generic_args = &self.generics.args;
+
+        let state_types = self.state_members().into_iter().map(|other| {
+            if other.orig_ident == member.orig_ident {
+                quote!(::bon::private::Unset<::bon::private::Required>)
+            } else {
+                other.generic_var_ident.to_token_stream()
+            }
+        });
+
+        let member_exprs = self.state_members().into_iter().map(|other| {
+            if other.orig_ident == member.orig_ident {
+                return quote!(::bon::private::Unset(::bon::private::Required));
+            }
+            let index = &other.index;
+            quote!(self.__private_named_members.#index)
+        });
+
+        let maybe_receiver_field = self
+            .receiver()
+            .map(|_| quote!(__private_receiver: self.__private_receiver,));
+
+        let maybe_start_fn_args_field = self
+            .start_fn_args()
+            .next()
+            .map(|_| quote!(__private_start_fn_args: self.__private_start_fn_args,));
+
+        let maybe_locations_field = self.locations_field_type().map(|_| {
+            let index = member.index.index as usize;
+            quote! {
+                __private_locations: {
+                    let mut locations = self.__private_locations;
+                    locations[#index] = ::core::option::Option::None;
+                    locations
+                },
+            }
+        });
+
+        let vis = member.setter_vis.as_ref().unwrap_or(&self.vis);
+
+        let docs = format!(
+            "Reverts the member `{core_name}` to the unset state, so that it can \
+            be set again with [`Self::{core_name}()`]. The states of the other \
+            members are kept as they were."
+        );
+
+        let must_use = self.setter_must_use();
+
+        Ok(quote! {
+            #[doc = #docs]
+            #must_use
+            #[inline(always)]
+            #[allow(
+                // This is intentional. We want the builder syntax to compile away
+                clippy::inline_always,
+            )]
+            #vis fn #method_ident(self) -> #builder_ident<
+                #(#generic_args,)*
+                (#(#state_types,)*)
+            > {
+                #builder_ident {
+                    __private_phantom: ::core::marker::PhantomData,
+                    #maybe_receiver_field
+                    #maybe_start_fn_args_field
+                    #maybe_locations_field
+                    __private_named_members: (#( #member_exprs, )*)
+                }
+            }
+        })
+    }
+}
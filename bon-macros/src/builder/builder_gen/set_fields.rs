@@ -0,0 +1,114 @@
+use super::BuilderGenCtx;
+use crate::util::prelude::*;
+use quote::quote;
+
+impl BuilderGenCtx {
+    /// Generates a struct with references to the members that are set in the
+    /// given state of the builder and an `as_set_fields()` method that returns it.
+    ///
+    /// Instead of generating a separate struct for every possible state of the
+    /// builder (there are `2^N` of them) we generate a single struct that is
+    /// generic over the states of the members. The type of every field of the
+    /// struct is a projection of the member's state. It's `&T` if the member is
+    /// set and `()` otherwise.
+    pub(crate) fn set_fields(&self) -> Option<TokenStream2> {
+        if !self.builder_type.as_set_fields.is_present() {
+            return None;
+        }
+
+        let builder_ident = &self.builder_type.ident;
+        let set_fields_ident = quote::format_ident!("{}SetFieldsRef", builder_ident.raw_name());
+
+        let generics_decl = &self.generics.decl_without_defaults;
+        let generic_args = &self.generics.args;
+        let where_clause = &self.generics.where_clause;
+        let vis = &self.vis;
+
+        let state_type_vars = self
+            .state_members()
+            .into_iter()
+            .map(|member| &member.generic_var_ident)
+            .collect::<Vec<_>>();
+
+        // The lifetime is used only in the types of the fields, so the struct
+        // can't declare it if there are no fields
+        let lifetime = (!state_type_vars.is_empty()).then(|| quote!('__ref,));
+
+        let fields_decls = self.named_members().map(|member| {
+            let ident = &member.orig_ident;
+            let docs = &member.docs;
+            let state_var = &member.generic_var_ident;
+
+            quote! {
+                #(#docs)*
+                #vis #ident: <#state_var as ::bon::private::MemberRef<'__ref>>::Ref
+            }
+        });
+
+        let fields_inits = self.named_members().map(|member| {
+            let ident = &member.orig_ident;
+            let index = &member.index;
+
+            quote! {
+                #ident: ::bon::private::MemberRef::member_ref(
+                    &self.__private_named_members.#index
+                )
+            }
+        });
+
+        let struct_docs = format!(
+            "References to the members of [`{builder_ident}`] returned from its \
+            `as_set_fields()` method. Every field is a reference to the value of \
+            the member if it's set in the current state of the builder or `()` \
+            if it's not set."
+        );
+
+        let allows = super::allow_warnings_on_member_types();
+
+        Some(quote! {
+            #[doc = #struct_docs]
+            #allows
+            #vis struct #set_fields_ident<
+                #lifetime
+                #(#state_type_vars: ::bon::private::MemberRef<'__ref>,)*
+            > {
+                #(#fields_decls,)*
+            }
+
+            #allows
+            #[automatically_derived]
+            impl<
+                #(#generics_decl,)*
+                #(#state_type_vars,)*
+            >
+            #builder_ident<
+                #(#generic_args,)*
+                (#(#state_type_vars,)*)
+            >
+            #where_clause
+            {
+                /// Returns references to the members that are set in the current
+                /// state of the builder. This is useful to validate several members
+                /// together before finishing building.
+                #[inline(always)]
+                #[allow(
+                    // This is intentional. We want the projection to compile away
+                    clippy::inline_always,
+                    // Let's keep it as non-const for now to avoid restricting ourselves to only
+                    // const operations.
+                    clippy::missing_const_for_fn,
+                )]
+                #vis fn as_set_fields<'__ref>(
+                    &'__ref self
+                ) -> #set_fields_ident<#lifetime #(#state_type_vars,)*>
+                where
+                    #(#state_type_vars: ::bon::private::MemberRef<'__ref>,)*
+                {
+                    #set_fields_ident {
+                        #(#fields_inits,)*
+                    }
+                }
+            }
+        })
+    }
+}
@@ -1,6 +1,7 @@
 use super::{BuilderGenCtx, NamedMember};
 use crate::util::prelude::*;
 use quote::quote;
+use syn::spanned::Spanned;
 
 /// Specifies the return type of the setter method. It is conditioned by the
 /// `cfg(doc)`. If `cfg(doc)` is enabled, we want to generate a shorter type
@@ -16,6 +17,21 @@ pub(crate) struct SettersReturnType {
     pub(crate) doc_false: TokenStream2,
 }
 
+impl BuilderGenCtx {
+    /// Dropping the builder returned from a setter is likely a bug. The builder
+    /// type itself isn't `#[must_use]`, because the compiler would report such
+    /// a dropped builder twice then. Instead, every method that returns a new
+    /// builder has the attribute with the message specific to it.
+    pub(crate) fn setter_must_use(&self) -> TokenStream2 {
+        let message = format!(
+            "this setter returns a new builder; chain `.{}()` or capture it.",
+            self.finish_func.ident.raw_name()
+        );
+
+        quote!(#[must_use = #message])
+    }
+}
+
 pub(crate) struct MemberSettersCtx<'a> {
     builder_gen: &'a BuilderGenCtx,
     member: &'a NamedMember,
@@ -36,62 +52,435 @@ impl<'a> MemberSettersCtx<'a> {
     }
 
     pub(crate) fn setter_methods(&self) -> Result<TokenStream2> {
-        let member_type = self.member.norm_ty.as_ref();
+        Ok(self
+            .setter_method_specs()?
+            .into_iter()
+            .map(|method| self.setter_method(method))
+            .collect())
+    }
+
+    /// Generates the declarations of the setters for the trait requested via
+    /// `#[builder(setter_trait = ...)]` and their implementations for the builder.
+    ///
+    /// Unlike the inherent setters, the trait's setters don't require the member
+    /// to be unset. The associated types of the trait define the state transitions
+    /// for every state of the builder, so the trait is implemented for the builder
+    /// in all states, and calling the trait's setter for the member that is already
+    /// set overwrites its value.
+    pub(crate) fn setter_trait_methods(
+        &self,
+        setter_trait: &TokenStream2,
+    ) -> Result<(TokenStream2, TokenStream2)> {
+        let params = &self.member.params;
+
+        for (flag, name) in [(&params.bitor, "bitor"), (&params.collection, "collection")] {
+            if flag.is_present() {
+                bail!(
+                    &flag.span(),
+                    "`{name}` members aren't supported with `setter_trait`, because \
+                    the trait is implemented for the builder in all states, while \
+                    the `{name}` setter needs to read the previous value of the member",
+                );
+            }
+        }
 
-        if let Some(inner_type) = self.member.as_optional_norm_ty() {
-            return self.setters_for_optional_member(inner_type);
+        if let Some(group) = &params.one_of {
+            bail!(
+                group,
+                "`one_of` members aren't supported with `setter_trait`, because \
+                the trait is implemented for the builder in all states, while \
+                the `one_of` setter requires the other members of its group to be unset",
+            );
         }
 
+        let member_pascal = &self.member.norm_ident_pascal;
+        let ret_decl = self.wrap_return_type(quote!(Self::#member_pascal));
+        let ret_impl = self.wrap_return_type(self.return_type.doc_false.clone());
+        let allows = setter_allows();
+
+        let (decls, imps) = self
+            .setter_method_specs()?
+            .into_iter()
+            .map(|method| {
+                let MemberSetterMethod {
+                    method_name,
+                    fn_params,
+                    overwrite_docs,
+                    body,
+                } = method;
+
+                let docs = self.setter_docs(overwrite_docs);
+
+                let body = match body {
+                    SetterBody::Delegate { method_name, arg } => quote! {
+                        <Self as #setter_trait>::#method_name(self, #arg)
+                    },
+                    SetterBody::Default { member_init } => self.setter_body(&member_init),
+                };
+
+                let must_use = self.builder_gen.setter_must_use();
+
+                let decl = quote! {
+                    #( #docs )*
+                    #allows
+                    #must_use
+                    fn #method_name(self, #fn_params) -> #ret_decl;
+                };
+
+                let track_caller = self.setter_track_caller();
+
+                let imp = quote! {
+                    #allows
+                    #track_caller
+                    #[inline(always)]
+                    fn #method_name(self, #fn_params) -> #ret_impl {
+                        #body
+                    }
+                };
+
+                (decl, imp)
+            })
+            .unzip();
+
+        Ok((decls, imps))
+    }
+
+    fn setter_method_specs(&self) -> Result<Vec<MemberSetterMethod>> {
+        let mut methods = match self.member.as_optional_norm_ty() {
+            Some(inner_type) if self.member.params.bitor.is_present() => {
+                vec![self.bitor_setter(inner_type)?]
+            }
+            Some(inner_type) if self.member.params.collection.is_present() => {
+                vec![self.collection_setter(inner_type)?]
+            }
+            Some(inner_type) => self.setters_for_optional_member(inner_type)?,
+            None => self.setters_for_required_member()?,
+        };
+
+        if self.member.params.via_fn.is_present() {
+            methods.push(self.via_fn_setter());
+        }
+
+        Ok(methods)
+    }
+
+    /// Generates the `{member}_via` setter requested with `#[builder(via_fn)]`.
+    /// The closure is invoked immediately, and its result is passed to the regular
+    /// setter, so all the conversions and checks of that setter still apply.
+    fn via_fn_setter(&self) -> MemberSetterMethod {
+        let setter_method_name = self.member.setter_method_core_name().clone();
+
+        // Preserve the original identifier span to make IDE's "go to definition" work correctly
+        let via_method_name = syn::Ident::new(
+            &format!("{}_via", setter_method_name.raw_name()),
+            setter_method_name.span(),
+        );
+
+        let value_type = self
+            .member
+            .as_optional_norm_ty()
+            .unwrap_or(&self.member.norm_ty);
+
+        MemberSetterMethod {
+            method_name: via_method_name,
+            fn_params: quote!(f: impl FnOnce() -> #value_type),
+            overwrite_docs: Some(format!(
+                "Same as [`Self::{setter_method_name}`], but accepts a closure \
+                that is invoked immediately to compute the value. See that \
+                method's documentation for more details.",
+            )),
+            body: SetterBody::Delegate {
+                method_name: setter_method_name,
+                arg: quote!(f()),
+            },
+        }
+    }
+
+    /// Generates the only setter of the member with `#[builder(bitor)]`. Unlike
+    /// the regular setters, it can be called in any state of the member, and it
+    /// combines the new value with the previous one using the `|` operator.
+    /// The accumulation starts from the default value of the member.
+    fn bitor_setter(&self, inner_type: &syn::Type) -> Result<MemberSetterMethod> {
         let has_into = self.member.param_into(&self.builder_gen.on_params)?;
 
         let (fn_param_type, maybe_into_call) = if has_into {
-            (quote!(impl Into<#member_type>), quote!(.into()))
+            (quote!(impl Into<#inner_type>), quote!(.into()))
         } else {
-            (quote!(#member_type), quote!())
+            (quote!(#inner_type), quote!())
+        };
+
+        let initial = match self.member.param_default().flatten() {
+            Some(default) if has_into => quote! {
+                ::core::convert::Into::<#inner_type>::into((|| #default)()) | value
+            },
+            Some(default) => quote! {
+                { let default: #inner_type = #default; default | value }
+            },
+            None => quote!(value),
+        };
+
+        let member_type_var = &self.member.generic_var_ident;
+        let member_label = self.builder_gen.members_label(self.member);
+        let index = &self.member.index;
+
+        let member_init = quote! {
+            ::bon::private::Set(::core::option::Option::Some({
+                let value: #inner_type = value #maybe_into_call;
+
+                let previous = <#member_type_var as ::bon::private::IntoSet<
+                    ::core::option::Option<#inner_type>,
+                    #member_label
+                >>::into_set(self.__private_named_members.#index);
+
+                match previous {
+                    ::core::option::Option::Some(previous) => previous | value,
+                    ::core::option::Option::None => #initial,
+                }
+            }))
+        };
+
+        Ok(MemberSetterMethod {
+            method_name: self.member.setter_method_core_name().clone(),
+            fn_params: quote!(value: #fn_param_type),
+            overwrite_docs: None,
+            body: SetterBody::Default { member_init },
+        })
+    }
+
+    /// Generates the only setter of the member with `#[builder(collection)]`.
+    /// It pushes a single item into the collection, so it can be called in any
+    /// state of the member. The collection starts from the default value of
+    /// the member. If `item_validate` is specified, the setter is named
+    /// `try_{member}`, and it validates the item before pushing it.
+    fn collection_setter(&self, inner_type: &syn::Type) -> Result<MemberSetterMethod> {
+        let item_type = self
+            .member
+            .collection_item_ty()
+            .expect("BUG: the type of the `collection` member must be validated");
+
+        let has_into = self.member.param_into(&self.builder_gen.on_params)?;
+
+        let (fn_param_type, maybe_into_call) = if has_into {
+            (quote!(impl Into<#item_type>), quote!(.into()))
+        } else {
+            (quote!(#item_type), quote!())
+        };
+
+        let initial = match self.member.param_default().flatten() {
+            Some(default) if has_into => quote! {
+                ::core::convert::Into::<#inner_type>::into((|| #default)())
+            },
+            Some(default) => quote! {
+                { let default: #inner_type = #default; default }
+            },
+            None => quote!(::core::default::Default::default()),
+        };
+
+        let maybe_validate = self.member.params.item_validate.as_ref().map(|validate| {
+            let with = &validate.with;
+            quote!((#with)(&item)?;)
+        });
+
+        let member_type_var = &self.member.generic_var_ident;
+        let member_label = self.builder_gen.members_label(self.member);
+        let index = &self.member.index;
+
+        let member_init = quote! {
+            ::bon::private::Set(::core::option::Option::Some({
+                let item: #item_type = item #maybe_into_call;
+                #maybe_validate
+
+                let previous = <#member_type_var as ::bon::private::IntoSet<
+                    ::core::option::Option<#inner_type>,
+                    #member_label
+                >>::into_set(self.__private_named_members.#index);
+
+                let mut items: #inner_type = match previous {
+                    ::core::option::Option::Some(previous) => previous,
+                    ::core::option::Option::None => #initial,
+                };
+
+                items.push(item);
+                items
+            }))
+        };
+
+        let core_name = self.member.setter_method_core_name();
+
+        let method_name = if self.member.params.item_validate.is_some() {
+            // Preserve the original identifier span to make IDE's "go to definition" work correctly
+            syn::Ident::new(&format!("try_{}", core_name.raw_name()), core_name.span())
+        } else {
+            core_name.clone()
+        };
+
+        Ok(MemberSetterMethod {
+            method_name,
+            fn_params: quote!(item: #fn_param_type),
+            overwrite_docs: None,
+            body: SetterBody::Default { member_init },
+        })
+    }
+
+    /// Bounds on the state of the member required by its setters. The regular
+    /// setters require the member to be unset. The setters of the members with
+    /// `#[builder(bitor)]` and `#[builder(collection)]` accept any state. The
+    /// `bitor` setter also requires the type of the member to support the `|`
+    /// operator.
+    fn member_state_bounds(&self) -> TokenStream2 {
+        let member_type_var = &self.member.generic_var_ident;
+
+        let inner_type = match self.member.as_optional_norm_ty() {
+            Some(inner_type) if self.member.is_accumulating() => inner_type,
+            _ => return quote!(#member_type_var: ::bon::private::IsUnset,),
+        };
+
+        let member_label = self.builder_gen.members_label(self.member);
+
+        let into_set_bound = quote! {
+            #member_type_var: ::bon::private::IntoSet<
+                ::core::option::Option<#inner_type>,
+                #member_label
+            >,
+        };
+
+        if !self.member.params.bitor.is_present() {
+            return into_set_bound;
+        }
+
+        // The span makes the compiler point to the member's type if it doesn't
+        // implement the `BitOr` trait
+        let bitor_bound = quote::quote_spanned! {inner_type.span()=>
+            #inner_type: ::core::ops::BitOr<Output = #inner_type>,
         };
 
-        Ok(self.setter_method(MemberSetterMethod {
+        quote! {
+            #into_set_bound
+            #bitor_bound
+        }
+    }
+
+    fn setters_for_required_member(&self) -> Result<Vec<MemberSetterMethod>> {
+        let member_type = self.member.norm_ty.as_ref();
+
+        let has_into = self.member.param_into(&self.builder_gen.on_params)?;
+
+        let (fn_param_type, converted_value) = match self.borrowed_input_ty(has_into)? {
+            Some(borrowed) => (quote!(#borrowed), quote!(<#member_type>::Borrowed(value))),
+            None if has_into => (quote!(impl Into<#member_type>), quote!(value.into())),
+            None => (quote!(#member_type), quote!(value)),
+        };
+
+        let converted_value = match &self.member.params.setter_body {
+            Some(body) => quote! {
+                ::bon::private::apply_setter_body::<#member_type>(#converted_value, #body)
+            },
+            None => converted_value,
+        };
+
+        let value = match &self.member.params.checked {
+            Some(checked) => {
+                let check = &checked.with;
+                quote! {{
+                    let value: #member_type = #converted_value;
+                    (#check)(&value)?;
+                    value
+                }}
+            }
+            None => converted_value,
+        };
+
+        Ok(vec![MemberSetterMethod {
             method_name: self.member.setter_method_core_name().clone(),
             fn_params: quote!(value: #fn_param_type),
             overwrite_docs: None,
             body: SetterBody::Default {
-                member_init: quote!(::bon::private::Set(value #maybe_into_call)),
+                member_init: quote!(::bon::private::Set(#value)),
             },
-        }))
+        }])
     }
 
-    fn setters_for_optional_member(&self, inner_type: &syn::Type) -> Result<TokenStream2> {
+    fn setters_for_optional_member(
+        &self,
+        inner_type: &syn::Type,
+    ) -> Result<Vec<MemberSetterMethod>> {
         let has_into = self.member.param_into(&self.builder_gen.on_params)?;
-        let (inner_type, maybe_map_conv_call) = if has_into {
-            (quote!(impl Into<#inner_type>), quote!(.map(Into::into)))
-        } else {
-            (quote!(#inner_type), quote!())
+        let (inner_type_param, maybe_map_conv_call) = match self.borrowed_input_ty(has_into)? {
+            Some(borrowed) => (quote!(#borrowed), quote!(.map(<#inner_type>::Borrowed))),
+            None if has_into => (quote!(impl Into<#inner_type>), quote!(.map(Into::into))),
+            None => (quote!(#inner_type), quote!()),
+        };
+
+        let maybe_map_setter_body = self.member.maybe_map_setter_body(inner_type);
+        let maybe_map_conv_call = quote!(#maybe_map_conv_call #maybe_map_setter_body);
+
+        let value = match &self.member.params.checked {
+            Some(checked) => {
+                let check = &checked.with;
+                quote! {{
+                    let value: Option<#inner_type> = value #maybe_map_conv_call;
+                    if let Some(value) = &value {
+                        (#check)(value)?;
+                    }
+                    value
+                }}
+            }
+            None => quote!(value #maybe_map_conv_call),
         };
 
         let setter_method_name = self.member.setter_method_core_name().clone();
 
+        // The `one_of` member counts as set only if it has a value, so it has
+        // no `maybe_{member}` setter that could set it to `None`
+        if self.member.params.one_of.is_some() {
+            return Ok(vec![MemberSetterMethod {
+                method_name: setter_method_name,
+                fn_params: quote!(value: #inner_type_param),
+                overwrite_docs: None,
+                body: SetterBody::Default {
+                    member_init: quote! {{
+                        let value = ::core::option::Option::Some(value);
+                        ::bon::private::Set(#value)
+                    }},
+                },
+            }]);
+        }
+
         // Preserve the original identifier span to make IDE's "go to definition" work correctly
         let option_method_name = syn::Ident::new(
             &format!("maybe_{}", setter_method_name.raw_name()),
             setter_method_name.span(),
         );
 
+        let into_option = &self.member.params.into_option;
+
+        if into_option.is_present() && has_into {
+            bail!(
+                &into_option.span(),
+                "`into_option` can't be combined with `into`, because the setter \
+                accepts `impl Into<Option<T>>` that already includes the `Into` \
+                conversion",
+            );
+        }
+
         // Option-less setter is just a shortcut for wrapping the value in `Some`.
-        let optionless_setter_body = quote! {
-            self.#option_method_name(Some(value))
+        let (optionless_setter_param, optionless_setter_arg) = if into_option.is_present() {
+            (quote!(impl Into<Option<#inner_type>>), quote!(value.into()))
+        } else {
+            (inner_type_param.clone(), quote!(Some(value)))
         };
 
-        let methods = [
+        let methods = vec![
             MemberSetterMethod {
-                method_name: option_method_name,
-                fn_params: quote!(value: Option<#inner_type>),
+                method_name: option_method_name.clone(),
+                fn_params: quote!(value: Option<#inner_type_param>),
                 overwrite_docs: Some(format!(
                     "Same as [`Self::{setter_method_name}`], but accepts \
                     an `Option` as input. See that method's documentation for \
                     more details.",
                 )),
                 body: SetterBody::Default {
-                    member_init: quote!(::bon::private::Set(value #maybe_map_conv_call)),
+                    member_init: quote!(::bon::private::Set(#value)),
                 },
             },
             // We intentionally keep the name and signature of the setter method
@@ -102,16 +491,48 @@ impl<'a> MemberSettersCtx<'a> {
             // users need to use the `maybe_{member_ident}` method.
             MemberSetterMethod {
                 method_name: setter_method_name,
-                fn_params: quote!(value: #inner_type),
+                fn_params: quote!(value: #optionless_setter_param),
                 overwrite_docs: None,
-                body: SetterBody::Custom(optionless_setter_body),
+                body: SetterBody::Delegate {
+                    method_name: option_method_name,
+                    arg: optionless_setter_arg,
+                },
             },
         ];
 
-        Ok(methods
-            .into_iter()
-            .map(|method| self.setter_method(method))
-            .collect())
+        Ok(methods)
+    }
+
+    /// The input of the setter of the member with `#[builder(borrow)]` that
+    /// is stored as `Cow::Borrowed`
+    fn borrowed_input_ty(&self, has_into: bool) -> Result<Option<syn::Type>> {
+        let borrow = &self.member.params.borrow;
+
+        if !borrow.is_present() {
+            return Ok(None);
+        }
+
+        if has_into {
+            bail!(
+                &borrow.span(),
+                "`borrow` can't be combined with `into`, because the setter \
+                accepts a reference that is stored without conversions",
+            );
+        }
+
+        Ok(self.member.borrowed_input_ty())
+    }
+
+    /// The setters record the location of their caller if requested with
+    /// `#[builder(capture_locations)]`. The setters that delegate to other
+    /// setters are `#[track_caller]` as well, so the location propagates
+    /// to the user's code.
+    fn setter_track_caller(&self) -> Option<TokenStream2> {
+        self.builder_gen
+            .builder_type
+            .capture_locations
+            .is_present()
+            .then(|| quote!(#[track_caller]))
     }
 
     fn setter_method(&self, method: MemberSetterMethod) -> TokenStream2 {
@@ -122,66 +543,31 @@ impl<'a> MemberSettersCtx<'a> {
             body,
         } = method;
 
-        let docs = match overwrite_docs {
-            Some(docs) => vec![syn::parse_quote!(#[doc = #docs])],
-            None if !self.member.docs.is_empty() => self.member.docs.clone(),
-            None => self.generate_docs_for_setter(),
-        };
-
-        let vis = &self.builder_gen.vis;
+        let docs = self.setter_docs(overwrite_docs);
+        let vis = self
+            .member
+            .setter_vis
+            .as_ref()
+            .unwrap_or(&self.builder_gen.vis);
 
         let body = match body {
-            SetterBody::Custom(body) => body,
-            SetterBody::Default { member_init } => {
-                let maybe_receiver_field = self
-                    .builder_gen
-                    .receiver()
-                    .map(|_| quote!(__private_receiver: self.__private_receiver,));
-
-                let maybe_start_fn_args_field = self
-                    .builder_gen
-                    .start_fn_args()
-                    .next()
-                    .map(|_| quote!(__private_start_fn_args: self.__private_start_fn_args,));
-
-                let builder_ident = &self.builder_gen.builder_type.ident;
-
-                let member_exprs = self.builder_gen.named_members().map(|other_member| {
-                    if other_member.norm_ident == self.member.norm_ident {
-                        return member_init.clone();
-                    }
-                    let index = &other_member.index;
-                    quote!(self.__private_named_members.#index)
-                });
-
-                quote! {
-                    #builder_ident {
-                        __private_phantom: ::core::marker::PhantomData,
-                        #maybe_receiver_field
-                        #maybe_start_fn_args_field
-                        __private_named_members: (#( #member_exprs, )*)
-                    }
-                }
-            }
+            SetterBody::Delegate { method_name, arg } => quote!(self.#method_name(#arg)),
+            SetterBody::Default { member_init } => self.setter_body(&member_init),
         };
 
-        let member_state_type = &self.member.generic_var_ident;
-        let SettersReturnType {
-            doc_true: ret_doc_true,
-            doc_false: ret_doc_false,
-        } = &self.return_type;
+        let member_state_bounds = self.member_state_bounds();
+        let one_of_bounds = self.builder_gen.one_of_setter_bounds(self.member);
+        let ret_doc_true = self.wrap_return_type(self.return_type.doc_true.clone());
+        let ret_doc_false = self.wrap_return_type(self.return_type.doc_false.clone());
+        let allows = setter_allows();
+        let must_use = self.builder_gen.setter_must_use();
+        let track_caller = self.setter_track_caller();
 
         quote! {
             #( #docs )*
-            #[allow(
-                // This is intentional. We want the builder syntax to compile away
-                clippy::inline_always,
-                // We don't want to avoid using `impl Trait` in the setter. This way
-                // the setter signature is easier to read, and anyway if you want to
-                // specify a type hint for the method that accepts an `impl Into`, then
-                // your design of this setter already went wrong.
-                clippy::impl_trait_in_params
-            )]
+            #allows
+            #must_use
+            #track_caller
             #[inline(always)]
             // The `cfg_attr` condition is for `doc`, so we don't pay the price
             // if invoking the `__return_type` macro in the usual case when the
@@ -189,13 +575,116 @@ impl<'a> MemberSettersCtx<'a> {
             #[cfg_attr(doc, bon::__return_type(#ret_doc_true))]
             #vis fn #method_name(self, #fn_params) -> #ret_doc_false
             where
-                #member_state_type: ::bon::private::IsUnset,
+                #member_state_bounds
+                #one_of_bounds
             {
                 #body
             }
         }
     }
 
+    fn setter_docs(&self, overwrite_docs: Option<String>) -> Vec<syn::Attribute> {
+        match overwrite_docs {
+            Some(docs) => vec![syn::parse_quote!(#[doc = #docs])],
+            None if !self.member.docs.is_empty() => self.member.docs.clone(),
+            None => self.generate_docs_for_setter(),
+        }
+    }
+
+    /// The error type of the setter that validates the value with `checked`
+    /// or the items with `item_validate`
+    fn validation_error(&self) -> Option<&syn::Type> {
+        let params = &self.member.params;
+        let validation = params.checked.as_ref().or(params.item_validate.as_ref())?;
+        Some(&validation.error)
+    }
+
+    /// Wraps the return type of the setter in a `Result` if the setter validates
+    /// its input
+    fn wrap_return_type(&self, return_type: TokenStream2) -> TokenStream2 {
+        match self.validation_error() {
+            Some(error) => quote!(::core::result::Result<#return_type, #error>),
+            None => return_type,
+        }
+    }
+
+    fn setter_body(&self, member_init: &TokenStream2) -> TokenStream2 {
+        let maybe_receiver_field = self
+            .builder_gen
+            .receiver()
+            .map(|_| quote!(__private_receiver: self.__private_receiver,));
+
+        let maybe_start_fn_args_field = self
+            .builder_gen
+            .start_fn_args()
+            .next()
+            .map(|_| quote!(__private_start_fn_args: self.__private_start_fn_args,));
+
+        let maybe_locations_field = self.builder_gen.locations_field_type().map(|_| {
+            let index = self.member.index.index as usize;
+            quote! {
+                __private_locations: {
+                    let mut locations = self.__private_locations;
+                    locations[#index] = ::core::option::Option::Some(
+                        ::core::panic::Location::caller()
+                    );
+                    locations
+                },
+            }
+        });
+
+        let builder_ident = &self.builder_gen.builder_type.ident;
+
+        let member_exprs = self
+            .builder_gen
+            .state_members()
+            .into_iter()
+            .map(|other_member| {
+                if other_member.norm_ident == self.member.norm_ident {
+                    return member_init.clone();
+                }
+                let index = &other_member.index;
+                quote!(self.__private_named_members.#index)
+            });
+
+        let builder = quote! {
+            #builder_ident {
+                __private_phantom: ::core::marker::PhantomData,
+                #maybe_receiver_field
+                #maybe_start_fn_args_field
+                #maybe_locations_field
+                __private_named_members: (#( #member_exprs, )*)
+            }
+        };
+
+        let builder = if self.validation_error().is_some() {
+            quote!(::core::result::Result::Ok(#builder))
+        } else {
+            builder
+        };
+
+        let maybe_trace = self
+            .builder_gen
+            .builder_type
+            .defmt_trace
+            .is_present()
+            .then(|| {
+                let message = format!(
+                    "{}: setting member `{}`",
+                    builder_ident.raw_name(),
+                    self.member.orig_ident.raw_name(),
+                );
+
+                // The macro expands to nothing if the `defmt` feature of `bon` is disabled
+                quote!(::bon::__defmt_trace!(#message);)
+            });
+
+        quote! {
+            #maybe_trace
+            #builder
+        }
+    }
+
     fn generate_docs_for_setter(&self) -> Vec<syn::Attribute> {
         let setter_core_name = self.member.setter_method_core_name();
         let start_fn_ident = &self.builder_gen.start_func.ident;
@@ -204,12 +693,16 @@ impl<'a> MemberSettersCtx<'a> {
             format!(" See {start_fn_path} for more info.")
         };
 
-        let suffix = self
-            .builder_gen
-            .assoc_method_ctx
-            .as_ref()
-            .map(|assoc_ctx| {
-                let ty_path = match assoc_ctx.self_ty.as_path() {
+        let start_fn_self_ty = self.builder_gen.start_func_on().or_else(|| {
+            self.builder_gen
+                .assoc_method_ctx
+                .as_ref()
+                .map(|assoc_ctx| assoc_ctx.self_ty.as_ref())
+        });
+
+        let suffix = start_fn_self_ty
+            .map(|self_ty| {
+                let ty_path = match self_ty.as_path() {
                     Some(ty_path) => ty_path,
 
                     // The type is quite complex. It's hard to generate a workable
@@ -229,9 +722,29 @@ impl<'a> MemberSettersCtx<'a> {
     }
 }
 
+fn setter_allows() -> TokenStream2 {
+    quote! {
+        #[allow(
+            // This is intentional. We want the builder syntax to compile away
+            clippy::inline_always,
+            // We don't want to avoid using `impl Trait` in the setter. This way
+            // the setter signature is easier to read, and anyway if you want to
+            // specify a type hint for the method that accepts an `impl Into`, then
+            // your design of this setter already went wrong.
+            clippy::impl_trait_in_params
+        )]
+    }
+}
+
 enum SetterBody {
-    Custom(TokenStream2),
-    Default { member_init: TokenStream2 },
+    /// Calls another setter of the same member with the given argument
+    Delegate {
+        method_name: syn::Ident,
+        arg: TokenStream2,
+    },
+    Default {
+        member_init: TokenStream2,
+    },
 }
 
 struct MemberSetterMethod {
@@ -0,0 +1,110 @@
+use super::setter_methods::{MemberSettersCtx, SettersReturnType};
+use super::BuilderGenCtx;
+use crate::util::prelude::*;
+use quote::quote;
+
+impl BuilderGenCtx {
+    /// Generates a trait with the signatures of all setters and implements it
+    /// for the builder. This is meant for code that wants to be generic over
+    /// the builder, for example, to substitute it with a mock in tests.
+    ///
+    /// The typestate of the builder is represented with an associated type per
+    /// member that defines the type of the builder after that member is set.
+    /// The associated types are bounded by the trait itself, so the setters
+    /// can be chained in the generic context.
+    ///
+    /// The members with setters less visible than the builder are left out of
+    /// the trait, because the methods of a trait are as visible as the trait.
+    pub(crate) fn setter_trait(&self) -> Result<Option<TokenStream2>> {
+        let trait_ident = match &self.builder_type.setter_trait {
+            Some(ident) => ident,
+            None => return Ok(None),
+        };
+
+        let vis = &self.vis;
+        let builder_ident = &self.builder_type.ident;
+        let generics_decl_with_defaults = &self.generics.decl_with_defaults;
+        let generics_decl = &self.generics.decl_without_defaults;
+        let generic_args = &self.generics.args;
+        let where_clause = &self.generics.where_clause;
+
+        let state_type_vars = self
+            .state_members()
+            .into_iter()
+            .map(|member| &member.generic_var_ident)
+            .collect::<Vec<_>>();
+
+        let trait_path = quote!(#trait_ident<#(#generic_args,)*>);
+
+        let members = self
+            .named_members()
+            .filter(|member| member.setter_vis.is_none())
+            .map(|member| {
+                let next_state = self.setter_next_state(member);
+
+                let return_type = SettersReturnType {
+                    doc_true: next_state.clone(),
+                    doc_false: next_state.clone(),
+                };
+
+                let (methods_decls, methods_impls) =
+                    MemberSettersCtx::new(self, member, return_type)
+                        .setter_trait_methods(&trait_path)?;
+
+                let member_pascal = &member.norm_ident_pascal;
+                let assoc_type_doc = format!(
+                    "The type of the builder after `{}` is set.",
+                    member.setter_method_core_name().raw_name()
+                );
+
+                let assoc_type_decl = quote! {
+                    #[doc = #assoc_type_doc]
+                    type #member_pascal: #trait_path;
+                };
+
+                let assoc_type_def = quote! {
+                    type #member_pascal = #next_state;
+                };
+
+                Ok((
+                    quote!(#assoc_type_decl #methods_decls),
+                    quote!(#assoc_type_def #methods_impls),
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let trait_items = members.iter().map(|(decl, _)| decl);
+        let impl_items = members.iter().map(|(_, imp)| imp);
+
+        let trait_doc = format!("Setters of [`{builder_ident}`] available in all of its states.");
+
+        let allows = super::allow_warnings_on_member_types();
+
+        Ok(Some(quote! {
+            #[doc = #trait_doc]
+            #allows
+            #vis trait #trait_ident<#(#generics_decl_with_defaults,)*>
+            #where_clause
+            {
+                #(#trait_items)*
+            }
+
+            #allows
+            #[automatically_derived]
+            impl<
+                #(#generics_decl,)*
+                #(#state_type_vars,)*
+            >
+                #trait_path
+            for
+                #builder_ident<
+                    #(#generic_args,)*
+                    (#(#state_type_vars,)*)
+                >
+            #where_clause
+            {
+                #(#impl_items)*
+            }
+        }))
+    }
+}
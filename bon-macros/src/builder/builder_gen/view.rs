@@ -0,0 +1,131 @@
+use super::{BuilderGenCtx, Member, NamedMember};
+use crate::util::prelude::*;
+use quote::quote;
+
+impl BuilderGenCtx {
+    /// Generates a `{finish_fn}_view(&self)` method that constructs the
+    /// user-defined view type from references to the members. It's available
+    /// in the same states of the builder as the finishing function.
+    ///
+    /// The view type is constructed with a struct literal. Required members
+    /// are borrowed as `&T`, and optional members are borrowed as `Option<&T>`.
+    /// Skipped members and members of the finishing function are not part of
+    /// the view, because the builder doesn't store them.
+    pub(crate) fn view(&self) -> Option<TokenStream2> {
+        let view_path = self.view.as_ref()?;
+
+        let fields = self.members.iter().filter_map(|member| {
+            let field = match member {
+                Member::Named(member) => {
+                    let ident = &member.orig_ident;
+                    let index = &member.index;
+                    let member_label = self.members_label(member);
+                    let ref_ty = view_ref_ty(member);
+
+                    quote! {
+                        #ident: ::bon::private::IntoSetRef::<
+                            #ref_ty,
+                            #member_label
+                        >::into_set_ref(&self.__private_named_members.#index)
+                    }
+                }
+                Member::StartFnArg(member) => {
+                    let ident = &member.base.ident;
+                    let index = &member.index;
+                    quote! {
+                        #ident: &self.__private_start_fn_args.#index
+                    }
+                }
+                Member::FinishFnArg(_) | Member::Skipped(_) => return None,
+            };
+
+            Some(field)
+        });
+
+        let where_bounds = self.named_members().map(|member| {
+            let member_type_var = &member.generic_var_ident;
+            let member_label = self.members_label(member);
+            let ref_ty = view_ref_ty(member);
+            quote! {
+                &'__view #member_type_var: ::bon::private::IntoSetRef<
+                    #ref_ty,
+                    #member_label
+                >
+            }
+        });
+
+        let generics_decl = &self.generics.decl_without_defaults;
+        let generic_args = &self.generics.args;
+        let where_clause = &self.generics.where_clause;
+        let builder_ident = &self.builder_type.ident;
+        let finish_where_predicates = &self.finish_func.where_predicates;
+        let vis = self.finish_func.vis.as_ref().unwrap_or(&self.vis);
+
+        let state_type_vars = self
+            .state_members()
+            .into_iter()
+            .map(|member| &member.generic_var_ident)
+            .collect::<Vec<_>>();
+
+        let view_fn_ident = quote::format_ident!("{}_view", self.finish_func.ident.raw_name());
+
+        let view_name = view_path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string())
+            .unwrap_or_default();
+
+        let view_fn_docs = format!(
+            "Returns a [`{view_name}`] that borrows the members of the builder \
+            without consuming it"
+        );
+
+        let allows = super::allow_warnings_on_member_types();
+
+        Some(quote! {
+            #allows
+            #[automatically_derived]
+            impl<
+                #(#generics_decl,)*
+                #(#state_type_vars,)*
+            >
+            #builder_ident<
+                #(#generic_args,)*
+                (#(#state_type_vars,)*)
+            >
+            #where_clause
+            {
+                #[doc = #view_fn_docs]
+                #[inline(always)]
+                #[allow(
+                    // This is intentional. We want the view to compile away
+                    clippy::inline_always,
+                    // Let's keep it as non-const for now to avoid restricting ourselves to only
+                    // const operations.
+                    clippy::missing_const_for_fn,
+                )]
+                #vis fn #view_fn_ident<'__view>(
+                    &'__view self
+                ) -> #view_path<'__view, #(#generic_args,)*>
+                where
+                    #(#where_bounds,)*
+                    #(#finish_where_predicates,)*
+                {
+                    #view_path {
+                        #(#fields,)*
+                    }
+                }
+            }
+        })
+    }
+}
+
+/// The type of the reference to the member's value in the view
+fn view_ref_ty(member: &NamedMember) -> TokenStream2 {
+    if let Some(ty) = member.as_optional_norm_ty() {
+        return quote!(::core::option::Option<&'__view #ty>);
+    }
+
+    let ty = &member.norm_ty;
+    quote!(&'__view #ty)
+}
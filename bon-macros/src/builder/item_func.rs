@@ -21,11 +21,13 @@ pub(crate) fn generate(params: FuncInputParams, orig_func: syn::ItemFn) -> Resul
 
     let MacroOutput {
         start_func,
+        start_func_with_defaults,
         other_items,
     } = ctx.into_builder_gen_ctx()?.output()?;
 
     Ok(quote! {
         #start_func
+        #start_func_with_defaults
         #other_items
 
         // Keep original function at the end. It seems like rust-analyzer
@@ -117,10 +117,18 @@ pub(crate) fn generate(mut orig_impl_block: syn::ItemImpl) -> Result<TokenStream
 
     let new_impl_items = outputs.iter().flat_map(|(adapted_func, output)| {
         let start_func = &output.start_func;
+        let start_func_with_defaults = output
+            .start_func_with_defaults
+            .as_ref()
+            .map(|func| syn::parse_quote!(#func));
+
         [
-            syn::parse_quote!(#start_func),
-            syn::parse_quote!(#adapted_func),
+            Some(syn::parse_quote!(#start_func)),
+            start_func_with_defaults,
+            Some(syn::parse_quote!(#adapted_func)),
         ]
+        .into_iter()
+        .flatten()
     });
 
     norm_selfful_impl_block.items = other_items;
@@ -5,25 +5,103 @@ use quote::quote;
 
 pub(crate) fn generate(orig_struct: syn::ItemStruct) -> Result<TokenStream2> {
     let struct_ident = orig_struct.ident.clone();
+    let struct_vis = orig_struct.vis.clone();
     let ctx = StructInputCtx::new(orig_struct)?;
 
+    let module = ctx.module().cloned();
+    let cfg = ctx.cfg().map(|cfg| {
+        let predicate = &cfg.predicate;
+
+        // The `doc(cfg(...))` attribute makes docs.rs render the badge that says
+        // under which configuration the items are available
+        let doc_cfg = cfg.doc_cfg.then(|| {
+            quote! {
+                #[cfg_attr(docsrs, doc(cfg(#predicate)))]
+            }
+        });
+
+        quote! {
+            #[cfg(#predicate)]
+            #doc_cfg
+        }
+    });
+
+    let ctx = ctx.into_builder_gen_ctx()?;
+    let start_func_on = ctx.start_func_on().cloned();
+
     let MacroOutput {
-        mut start_func,
+        start_func,
+        start_func_with_defaults,
         other_items,
-    } = ctx.into_builder_gen_ctx()?.output()?;
+    } = ctx.output()?;
+
+    let start_funcs = std::iter::once(start_func).chain(start_func_with_defaults);
+    let start_func_impl = start_func_impl(&struct_ident, start_func_on, start_funcs);
+
+    let items: syn::File = syn::parse_quote! {
+        #start_func_impl
+        #other_items
+    };
+
+    if let Some(module) = module {
+        let module_docs =
+            format!("Builder for [`{struct_ident}`] and other items generated for it");
 
-    let impl_generics = std::mem::take(&mut start_func.sig.generics);
+        // The module has the same visibility as the struct. The visibility of the
+        // items inside of it is already adjusted to be the same as if they were
+        // placed in the parent module. The items from the parent module are brought
+        // into scope with the glob import, so the types of the members resolve the
+        // same way.
+        return Ok(quote! {
+            #[doc = #module_docs]
+            #cfg
+            #struct_vis mod #module {
+                #[allow(unused_imports)]
+                use super::*;
+
+                #items
+            }
+        });
+    }
+
+    let items = items.items.iter().map(|item| quote!(#cfg #item));
+
+    Ok(quote!(#(#items)*))
+}
+
+fn start_func_impl(
+    struct_ident: &syn::Ident,
+    start_func_on: Option<syn::Type>,
+    start_funcs: impl IntoIterator<Item = syn::ItemFn>,
+) -> TokenStream2 {
+    let mut start_funcs: Vec<_> = start_funcs.into_iter().collect();
+
+    // The start fn keeps the generics of the struct when it's placed on
+    // a different type, because that type isn't generic over them.
+    if let Some(on) = start_func_on {
+        return quote! {
+            #[automatically_derived]
+            impl #on {
+                #(#start_funcs)*
+            }
+        };
+    }
+
+    // All start functions have the same generics as the struct, so they are
+    // moved to the impl block
+    let mut impl_generics = syn::Generics::default();
+    for start_func in &mut start_funcs {
+        impl_generics = std::mem::take(&mut start_func.sig.generics);
+    }
 
     let (generics_decl, generic_args, where_clause) = impl_generics.split_for_impl();
 
-    Ok(quote! {
+    quote! {
         #[automatically_derived]
         impl #generics_decl #struct_ident #generic_args
             #where_clause
         {
-            #start_func
+            #(#start_funcs)*
         }
-
-        #other_items
-    })
+    }
 }
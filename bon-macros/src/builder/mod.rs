@@ -86,3 +86,252 @@ fn generate_completion_triggers(params: TokenStream2) -> TokenStream2 {
 
     util::ide::generate_completion_triggers(meta)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[track_caller]
+    fn assert_impls_automatically_derived(output: TokenStream2) {
+        let file: syn::File = syn::parse2(output).unwrap();
+
+        let impls: Vec<_> = file
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                syn::Item::Impl(item_impl) => Some(item_impl),
+                _ => None,
+            })
+            .collect();
+
+        assert!(!impls.is_empty());
+
+        for item_impl in impls {
+            let is_automatically_derived = item_impl
+                .attrs
+                .iter()
+                .any(|attr| attr.path().is_ident("automatically_derived"));
+
+            assert!(
+                is_automatically_derived,
+                "impl block misses `#[automatically_derived]`: {}",
+                quote!(#item_impl)
+            );
+        }
+    }
+
+    #[test]
+    fn struct_impls_are_automatically_derived() {
+        assert_impls_automatically_derived(generate_from_derive(quote! {
+            #[builder(
+                derive(Clone, Debug, PartialEq, Eq, Hash),
+                setter_trait = SutBuilderSetters,
+                args_struct = SutArgs,
+                as_set_fields,
+                json_schema,
+                required_fields,
+                start_with_defaults,
+                finish_and_reset,
+                finish_into_out,
+                finish_into_slot,
+                build_by_ref,
+                view = SutView,
+            )]
+            struct Sut<'a, T> {
+                #[builder(start_fn)]
+                x1: &'a T,
+
+                #[builder(finish_fn)]
+                x2: u32,
+
+                x3: Option<T>,
+
+                #[builder(required_message = "x4 is required")]
+                x4: u32,
+            }
+        }));
+    }
+
+    #[test]
+    fn fn_impls_are_automatically_derived() {
+        assert_impls_automatically_derived(generate_from_attr(
+            quote!(derive(Clone, Debug)),
+            quote! {
+                fn sut<T>(#[builder(start_fn)] x1: T, x2: Option<u32>) {}
+            },
+        ));
+    }
+
+    #[test]
+    fn test_only_debug_is_cfg_test() {
+        let output = generate_from_derive(quote! {
+            #[builder(test_only_debug)]
+            struct Sut {
+                x1: u32,
+            }
+        });
+
+        let file: syn::File = syn::parse2(output).unwrap();
+
+        let debug_impls: Vec<_> = file
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                syn::Item::Impl(item_impl) => Some(item_impl),
+                _ => None,
+            })
+            .filter(|item_impl| {
+                let trait_path = match &item_impl.trait_ {
+                    Some((_, path, _)) => path,
+                    None => return false,
+                };
+                quote!(#trait_path).to_string() == quote!(::core::fmt::Debug).to_string()
+            })
+            .collect();
+
+        assert_eq!(debug_impls.len(), 1);
+
+        let cfgs = debug_impls[0]
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("cfg"))
+            .map(|attr| quote!(#attr).to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(cfgs, [quote!(#[cfg(test)]).to_string()]);
+    }
+
+    #[test]
+    fn cfg_emits_doc_cfg_for_docsrs_only_if_requested() {
+        let doc_cfg = quote!(#[cfg_attr(docsrs, doc(cfg(feature = "builder")))]).to_string();
+
+        let assert_doc_cfg = |params: TokenStream2, expected: &[String]| {
+            let output = generate_from_derive(quote! {
+                #[builder(#params)]
+                struct Sut {
+                    x1: u32,
+                }
+            });
+
+            let file: syn::File = syn::parse2(output).unwrap();
+
+            assert!(!file.items.is_empty());
+
+            for mut item in file.items {
+                let item_tokens = quote!(#item).to_string();
+                let attrs = item.attrs_mut().unwrap();
+                let doc_cfgs = attrs
+                    .iter()
+                    .filter(|attr| attr.path().is_ident("cfg_attr"))
+                    .map(|attr| quote!(#attr).to_string())
+                    .collect::<Vec<_>>();
+
+                assert_eq!(doc_cfgs, expected, "{item_tokens}");
+            }
+        };
+
+        assert_doc_cfg(quote!(cfg(feature = "builder")), &[]);
+        assert_doc_cfg(quote!(module = builders, cfg(feature = "builder")), &[]);
+
+        let expected = [doc_cfg];
+
+        assert_doc_cfg(quote!(cfg(feature = "builder", doc_cfg)), &expected);
+        assert_doc_cfg(
+            quote!(module = builders, cfg(feature = "builder", doc_cfg)),
+            &expected,
+        );
+    }
+
+    #[test]
+    fn doc_setters_lists_setters_in_start_fn_docs() {
+        let output = generate_from_derive(quote! {
+            #[builder(doc_setters)]
+            struct Sut {
+                #[builder(start_fn)]
+                x1: u32,
+
+                x2: u32,
+                x3: Option<u32>,
+
+                #[builder(default, via_fn)]
+                x4: u32,
+
+                #[builder(bitor)]
+                x5: u32,
+
+                #[builder(skip)]
+                x6: u32,
+
+                #[builder(collection, item_validate(with = check, error = ()))]
+                x7: Vec<u32>,
+            }
+        });
+
+        let file: syn::File = syn::parse2(output).unwrap();
+
+        let start_fn = file
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                syn::Item::Impl(item_impl) => Some(&item_impl.items),
+                _ => None,
+            })
+            .flatten()
+            .find_map(|item| match item {
+                syn::ImplItem::Fn(func) if func.sig.ident == "builder" => Some(func),
+                _ => None,
+            })
+            .unwrap();
+
+        let docs = start_fn
+            .attrs
+            .iter()
+            .filter_map(|attr| match &attr.meta {
+                syn::Meta::NameValue(meta) if meta.path.is_ident("doc") => {
+                    let value = &meta.value;
+                    Some(quote!(#value).to_string())
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let expected = [
+            "\"Create an instance of [`Sut`] using the builder syntax\"",
+            "\"\"",
+            "\"# Setters\"",
+            "\"\"",
+            "\"- `x2()` (required)\"",
+            "\"- `x3()`, `maybe_x3()` (optional)\"",
+            "\"- `x4()`, `maybe_x4()`, `x4_via()` (optional)\"",
+            "\"- `x5()` (optional)\"",
+            "\"- `try_x7()` (optional)\"",
+        ];
+
+        assert_eq!(docs, expected);
+    }
+
+    #[test]
+    fn generic_params_to_args() {
+        let generics: syn::Generics = syn::parse_quote! {
+            <'a, 'b: 'a, T: Clone + 'a = u32, const N: usize = 3>
+        };
+
+        let args = generics
+            .params
+            .iter()
+            .map(builder_gen::generic_param_to_arg)
+            .collect::<Vec<_>>();
+
+        assert!(matches!(args[0], syn::GenericArgument::Lifetime(_)));
+        assert!(matches!(args[1], syn::GenericArgument::Lifetime(_)));
+        assert!(matches!(args[2], syn::GenericArgument::Type(_)));
+        assert!(matches!(args[3], syn::GenericArgument::Const(_)));
+
+        let ty: syn::Type = syn::parse_quote!(Sut<#(#args),*>);
+
+        assert_eq!(
+            quote!(#ty).to_string(),
+            quote!(Sut<'a, 'b, T, N>).to_string()
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use crate::util::prelude::*;
+use quote::quote;
+use syn::punctuated::Punctuated;
+
+/// Name of the helper attribute that opts a struct out of the `#[builder_all]`
+const SKIP_ATTR: &str = "builder_skip";
+
+pub(crate) fn generate(params: TokenStream2, item: TokenStream2) -> TokenStream2 {
+    try_generate(params, item.clone())
+        .unwrap_or_else(|err| crate::error::error_into_token_stream(err, item))
+}
+
+fn try_generate(params: TokenStream2, item: TokenStream2) -> Result<TokenStream2> {
+    if let Some(param) = params.into_iter().next() {
+        bail!(&param, "`#[builder_all]` doesn't accept any parameters");
+    }
+
+    let mut item_mod: syn::ItemMod = match syn::parse2(item)? {
+        syn::Item::Mod(item_mod) => item_mod,
+        item => bail!(
+            &item,
+            "`#[builder_all]` attribute is expected to be placed on a `mod` item \
+            but it was placed on other syntax instead"
+        ),
+    };
+
+    let items = match &mut item_mod.content {
+        Some((_, items)) => items,
+        None => bail!(
+            &item_mod,
+            "`#[builder_all]` requires the module to be defined inline \
+            (`mod name {{ ... }}`), because the macro can't see the contents \
+            of the module defined in a separate file"
+        ),
+    };
+
+    for item in items {
+        let item_struct = match item {
+            syn::Item::Struct(item_struct) => item_struct,
+            _ => continue,
+        };
+
+        let attrs_count = item_struct.attrs.len();
+        item_struct
+            .attrs
+            .retain(|attr| !attr.path().is_ident(SKIP_ATTR));
+
+        let is_skipped = item_struct.attrs.len() != attrs_count;
+
+        // Tuple and unit structs aren't supported by `#[derive(Builder)]`, so
+        // they are skipped. Proc macros can't emit warnings on stable Rust, so
+        // this is documented in the reference of `#[builder_all]` instead
+        let is_eligible = matches!(item_struct.fields, syn::Fields::Named(_));
+
+        if is_skipped || !is_eligible || derives_builder(&item_struct.attrs)? {
+            continue;
+        }
+
+        item_struct
+            .attrs
+            .insert(0, syn::parse_quote!(#[derive(::bon::Builder)]));
+    }
+
+    Ok(quote!(#item_mod))
+}
+
+/// Checks if the struct already has a `#[derive(Builder)]` written by the user.
+/// We don't want to generate it twice. Only the paths that refer to `bon::Builder`
+/// are recognized, so the derives of other crates with the same name, such as
+/// `derive_builder::Builder`, don't prevent the generation. A bare `Builder`
+/// is assumed to be imported from `bon`.
+fn derives_builder(attrs: &[syn::Attribute]) -> Result<bool> {
+    for attr in attrs {
+        if !attr.path().is_ident("derive") {
+            continue;
+        }
+
+        let paths =
+            attr.parse_args_with(Punctuated::<syn::Path, syn::Token![,]>::parse_terminated)?;
+
+        let has_builder = paths.iter().any(|path| {
+            let segments: Vec<_> = path.segments.iter().map(|segment| &segment.ident).collect();
+
+            match segments.as_slice() {
+                [builder] => path.leading_colon.is_none() && *builder == "Builder",
+                [krate, builder] => *krate == "bon" && *builder == "Builder",
+                _ => false,
+            }
+        });
+
+        if has_builder {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
@@ -13,6 +13,7 @@
 
 mod bon;
 mod builder;
+mod builder_all;
 mod collections;
 mod error;
 mod normalization;
@@ -203,6 +204,51 @@ pub fn bon(params: TokenStream, item: TokenStream) -> TokenStream {
     bon::generate(params.into(), item.into()).into()
 }
 
+/// Derives a builder for every struct with named fields in the module it's placed on.
+///
+/// This is a shortcut for placing `#[derive(Builder)]` on each struct in the module
+/// manually. Structs that aren't eligible for a builder (tuple and unit structs) are
+/// skipped. Structs that already have a `#[derive(Builder)]` on them don't get
+/// another one, but their `#[builder(...)]` attributes work as usual. Add a
+/// `#[builder_skip]` attribute to a struct to opt it out of the builder generation.
+///
+/// The module must be defined inline, i.e. with `mod name { ... }` syntax. Custom
+/// inner attributes (`#![builder_all]`) aren't supported by stable Rust yet, so
+/// the attribute should be placed on top of the `mod` item instead.
+///
+/// # Quick example
+///
+/// ```rust
+/// #[bon::builder_all]
+/// mod dto {
+///     pub struct User {
+///         pub name: String,
+///         pub level: Option<u32>,
+///     }
+///
+///     // This struct doesn't get a builder
+///     #[builder_skip]
+///     pub struct Point {
+///         pub x: u32,
+///         pub y: u32,
+///     }
+/// }
+///
+/// let user = dto::User::builder()
+///     .name("Bon".to_owned())
+///     .build();
+///
+/// assert_eq!(user.name, "Bon");
+/// assert_eq!(user.level, None);
+/// ```
+///
+/// See the full documentation for more details:
+/// - [Attributes reference](https://elastio.github.io/bon/reference/builder-all)
+#[proc_macro_attribute]
+pub fn builder_all(params: TokenStream, item: TokenStream) -> TokenStream {
+    builder_all::generate(params.into(), item.into()).into()
+}
+
 /// Creates any map-like collection that implements [`FromIterator<(K, V)>`].
 ///
 /// It automatically converts each key and value to the target type using [`Into`].
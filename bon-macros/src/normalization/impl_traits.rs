@@ -4,32 +4,56 @@ use syn::visit_mut::VisitMut;
 pub(crate) struct NormalizeImplTraits;
 
 impl VisitMut for NormalizeImplTraits {
-    fn visit_impl_item_fn_mut(&mut self, fn_item: &mut syn::ImplItemFn) {
+    fn visit_item_impl_mut(&mut self, item_impl: &mut syn::ItemImpl) {
         // We are interested only in signatures of functions. Don't recurse
         // into the function's block.
-        self.visit_signature_mut(&mut fn_item.sig);
+        for item in &mut item_impl.items {
+            if let syn::ImplItem::Fn(fn_item) = item {
+                assign_type_params(&mut fn_item.sig, Some(&item_impl.generics));
+            }
+        }
     }
 
     fn visit_signature_mut(&mut self, signature: &mut syn::Signature) {
-        let mut visitor = AssignTypeParams::new(&mut signature.generics);
+        assign_type_params(signature, None);
+    }
+}
 
-        for arg in &mut signature.inputs {
-            visitor.visit_fn_arg_mut(arg);
-        }
+/// Replaces `impl Trait` in the function's arguments with generic params. They
+/// must not clash with the generics of the function and the `impl` block.
+fn assign_type_params(signature: &mut syn::Signature, impl_generics: Option<&syn::Generics>) {
+    let mut visitor = AssignTypeParams {
+        generics: &mut signature.generics,
+        impl_generics,
+        next_type_param_index: 0,
+    };
+
+    for arg in &mut signature.inputs {
+        visitor.visit_fn_arg_mut(arg);
     }
 }
 
 struct AssignTypeParams<'a> {
     generics: &'a mut syn::Generics,
+    impl_generics: Option<&'a syn::Generics>,
     next_type_param_index: usize,
 }
 
-impl<'a> AssignTypeParams<'a> {
-    fn new(generics: &'a mut syn::Generics) -> Self {
-        Self {
-            generics,
-            next_type_param_index: 0,
-        }
+impl AssignTypeParams<'_> {
+    fn is_taken(&self, ident: &syn::Ident) -> bool {
+        self.generics
+            .params
+            .iter()
+            .chain(
+                self.impl_generics
+                    .into_iter()
+                    .flat_map(|generics| &generics.params),
+            )
+            .any(|param| match param {
+                syn::GenericParam::Type(param) => param.ident == *ident,
+                syn::GenericParam::Const(param) => param.ident == *ident,
+                syn::GenericParam::Lifetime(_) => false,
+            })
     }
 }
 
@@ -51,10 +75,19 @@ impl VisitMut for AssignTypeParams<'_> {
             return;
         };
 
-        let index = self.next_type_param_index;
-        self.next_type_param_index += 1;
+        // Skip the names that are already taken by the generic params of the
+        // function or the impl block to avoid the clashes with them
+        let type_param = loop {
+            let index = self.next_type_param_index;
+            self.next_type_param_index += 1;
+
+            let type_param = quote::format_ident!("__{index}");
+
+            if !self.is_taken(&type_param) {
+                break type_param;
+            }
+        };
 
-        let type_param = quote::format_ident!("__{index}");
         let impl_trait = std::mem::replace(ty, syn::Type::Path(syn::parse_quote!(#type_param)));
 
         let impl_trait = match impl_trait {
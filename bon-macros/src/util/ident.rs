@@ -19,12 +19,21 @@ pub(crate) trait IdentExt {
     /// identifier.
     fn snake_to_pascal_case(&self) -> Self;
 
+    /// Converts the ident (assumed to be in `PascalCase`) to `snake_case` without
+    /// preserving its span for the same reason as [`Self::snake_to_pascal_case`].
+    fn pascal_to_snake_case(&self) -> Self;
+
     /// Creates a new ident with the given name and span. If the name starts with
     /// `r#` then automatically creates a raw ident.
     fn new_maybe_raw(name: &str, span: Span) -> Self;
 
     /// Returns the name of the identifier stripping the `r#` prefix if it exists.
     fn raw_name(&self) -> String;
+
+    /// Checks if the identifier would pass the `non_snake_case` lint. This mirrors
+    /// the check in `rustc`: leading and trailing underscores are ignored, and the
+    /// rest must have no uppercase letters and no consecutive underscores.
+    fn is_snake_case(&self) -> bool;
 }
 
 impl IdentExt for syn::Ident {
@@ -38,6 +47,11 @@ impl IdentExt for syn::Ident {
         Self::new(&renamed, Span::call_site())
     }
 
+    fn pascal_to_snake_case(&self) -> Self {
+        let renamed = RenameRule::SnakeCase.apply_to_variant(self.raw_name());
+        Self::new_maybe_raw(&renamed, Span::call_site())
+    }
+
     fn new_maybe_raw(name: &str, span: Span) -> Self {
         if let Some(name) = name.strip_prefix("r#") {
             Self::new_raw(name, span)
@@ -54,4 +68,10 @@ impl IdentExt for syn::Ident {
             name
         }
     }
+    fn is_snake_case(&self) -> bool {
+        let name = self.raw_name();
+        let name = name.trim_matches('_');
+
+        !name.contains("__") && !name.chars().any(char::is_uppercase)
+    }
 }
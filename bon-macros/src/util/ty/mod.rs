@@ -1,6 +1,7 @@
 mod match_types;
 
 use crate::util::prelude::*;
+use quote::quote;
 
 pub(crate) trait TypeExt {
     /// Try downcasting the type to [`syn::Type::Path`]
@@ -32,6 +33,10 @@ pub(crate) trait TypeExt {
     /// Any wildcards in `Self` will not be specially handled. Only wildcards in `pattern`
     /// have semantic meaning.
     fn matches(&self, pattern: &syn::Type) -> Result<bool>;
+
+    /// Renders the type the way it's usually written in code. The default
+    /// `Display` implementation of the token stream puts spaces between all tokens.
+    fn to_code_string(&self) -> String;
 }
 
 impl TypeExt for syn::Type {
@@ -105,4 +110,30 @@ impl TypeExt for syn::Type {
     fn matches(&self, pattern: &syn::Type) -> Result<bool> {
         match_types::match_types(self, pattern)
     }
+
+    fn to_code_string(&self) -> String {
+        let mut output = quote!(#self).to_string();
+
+        let replacements = [
+            (" < ", "<"),
+            ("< ", "<"),
+            (" <", "<"),
+            (" >", ">"),
+            (" :: ", "::"),
+            (":: ", "::"),
+            (" ,", ","),
+            (" ;", ";"),
+            ("& ", "&"),
+            ("[ ", "["),
+            (" ]", "]"),
+            ("( ", "("),
+            (" )", ")"),
+        ];
+
+        for (from, to) in replacements {
+            output = output.replace(from, to);
+        }
+
+        output
+    }
 }
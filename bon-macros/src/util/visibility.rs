@@ -0,0 +1,121 @@
+use crate::util::prelude::*;
+
+pub(crate) trait VisibilityExt {
+    /// Returns the visibility that is equivalent to this one when it's used
+    /// on an item placed in a child module. For example, the private visibility
+    /// becomes `pub(super)`, and `pub(super)` becomes `pub(in super::super)`.
+    fn into_equivalent_in_child_module(self) -> Result<syn::Visibility>;
+
+    /// Returns the narrower of this visibility and the `cap`. The visibilities
+    /// are ordered as `pub`, `pub(crate)`, `pub(in path)`, private. The paths of
+    /// two `pub(in path)` visibilities aren't compared, in which case this
+    /// visibility is returned.
+    fn capped_by(self, cap: &syn::Visibility) -> syn::Visibility;
+}
+
+fn visibility_rank(vis: &syn::Visibility) -> u8 {
+    match vis {
+        syn::Visibility::Public(_) => 3,
+        syn::Visibility::Restricted(restricted) if restricted.path.is_ident("crate") => 2,
+        syn::Visibility::Restricted(restricted) if restricted.path.is_ident("self") => 0,
+        syn::Visibility::Restricted(_) => 1,
+        syn::Visibility::Inherited => 0,
+    }
+}
+
+impl VisibilityExt for syn::Visibility {
+    fn capped_by(self, cap: &syn::Visibility) -> syn::Visibility {
+        if visibility_rank(&self) <= visibility_rank(cap) {
+            self
+        } else {
+            cap.clone()
+        }
+    }
+
+    fn into_equivalent_in_child_module(self) -> Result<syn::Visibility> {
+        let restricted = match self {
+            Self::Public(_) => return Ok(self),
+            Self::Inherited => return Ok(syn::parse_quote!(pub(super))),
+            Self::Restricted(restricted) => restricted,
+        };
+
+        let path = &restricted.path;
+
+        if path.starts_with_segment("crate") || path.leading_colon.is_some() {
+            return Ok(Self::Restricted(restricted));
+        }
+
+        if path.starts_with_segment("self") {
+            let rest = path.segments.iter().skip(1);
+            return Ok(syn::parse_quote!(pub(in super #(::#rest)*)));
+        }
+
+        if path.starts_with_segment("super") {
+            return Ok(syn::parse_quote!(pub(in super::#path)));
+        }
+
+        bail!(
+            &restricted,
+            "unsupported visibility; expected `pub(crate)`, `pub(self)`, `pub(super)` \
+            or `pub(in path)` where the path starts with `crate`, `self` or `super`"
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // One less `&` character to type in assertions
+    #![allow(clippy::needless_pass_by_value)]
+    use super::*;
+    use quote::quote;
+    use syn::parse_quote as pq;
+
+    #[track_caller]
+    fn assert_child_vis(vis: syn::Visibility, expected: syn::Visibility) {
+        let actual = vis.into_equivalent_in_child_module().unwrap();
+        assert_eq!(quote!(#actual).to_string(), quote!(#expected).to_string());
+    }
+
+    #[test]
+    fn into_equivalent_in_child_module() {
+        assert_child_vis(syn::Visibility::Inherited, pq!(pub(super)));
+        assert_child_vis(pq!(pub), pq!(pub));
+        assert_child_vis(pq!(pub(crate)), pq!(pub(crate)));
+        assert_child_vis(pq!(pub(in crate::foo)), pq!(pub(in crate::foo)));
+        assert_child_vis(pq!(pub(self)), pq!(pub(in super)));
+        assert_child_vis(pq!(pub(in self::foo)), pq!(pub(in super::foo)));
+        assert_child_vis(pq!(pub(super)), pq!(pub(in super::super)));
+        assert_child_vis(pq!(pub(in super::super)), pq!(pub(in super::super::super)));
+    }
+
+    #[track_caller]
+    fn assert_capped_vis(vis: syn::Visibility, cap: syn::Visibility, expected: syn::Visibility) {
+        let actual = vis.capped_by(&cap);
+        assert_eq!(quote!(#actual).to_string(), quote!(#expected).to_string());
+    }
+
+    #[test]
+    fn capped_by() {
+        assert_capped_vis(pq!(pub), pq!(pub), pq!(pub));
+        assert_capped_vis(pq!(pub), pq!(pub(crate)), pq!(pub(crate)));
+        assert_capped_vis(pq!(pub(crate)), pq!(pub), pq!(pub(crate)));
+        assert_capped_vis(
+            pq!(pub),
+            syn::Visibility::Inherited,
+            syn::Visibility::Inherited,
+        );
+        assert_capped_vis(
+            syn::Visibility::Inherited,
+            pq!(pub),
+            syn::Visibility::Inherited,
+        );
+        assert_capped_vis(pq!(pub(super)), pq!(pub(crate)), pq!(pub(super)));
+        assert_capped_vis(
+            pq!(pub(crate)),
+            pq!(pub(in crate::foo)),
+            pq!(pub(in crate::foo)),
+        );
+        assert_capped_vis(pq!(pub(self)), pq!(pub(super)), pq!(pub(self)));
+        assert_capped_vis(pq!(pub(super)), pq!(pub(in crate::foo)), pq!(pub(super)));
+    }
+}
@@ -9,6 +9,41 @@ pub use bon_macros::*;
 #[doc(hidden)]
 pub mod private;
 
+/// Implemented by every builder in the states where all of its required members
+/// are set, i.e. where the builder can finish building. This allows writing the
+/// code that is generic over any builder that is ready to finish building.
+///
+/// The [`finish()`](Finish::finish) method delegates to the inherent finishing
+/// function of the builder (`build()` by default). The trait isn't implemented
+/// for the builders whose finishing function is `async` or `unsafe`, accepts
+/// the arguments with `#[builder(finish_fn)]`, returns an `impl Trait` type,
+/// or has a custom visibility.
+///
+/// ```
+/// use bon::{Builder, Finish};
+///
+/// fn finish_twice<B: Finish + Clone>(builder: B) -> [B::Output; 2] {
+///     [builder.clone().finish(), builder.finish()]
+/// }
+///
+/// #[derive(Builder)]
+/// #[builder(derive(Clone))]
+/// struct Point {
+///     x: u32,
+///     y: u32,
+/// }
+///
+/// let [first, second] = finish_twice(Point::builder().x(1).y(2));
+/// assert_eq!((first.x, second.y), (1, 2));
+/// ```
+pub trait Finish {
+    /// The value returned from the finishing function
+    type Output;
+
+    /// Finishes building and returns the requested object
+    fn finish(self) -> Self::Output;
+}
+
 /// Same as [`std::vec!`] but converts each element with [`Into`].
 ///
 /// **WARNING:** it's not recommended to import this macro into scope. Reference it
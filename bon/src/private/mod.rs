@@ -19,9 +19,25 @@ pub mod ide;
 #[cfg(feature = "alloc")]
 pub extern crate alloc;
 
+/// Used to implement `#[builder(defmt_trace)]` without requiring the crate
+/// that uses the attribute to depend on `defmt` itself.
+#[cfg(feature = "defmt")]
+pub use defmt;
+
+/// Used to generate `#[diagnostic::on_unimplemented]` attributes only on
+/// the compiler versions that support them.
+pub use rustversion;
+
 pub fn assert_clone<T: Clone>() {}
 pub fn assert_debug<T: ?Sized + core::fmt::Debug>() {}
 
+/// Gives the closure from `#[builder(setter_body = ...)]` the expected signature,
+/// so that the type of its parameter doesn't need to be annotated.
+#[inline(always)]
+pub fn apply_setter_body<T>(value: T, body: impl FnOnce(T) -> T) -> T {
+    body(value)
+}
+
 /// Marker trait to denote the state of the member that is not set yet.
 #[rustversion::attr(
     since(1.78.0),
@@ -44,6 +60,24 @@ pub struct Unset<T>(pub T);
 
 impl<T> IsUnset for Unset<T> {}
 
+/// All unset members are equal to each other, so they don't affect the result
+/// of comparing the builders with `derive(PartialEq)`.
+impl<T> PartialEq for Unset<T> {
+    #[inline(always)]
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<T> Eq for Unset<T> {}
+
+/// Unset members don't contribute to the hash of the builder with `derive(Hash)`,
+/// which keeps it consistent with the `PartialEq` implementation.
+impl<T> core::hash::Hash for Unset<T> {
+    #[inline(always)]
+    fn hash<H: core::hash::Hasher>(&self, _: &mut H) {}
+}
+
 /// A trait used to transition optional members to the [`Set`] state.
 ///
 /// It also provides a better error message when the member is not set.
@@ -75,6 +109,43 @@ impl<T, Member> IntoSet<Option<T>, Member> for Unset<Optional> {
     }
 }
 
+/// A trait used to borrow the values of the members in the view generated
+/// with `#[builder(view = ...)]`. Required members are borrowed as `&T`, and
+/// optional members are borrowed as `Option<&T>`.
+///
+/// The `Member` generic parameter plays the same role as in [`IntoSet`].
+#[rustversion::attr(
+    since(1.78.0),
+    diagnostic::on_unimplemented(
+        message = "can't build the view yet; the member `{Member}` was not set",
+        label = "the member `{Member}` was not set"
+    )
+)]
+pub trait IntoSetRef<T, Member> {
+    fn into_set_ref(self) -> T;
+}
+
+impl<'a, T, Member> IntoSetRef<&'a T, Member> for &'a Set<T> {
+    #[inline(always)]
+    fn into_set_ref(self) -> &'a T {
+        &self.0
+    }
+}
+
+impl<'a, T, Member> IntoSetRef<Option<&'a T>, Member> for &'a Set<Option<T>> {
+    #[inline(always)]
+    fn into_set_ref(self) -> Option<&'a T> {
+        self.0.as_ref()
+    }
+}
+
+impl<'a, T, Member> IntoSetRef<Option<&'a T>, Member> for &'a Unset<Optional> {
+    #[inline(always)]
+    fn into_set_ref(self) -> Option<&'a T> {
+        None
+    }
+}
+
 /// Implemented by `Unset` and `Set` states of members, which are basically
 /// all possible states of a member.
 pub trait MemberState {
@@ -95,6 +166,57 @@ impl<T> MemberState for Unset<T> {
     }
 }
 
+/// Projects the state of a member to a reference to its value if the member
+/// is set or to `()` if it's not set. It's used by the struct generated with
+/// `#[builder(as_set_fields)]`, that has a field of type [`MemberRef::Ref`]
+/// for every member.
+pub trait MemberRef<'a> {
+    type Ref;
+
+    fn member_ref(&'a self) -> Self::Ref;
+}
+
+impl<'a, T: 'a> MemberRef<'a> for Set<T> {
+    type Ref = &'a T;
+
+    #[inline(always)]
+    fn member_ref(&'a self) -> &'a T {
+        &self.0
+    }
+}
+
+impl<'a, T> MemberRef<'a> for Unset<T> {
+    type Ref = ();
+
+    #[inline(always)]
+    fn member_ref(&'a self) {}
+}
+
+/// Gives mutable access to the value of a member that is already set. It's
+/// used by the `&mut self` setters generated with `#[builder(mut_setters)]`,
+/// which overwrite the value of the member without changing the state of
+/// the builder.
+pub trait MemberMut<T> {
+    fn member_mut(&mut self) -> &mut T;
+}
+
+impl<T> MemberMut<T> for Set<T> {
+    #[inline(always)]
+    fn member_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+/// Implemented only when `Self` and `T` are the same type. The finishing
+/// function of a struct builder has a type parameter for every type parameter
+/// of the struct, that is bound to be equal to it with this trait in both
+/// directions. This way the type parameter of the struct can be specified
+/// with a turbofish at the finishing function, and it's still inferred
+/// automatically if there is no turbofish.
+pub trait TypeEq<T: ?Sized> {}
+
+impl<T: ?Sized> TypeEq<T> for T {}
+
 /// This is all a big embarrassing workaround, please don't oversee 😳😳😳.
 ///
 /// Anyway, if you are curious what the hell is going on here, then here is
@@ -200,8 +322,83 @@ macro_rules! __eval_cfg_callback {
     };
 }
 
+/// Logs a setter call with `defmt::trace!` if the `defmt` feature is enabled.
+/// The macros of `defmt` reference the `defmt` crate by a relative path, so
+/// the re-export is brought into scope under that name. This way the crate
+/// that invokes the macro doesn't need to depend on `defmt` directly.
+#[cfg(feature = "defmt")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __defmt_trace {
+    ($message:literal) => {{
+        use $crate::private::defmt;
+        defmt::trace!($message)
+    }};
+}
+
+/// The `defmt` feature is disabled, so the trace is compiled out.
+#[cfg(not(feature = "defmt"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __defmt_trace {
+    ($message:literal) => {};
+}
+
+/// Generates the `json_schema()` function of the builder from the schema string
+/// if the `json-schema` feature is enabled.
+#[cfg(feature = "json-schema")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __json_schema_fn {
+    ($vis:vis, $schema:literal) => {
+        /// Returns a [JSON Schema](https://json-schema.org/) that describes the
+        /// members of the builder, their types and which of them are required.
+        $vis fn json_schema() -> $crate::private::alloc::string::String {
+            $crate::private::alloc::string::String::from($schema)
+        }
+    };
+}
+
+/// The `json-schema` feature is disabled, so report an error that explains
+/// how to enable it.
+#[cfg(not(feature = "json-schema"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __json_schema_fn {
+    ($vis:vis, $schema:literal) => {
+        ::core::compile_error!(
+            "`#[builder(json_schema)]` requires the `json-schema` feature of `bon` to be enabled"
+        );
+    };
+}
+
+/// Emits the `from_arbitrary()` method if the `arbitrary` feature is enabled.
+/// The `arbitrary` crate must be a dependency of the crate that invokes the
+/// macro, because the method references it by name.
+#[cfg(feature = "arbitrary")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __from_arbitrary_fn {
+    ($($item:tt)*) => {
+        $($item)*
+    };
+}
+
+/// The `arbitrary` feature is disabled, so report an error that explains
+/// how to enable it.
+#[cfg(not(feature = "arbitrary"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __from_arbitrary_fn {
+    ($($item:tt)*) => {
+        ::core::compile_error!(
+            "`#[builder(arbitrary)]` requires the `arbitrary` feature of `bon` to be enabled"
+        );
+    };
+}
+
 #[repr(transparent)]
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Set<T>(pub T);
 
 impl<T: core::fmt::Debug> core::fmt::Debug for Set<T> {
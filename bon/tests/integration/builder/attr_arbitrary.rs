@@ -0,0 +1,81 @@
+use crate::prelude::*;
+
+use arbitrary::{Arbitrary, Unstructured};
+
+#[test]
+fn struct_smoke() {
+    #[derive(Builder)]
+    #[builder(arbitrary)]
+    #[allow(dead_code)]
+    struct Sut<'a> {
+        #[builder(start_fn)]
+        id: u8,
+
+        #[builder(finish_fn)]
+        retries: u8,
+
+        #[builder(checked(with = |port: &u16| if *port == 0 { Err(()) } else { Ok(()) }, error = ()))]
+        port: u16,
+
+        #[builder(into)]
+        payload: &'a [u8],
+
+        level: Option<u8>,
+
+        #[builder(default)]
+        verbose: bool,
+
+        #[builder(skip = 42)]
+        skipped: u8,
+    }
+
+    let data = [7, 80, 0, 3, 1, 2, 3, 1, 9, 1, 1, 5];
+
+    let actual = Sut::from_arbitrary(&mut Unstructured::new(&data)).unwrap();
+
+    // The values are consumed from the input in the order of the members'
+    // declaration, and the members with defaults are consumed as `Option`s.
+    // The finishing function's arguments go last.
+    let mut u = Unstructured::new(&data);
+    let id = u8::arbitrary(&mut u).unwrap();
+    let port = u16::arbitrary(&mut u).unwrap();
+    let payload = <&[u8]>::arbitrary(&mut u).unwrap();
+    let level = Option::<u8>::arbitrary(&mut u).unwrap();
+    let verbose = Option::<bool>::arbitrary(&mut u).unwrap();
+    let retries = u8::arbitrary(&mut u).unwrap();
+
+    assert_eq!(actual.id, id);
+    assert_eq!(actual.port, port);
+    assert_eq!(actual.payload, payload);
+    assert_eq!(actual.level, level);
+    assert_eq!(actual.verbose, verbose.unwrap_or_default());
+    assert_eq!(actual.retries, retries);
+    assert_eq!(actual.skipped, 42);
+
+    // The same input always produces the same value
+    let again = Sut::from_arbitrary(&mut Unstructured::new(&data)).unwrap();
+    assert_eq!(again.payload, actual.payload);
+
+    // The value rejected by the `checked` setter
+    let actual = Sut::from_arbitrary(&mut Unstructured::new(&[7, 0, 0]));
+    assert!(matches!(actual, Err(arbitrary::Error::IncorrectFormat)));
+}
+
+#[test]
+fn generic_struct() {
+    #[derive(Builder)]
+    #[builder(arbitrary)]
+    #[allow(dead_code)]
+    struct Sut<T> {
+        value: T,
+        other: Option<T>,
+    }
+
+    let data = [1, 2, 1, 3, 4];
+
+    let actual = Sut::<u16>::from_arbitrary(&mut Unstructured::new(&data)).unwrap();
+
+    let mut u = Unstructured::new(&data);
+    assert_eq!(actual.value, u16::arbitrary(&mut u).unwrap());
+    assert_eq!(actual.other, Option::<u16>::arbitrary(&mut u).unwrap());
+}
@@ -0,0 +1,91 @@
+use crate::prelude::*;
+
+#[test]
+fn smoke() {
+    #[derive(Debug, Builder)]
+    #[builder(args_struct = SutArgs)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(start_fn)]
+        id: u32,
+
+        x: u32,
+        y: Option<u32>,
+
+        #[builder(default = x + 1)]
+        z: u32,
+
+        #[builder(skip = x + z)]
+        sum: u32,
+    }
+
+    assert_debug_eq(
+        Sut::from_args(SutArgs {
+            id: 1,
+            x: 2,
+            y: None,
+            z: None,
+        }),
+        expect!["Sut { id: 1, x: 2, y: None, z: 3, sum: 5 }"],
+    );
+
+    assert_debug_eq(
+        Sut::from_args(SutArgs {
+            id: 1,
+            x: 2,
+            y: Some(3),
+            z: Some(4),
+        }),
+        expect!["Sut { id: 1, x: 2, y: Some(3), z: 4, sum: 6 }"],
+    );
+}
+
+#[test]
+fn generics() {
+    #[derive(Debug, Builder)]
+    #[builder(args_struct = SutArgs)]
+    #[allow(dead_code)]
+    struct Sut<'a, T: Default> {
+        x: &'a str,
+
+        #[builder(default)]
+        y: T,
+    }
+
+    assert_debug_eq(
+        Sut::from_args(SutArgs {
+            x: "x",
+            y: None::<u32>,
+        }),
+        expect![[r#"Sut { x: "x", y: 0 }"#]],
+    );
+}
+
+mod field_vis {
+    use crate::prelude::*;
+
+    #[derive(Debug, Builder)]
+    #[builder(args_struct = SutArgs)]
+    #[allow(dead_code)]
+    pub(crate) struct Sut {
+        pub(crate) x: u32,
+        y: Option<u32>,
+    }
+
+    // The private fields of the args struct are accessible in the module of the struct
+    pub(crate) fn with_private() -> Sut {
+        Sut::from_args(SutArgs { x: 1, y: Some(2) })
+    }
+}
+
+#[test]
+fn field_vis() {
+    assert_debug_eq(
+        field_vis::Sut::builder().x(1).build(),
+        expect!["Sut { x: 1, y: None }"],
+    );
+    assert_debug_eq(
+        field_vis::with_private(),
+        expect!["Sut { x: 1, y: Some(2) }"],
+    );
+}
@@ -0,0 +1,60 @@
+use crate::prelude::*;
+
+#[test]
+fn struct_mid_chain() {
+    #[derive(Debug, Builder)]
+    #[builder(as_set_fields)]
+    #[allow(dead_code)]
+    struct Sut {
+        min: u32,
+        max: u32,
+        label: Option<&'static str>,
+    }
+
+    let builder = Sut::builder().min(1);
+
+    let fields: SutBuilderSetFieldsRef<'_, _, _, _> = builder.as_set_fields();
+    let min: &u32 = fields.min;
+    let (): () = fields.max;
+    let (): () = fields.label;
+
+    assert_eq!(*min, 1);
+
+    let builder = builder.max(3).label("range");
+
+    // Validation that spans several members before finishing building
+    let fields = builder.as_set_fields();
+    assert!(fields.min <= fields.max);
+    assert_eq!(fields.label, &Some("range"));
+
+    assert_debug_eq(
+        builder.build(),
+        expect![[r#"Sut { min: 1, max: 3, label: Some("range") }"#]],
+    );
+}
+
+#[test]
+fn fn_generic() {
+    #[builder(as_set_fields)]
+    fn sut<T: Copy>(x: T, y: Option<T>) -> (T, Option<T>) {
+        (x, y)
+    }
+
+    let builder = sut().y(2);
+    let fields = builder.as_set_fields();
+
+    assert_eq!(fields.y, &Some(2));
+
+    let () = fields.x;
+
+    assert_eq!(builder.x(1).call(), (1, Some(2)));
+}
+
+#[test]
+fn no_members() {
+    #[derive(Builder)]
+    #[builder(as_set_fields)]
+    struct Sut {}
+
+    let SutBuilderSetFieldsRef {} = Sut::builder().as_set_fields();
+}
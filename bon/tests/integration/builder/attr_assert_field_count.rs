@@ -0,0 +1,35 @@
+use crate::prelude::*;
+
+#[test]
+fn smoke() {
+    const FIELDS: usize = 3;
+
+    #[derive(Debug, Builder)]
+    #[builder(assert_field_count = 3)]
+    #[allow(dead_code)]
+    struct Literal {
+        #[builder(start_fn)]
+        x1: u32,
+        x2: u32,
+
+        #[builder(skip)]
+        x3: u32,
+    }
+
+    #[derive(Debug, Builder)]
+    #[builder(assert_field_count = FIELDS - 1)]
+    #[allow(dead_code)]
+    struct Expr {
+        x1: u32,
+        x2: Option<u32>,
+    }
+
+    assert_debug_eq(
+        Literal::builder(1).x2(2).build(),
+        expect!["Literal { x1: 1, x2: 2, x3: 0 }"],
+    );
+    assert_debug_eq(
+        Expr::builder().x1(1).build(),
+        expect!["Expr { x1: 1, x2: None }"],
+    );
+}
@@ -0,0 +1,75 @@
+use crate::prelude::*;
+use core::ops::BitOr;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct Flags(u8);
+
+impl Flags {
+    const A: Self = Self(0b001);
+    const B: Self = Self(0b010);
+    const C: Self = Self(0b100);
+}
+
+impl BitOr for Flags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl From<u8> for Flags {
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+
+#[test]
+fn struct_smoke() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(bitor)]
+        flags: Flags,
+
+        #[builder(bitor, name = perm)]
+        perms: Option<u32>,
+
+        #[builder(bitor, default = Flags::C, into)]
+        extra: Flags,
+    }
+
+    assert_debug_eq(
+        Sut::builder().build(),
+        expect!["Sut { flags: Flags(0), perms: None, extra: Flags(4) }"],
+    );
+
+    let actual = Sut::builder()
+        .flags(Flags::A)
+        .perm(0o400)
+        .flags(Flags::B)
+        .extra(1)
+        .perm(0o040)
+        .extra(Flags::B)
+        .build();
+
+    assert_debug_eq(
+        actual,
+        expect!["Sut { flags: Flags(3), perms: Some(288), extra: Flags(7) }"],
+    );
+}
+
+#[test]
+fn fn_smoke() {
+    #[builder]
+    fn sut(#[builder(bitor, via_fn)] flags: Flags, other: u32) -> (Flags, u32) {
+        (flags, other)
+    }
+
+    assert_eq!(
+        sut().flags(Flags::A).other(1).flags_via(|| Flags::C).call(),
+        (Flags(0b101), 1),
+    );
+
+    assert_eq!(sut().other(1).call(), (Flags(0), 1));
+}
@@ -0,0 +1,59 @@
+use crate::prelude::*;
+
+#[test]
+fn struct_smoke() {
+    #[derive(Debug, Builder)]
+    #[builder(blanket_from)]
+    #[allow(dead_code)]
+    struct Meters {
+        #[builder(into)]
+        value: u64,
+    }
+
+    assert_debug_eq(Meters::from(3_u8), expect!["Meters { value: 3 }"]);
+    assert_debug_eq(Meters::from(4_u32), expect!["Meters { value: 4 }"]);
+
+    let meters: Meters = 5_u16.into();
+    assert_debug_eq(meters, expect!["Meters { value: 5 }"]);
+
+    // The builder is still available
+    assert_debug_eq(
+        Meters::builder().value(6_u8).build(),
+        expect!["Meters { value: 6 }"],
+    );
+}
+
+#[test]
+fn optional_and_skipped_members() {
+    #[derive(Debug, Builder)]
+    #[builder(blanket_from, on(u32, into))]
+    #[allow(dead_code)]
+    struct Sut {
+        value: Option<u32>,
+
+        #[builder(skip = value.map(|value| value * 2))]
+        doubled: Option<u32>,
+    }
+
+    assert_debug_eq(
+        Sut::from(2_u8),
+        expect!["Sut { value: Some(2), doubled: Some(4) }"],
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn generic_struct() {
+    #[derive(Debug, Builder)]
+    #[builder(blanket_from)]
+    #[allow(dead_code)]
+    struct Wrapper<T> {
+        #[builder(into)]
+        items: Vec<T>,
+    }
+
+    assert_debug_eq(
+        Wrapper::from(vec![1_u8, 2]),
+        expect!["Wrapper { items: [1, 2] }"],
+    );
+}
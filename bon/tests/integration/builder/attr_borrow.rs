@@ -0,0 +1,64 @@
+use crate::prelude::*;
+use alloc::borrow::Cow;
+
+#[test]
+fn struct_smoke() {
+    #[derive(Debug, Builder)]
+    struct Sut<'a> {
+        #[builder(borrow)]
+        name: Cow<'a, str>,
+
+        #[builder(borrow, name = ids)]
+        items: Option<Cow<'a, [u32]>>,
+
+        #[builder(borrow, default = Cow::Owned(String::from("none")))]
+        note: Cow<'a, str>,
+    }
+
+    let name = String::from("bon");
+    let items = vec![1, 2];
+
+    let sut = Sut::builder().name(&name).ids(&items).build();
+
+    assert!(matches!(sut.name, Cow::Borrowed(_)));
+    assert!(matches!(sut.items, Some(Cow::Borrowed(_))));
+    assert!(matches!(sut.note, Cow::Owned(_)));
+
+    assert_debug_eq(
+        sut,
+        expect![[r#"Sut { name: "bon", items: Some([1, 2]), note: "none" }"#]],
+    );
+
+    let sut = Sut::builder()
+        .name("static")
+        .maybe_ids(None)
+        .note("x")
+        .build();
+
+    assert_debug_eq(
+        sut,
+        expect![[r#"Sut { name: "static", items: None, note: "x" }"#]],
+    );
+}
+
+#[test]
+fn fn_smoke() {
+    #[builder]
+    #[allow(clippy::needless_lifetimes)]
+    fn sut<'a>(
+        #[builder(borrow, checked(with = |value: &Cow<'_, str>| {
+            if value.is_empty() { Err(()) } else { Ok(()) }
+        }, error = ()))]
+        name: Cow<'a, str>,
+    ) -> Cow<'a, str> {
+        name
+    }
+
+    let name = String::from("bon");
+
+    assert!(matches!(
+        sut().name(&name).unwrap().call(),
+        Cow::Borrowed("bon")
+    ));
+    assert!(sut().name("").is_err());
+}
@@ -0,0 +1,100 @@
+use crate::prelude::*;
+
+#[test]
+fn smoke() {
+    #[derive(Debug, Builder)]
+    #[builder(build_by_ref)]
+    #[allow(dead_code)]
+    struct Sut {
+        x: u32,
+        y: Option<&'static str>,
+
+        #[builder(default = 3)]
+        z: u32,
+    }
+
+    let builder = Sut::builder().x(1).y("y");
+
+    assert_debug_eq(
+        builder.build(),
+        expect![[r#"Sut { x: 1, y: Some("y"), z: 3 }"#]],
+    );
+
+    // The builder is left intact and can be finished again
+    assert_debug_eq(
+        builder.z(4).build(),
+        expect![[r#"Sut { x: 1, y: Some("y"), z: 4 }"#]],
+    );
+}
+
+#[test]
+fn start_fn_and_finish_fn_members() {
+    #[derive(Debug, Clone)]
+    #[allow(dead_code)]
+    struct Counter(u32);
+
+    #[derive(Debug, Builder)]
+    #[builder(build_by_ref, finish_fn = assemble)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(start_fn)]
+        prefix: Counter,
+
+        #[builder(finish_fn)]
+        suffix: u32,
+
+        value: Counter,
+    }
+
+    let builder = Sut::builder(Counter(1)).value(Counter(2));
+
+    assert_debug_eq(
+        builder.assemble(3),
+        expect!["Sut { prefix: Counter(1), suffix: 3, value: Counter(2) }"],
+    );
+    assert_debug_eq(
+        builder.assemble(4),
+        expect!["Sut { prefix: Counter(1), suffix: 4, value: Counter(2) }"],
+    );
+}
+
+#[test]
+fn unset_members_dont_require_clone() {
+    #[derive(Debug)]
+    struct NotClone;
+
+    #[derive(Debug, Builder)]
+    #[builder(build_by_ref)]
+    #[allow(dead_code)]
+    struct Sut {
+        x: u32,
+        y: Option<NotClone>,
+    }
+
+    let builder = Sut::builder().x(1);
+
+    assert_debug_eq(builder.build(), expect!["Sut { x: 1, y: None }"]);
+    assert_debug_eq(builder.build(), expect!["Sut { x: 1, y: None }"]);
+}
+
+#[test]
+fn methods_that_delegate_to_finish_fn() {
+    #[derive(Debug, Builder)]
+    #[builder(build_by_ref, finish_and_reset, finish_into_slot)]
+    #[allow(dead_code)]
+    struct Sut {
+        x: u32,
+    }
+
+    let builder = Sut::builder().x(1);
+
+    // These methods still consume the builder
+    let (sut, reset) = builder.build_and_reset();
+
+    assert_debug_eq(sut, expect!["Sut { x: 1 }"]);
+
+    let mut slot = None;
+    reset.x(2).build_into_slot(&mut slot);
+
+    assert_debug_eq(slot, expect!["Some(Sut { x: 2 })"]);
+}
@@ -0,0 +1,63 @@
+use crate::prelude::*;
+
+#[test]
+fn type_alias_with_default_ok() {
+    type AppResult<T> = Result<T, &'static str>;
+
+    #[derive(Debug, Builder)]
+    #[builder(build_result_wrapper = AppResult)]
+    #[allow(dead_code)]
+    struct Sut {
+        x: u32,
+        y: Option<u32>,
+    }
+
+    let result: AppResult<Sut> = Sut::builder().x(1).y(2).build();
+
+    assert_debug_eq(result, expect!["Ok(Sut { x: 1, y: Some(2) })"]);
+}
+
+#[test]
+fn custom_wrapper_with_ok_path() {
+    #[derive(Debug)]
+    struct Validated<T> {
+        value: T,
+        warnings: u32,
+    }
+
+    impl<T> Validated<T> {
+        fn new(value: T) -> Self {
+            Self { value, warnings: 0 }
+        }
+    }
+
+    #[derive(Debug, Builder)]
+    #[builder(build_result_wrapper(ty = Validated, ok = Validated::new))]
+    #[allow(dead_code)]
+    struct Sut<T> {
+        x: T,
+    }
+
+    let validated: Validated<Sut<&str>> = Sut::builder().x("x").build();
+
+    assert_eq!(validated.value.x, "x");
+    assert_eq!(validated.warnings, 0);
+}
+
+#[test]
+fn with_finish_and_reset() {
+    #[derive(Debug, Builder)]
+    #[builder(build_result_wrapper(ty = Option, ok = Some), finish_and_reset)]
+    #[allow(dead_code)]
+    struct Sut {
+        x: u32,
+    }
+
+    let (sut, builder) = Sut::builder().x(1).build_and_reset();
+    let sut2 = builder.x(2).build();
+
+    assert_debug_eq(
+        (sut, sut2),
+        expect!["(Some(Sut { x: 1 }), Some(Sut { x: 2 }))"],
+    );
+}
@@ -0,0 +1,52 @@
+use crate::prelude::*;
+
+#[test]
+fn struct_smoke() {
+    #[derive(Builder)]
+    #[builder(capture_locations, derive(Clone))]
+    #[allow(dead_code)]
+    struct Sut {
+        x1: u32,
+        x2: Option<u32>,
+
+        #[builder(name = renamed)]
+        _x3: u32,
+    }
+
+    let builder = Sut::builder();
+    assert!(builder.field_location("x1").is_none());
+
+    let (builder, x1_line) = (builder.x1(1), line!());
+    let (builder, x2_line) = (builder.x2(2), line!());
+
+    let x1 = builder.field_location("x1").unwrap();
+    assert_eq!(x1.file(), file!());
+    assert_eq!(x1.line(), x1_line);
+
+    let x2 = builder.field_location("x2").unwrap();
+    assert_eq!(x2.line(), x2_line);
+
+    assert!(builder.field_location("x3").is_none());
+    assert!(builder.field_location("unknown").is_none());
+
+    let (builder, x3_line) = (builder.clone().renamed(3), line!());
+    assert_eq!(builder.field_location("x3").unwrap().line(), x3_line);
+
+    let _ = builder.build();
+}
+
+#[test]
+fn fn_smoke() {
+    #[builder(capture_locations)]
+    fn sut(arg1: bool, arg2: Option<u32>) -> (bool, Option<u32>) {
+        (arg1, arg2)
+    }
+
+    let (builder, arg2_line) = (sut().arg2(2), line!());
+
+    let arg2 = builder.field_location("arg2").unwrap();
+    assert_eq!(arg2.file(), file!());
+    assert_eq!(arg2.line(), arg2_line);
+
+    assert_eq!(builder.arg1(true).call(), (true, Some(2)));
+}
@@ -0,0 +1,92 @@
+use crate::prelude::*;
+
+#[derive(Debug, PartialEq, Eq)]
+struct PortError(u16);
+
+#[derive(Debug, PartialEq, Eq)]
+struct HostError;
+
+#[derive(Debug, PartialEq, Eq)]
+enum ConfigError {
+    Port(PortError),
+    Host(HostError),
+}
+
+impl From<PortError> for ConfigError {
+    fn from(err: PortError) -> Self {
+        Self::Port(err)
+    }
+}
+
+impl From<HostError> for ConfigError {
+    fn from(err: HostError) -> Self {
+        Self::Host(err)
+    }
+}
+
+// The signature is dictated by the `checked` attribute
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn check_port(port: &u16) -> Result<(), PortError> {
+    if *port == 0 {
+        return Err(PortError(*port));
+    }
+    Ok(())
+}
+
+fn check_host(host: &&str) -> Result<(), HostError> {
+    if host.is_empty() {
+        return Err(HostError);
+    }
+    Ok(())
+}
+
+#[test]
+fn struct_smoke() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Config {
+        #[builder(checked(with = check_port, error = PortError))]
+        port: u16,
+
+        #[builder(checked(with = check_host, error = HostError))]
+        host: &'static str,
+
+        #[builder(checked(with = |level: &u32| if *level > 3 { Err(()) } else { Ok(()) }, error = ()))]
+        level: Option<u32>,
+    }
+
+    fn build(port: u16, host: &'static str) -> Result<Config, ConfigError> {
+        Ok(Config::builder().port(port)?.host(host)?.build())
+    }
+
+    assert_debug_eq(
+        build(80, "localhost"),
+        expect![[r#"Ok(Config { port: 80, host: "localhost", level: None })"#]],
+    );
+    assert_eq!(build(0, "localhost").unwrap_err(), PortError(0).into());
+    assert_eq!(build(80, "").unwrap_err(), HostError.into());
+
+    assert!(Config::builder().level(5).is_err());
+    assert!(Config::builder().maybe_level(None).is_ok());
+}
+
+#[test]
+fn fn_smoke() {
+    #[builder]
+    fn sut(
+        #[builder(checked(with = check_port, error = ConfigError), into)] port: u16,
+        #[builder(checked(with = check_port, error = ConfigError))] maybe: Option<u16>,
+    ) -> (u16, Option<u16>) {
+        (port, maybe)
+    }
+
+    fn call() -> Result<(u16, Option<u16>), ConfigError> {
+        Ok(sut().port(8_u8)?.maybe_maybe(None)?.call())
+    }
+
+    assert_eq!(call(), Ok((8, None)));
+    assert_eq!(
+        sut().port(1_u16).unwrap().maybe(0).err(),
+        Some(ConfigError::Port(PortError(0))),
+    );
+}
@@ -0,0 +1,105 @@
+use crate::prelude::*;
+
+#[derive(Debug, PartialEq, Eq)]
+struct EmptyTagError;
+
+fn check_tag(tag: &&str) -> Result<(), EmptyTagError> {
+    if tag.is_empty() {
+        return Err(EmptyTagError);
+    }
+    Ok(())
+}
+
+#[test]
+fn struct_smoke() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(collection)]
+        ids: Vec<u32>,
+
+        #[builder(collection, name = arg, into)]
+        args: Option<Vec<String>>,
+
+        #[builder(collection, default = vec![1])]
+        extra: Vec<u8>,
+    }
+
+    assert_debug_eq(
+        Sut::builder().build(),
+        expect!["Sut { ids: [], args: None, extra: [1] }"],
+    );
+
+    let actual = Sut::builder()
+        .ids(1)
+        .arg("-v")
+        .extra(2)
+        .ids(2)
+        .arg("--all")
+        .build();
+
+    assert_debug_eq(
+        actual,
+        expect![[r#"
+            Sut {
+                ids: [
+                    1,
+                    2,
+                ],
+                args: Some(
+                    [
+                        "-v",
+                        "--all",
+                    ],
+                ),
+                extra: [
+                    1,
+                    2,
+                ],
+            }"#]],
+    );
+}
+
+#[test]
+fn item_validate() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(collection, item_validate(with = check_tag, error = EmptyTagError))]
+        tags: Vec<&'static str>,
+    }
+
+    fn build(tags: &[&'static str]) -> Result<Sut, EmptyTagError> {
+        let mut builder = Sut::builder().try_tags("first")?;
+        for tag in tags {
+            builder = builder.try_tags(tag)?;
+        }
+        Ok(builder.build())
+    }
+
+    assert_debug_eq(
+        build(&["a", "b"]),
+        expect![[r#"Ok(Sut { tags: ["first", "a", "b"] })"#]],
+    );
+    assert_eq!(build(&["a", ""]).unwrap_err(), EmptyTagError);
+}
+
+#[test]
+fn fn_smoke() {
+    #[builder]
+    fn sut(
+        #[builder(collection)] values: Vec<u32>,
+        #[builder(collection, item_validate(with = check_tag, error = EmptyTagError))] tags: Vec<
+            &'static str,
+        >,
+    ) -> (Vec<u32>, Vec<&'static str>) {
+        (values, tags)
+    }
+
+    fn call() -> Result<(Vec<u32>, Vec<&'static str>), EmptyTagError> {
+        Ok(sut().values(1).try_tags("a")?.values(2).call())
+    }
+
+    assert_eq!(call(), Ok((vec![1, 2], vec!["a"])));
+    assert!(sut().try_tags("").is_err());
+}
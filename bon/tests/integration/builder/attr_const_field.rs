@@ -0,0 +1,66 @@
+use crate::prelude::*;
+
+#[test]
+fn const_field_is_always_the_constant() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(const_field = 2)]
+        version: u32,
+
+        #[builder(const_field = "bon")]
+        _kind: &'static str,
+
+        x: u32,
+    }
+
+    assert_eq!(Sut::VERSION, 2);
+    assert_eq!(Sut::KIND, "bon");
+
+    assert_debug_eq(
+        Sut::builder().x(1).build(),
+        expect![[r#"Sut { version: 2, _kind: "bon", x: 1 }"#]],
+    );
+}
+
+#[test]
+fn generic_struct() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Sut<T> {
+        #[builder(const_field = 16)]
+        capacity: usize,
+
+        x: T,
+    }
+
+    assert_eq!(Sut::<bool>::CAPACITY, 16);
+
+    assert_debug_eq(
+        Sut::builder().x(true).build(),
+        expect!["Sut { capacity: 16, x: true }"],
+    );
+}
+
+#[test]
+fn explicit_name() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(const_field(name = MAGIC, value = 0xB0))]
+        _1: u8,
+
+        #[builder(const_field(name = DEFAULT_Y, value = 3))]
+        _y: u32,
+
+        y: u32,
+    }
+
+    assert_eq!(Sut::MAGIC, 0xB0);
+    assert_eq!(Sut::DEFAULT_Y, 3);
+
+    assert_debug_eq(
+        Sut::builder().y(1).build(),
+        expect!["Sut { _1: 176, _y: 3, y: 1 }"],
+    );
+}
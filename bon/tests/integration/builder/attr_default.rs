@@ -353,3 +353,102 @@ mod interaction_with_positional_members {
         );
     }
 }
+
+fn panicking_default() -> u32 {
+    panic!("the default must not be evaluated if the member is set")
+}
+
+#[test]
+fn struct_default_is_lazy() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(default = panicking_default())]
+        x: u32,
+
+        #[builder(into, default = panicking_default())]
+        y: u32,
+    }
+
+    assert_debug_eq(
+        Sut::builder().x(1).y(2_u8).build(),
+        expect!["Sut { x: 1, y: 2 }"],
+    );
+
+    assert_debug_eq(
+        Sut::builder().maybe_x(Some(1)).maybe_y(Some(2_u8)).build(),
+        expect!["Sut { x: 1, y: 2 }"],
+    );
+}
+
+#[test]
+fn fn_default_is_lazy() {
+    #[builder]
+    fn sut(#[builder(default = panicking_default())] x: u32) -> u32 {
+        x
+    }
+
+    assert_eq!(sut().x(3).call(), 3);
+}
+
+mod serde_interop {
+    use crate::prelude::*;
+
+    fn default_level() -> u32 {
+        3
+    }
+
+    #[test]
+    fn serde_default_makes_member_optional() {
+        #[derive(Debug, Builder, serde::Deserialize)]
+        #[allow(dead_code)]
+        struct Sut {
+            name: &'static str,
+
+            #[serde(default)]
+            retries: u32,
+
+            #[serde(rename = "lvl", default = "default_level")]
+            level: u32,
+
+            // The explicit builder default takes precedence over the serde one
+            #[serde(default)]
+            #[builder(default = 10)]
+            timeout: u32,
+
+            #[serde(default)]
+            tag: Option<u32>,
+        }
+
+        assert_debug_eq(
+            Sut::builder().name("bon").build(),
+            expect![[r#"
+                Sut {
+                    name: "bon",
+                    retries: 0,
+                    level: 3,
+                    timeout: 10,
+                    tag: None,
+                }"#]],
+        );
+
+        assert_debug_eq(
+            Sut::builder()
+                .name("bon")
+                .retries(1)
+                .maybe_level(Some(2))
+                .tag(4)
+                .build(),
+            expect![[r#"
+                Sut {
+                    name: "bon",
+                    retries: 1,
+                    level: 2,
+                    timeout: 10,
+                    tag: Some(
+                        4,
+                    ),
+                }"#]],
+        );
+    }
+}
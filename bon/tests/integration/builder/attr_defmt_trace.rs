@@ -0,0 +1,28 @@
+use crate::prelude::*;
+
+// The crate doesn't depend on `defmt` directly. The generated code must use
+// the `defmt` crate re-exported by `bon` when the `defmt` feature is enabled.
+#[test]
+fn defmt_trace() {
+    #[derive(Debug, Builder)]
+    #[builder(defmt_trace)]
+    #[allow(dead_code)]
+    struct Sut {
+        x1: u32,
+        x2: Option<u32>,
+
+        #[builder(default)]
+        x3: u32,
+    }
+
+    #[builder(defmt_trace)]
+    fn sum(x1: u32, x2: Option<u32>) -> u32 {
+        x1 + x2.unwrap_or_default()
+    }
+
+    let actual = Sut::builder().x1(1).x2(2).build();
+
+    assert_debug_eq(actual, expect!["Sut { x1: 1, x2: Some(2), x3: 0 }"]);
+
+    assert_eq!(sum().x1(1).maybe_x2(None).call(), 1);
+}
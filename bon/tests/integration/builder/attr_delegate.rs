@@ -0,0 +1,79 @@
+use crate::prelude::*;
+
+#[test]
+fn struct_smoke() {
+    #[derive(Debug, Builder)]
+    #[builder(delegate(
+        fn area(&self) -> u32,
+        fn scaled(self, factor: u32) -> Rect,
+        fn grow<T: Into<u32>>(&mut self, by: T) -> u32,
+    ))]
+    struct Rect {
+        width: u32,
+
+        #[builder(default = width)]
+        height: u32,
+    }
+
+    impl Rect {
+        fn area(&self) -> u32 {
+            self.width * self.height
+        }
+
+        fn scaled(self, factor: u32) -> Self {
+            Self {
+                width: self.width * factor,
+                height: self.height * factor,
+            }
+        }
+
+        fn grow<T: Into<u32>>(&mut self, by: T) -> u32 {
+            let by = by.into();
+            self.width += by;
+            self.height += by;
+            self.area()
+        }
+    }
+
+    // The methods of the built value are available on the builder
+    // as soon as all required members are set
+    assert_eq!(Rect::builder().width(2).area(), 4);
+    assert_eq!(Rect::builder().width(2).height(3).area(), 6);
+    assert_eq!(Rect::builder().width(1).grow(1_u8), 4);
+
+    assert_debug_eq(
+        Rect::builder().width(1).height(2).scaled(3),
+        expect!["Rect { width: 3, height: 6 }"],
+    );
+}
+
+#[test]
+fn borrows_not_from_self() {
+    #[derive(Builder)]
+    #[builder(delegate(
+        fn name(&self) -> &'a str,
+        fn formatter(&self) -> fn(&u32) -> u32,
+    ))]
+    struct Sut<'a> {
+        label: &'a str,
+    }
+
+    impl<'a> Sut<'a> {
+        fn name(&self) -> &'a str {
+            self.label
+        }
+
+        fn formatter(&self) -> fn(&u32) -> u32 {
+            if self.label.is_empty() {
+                |value| *value
+            } else {
+                |value| value * 2
+            }
+        }
+    }
+
+    let name = Sut::builder().label("Bon").name();
+
+    assert_eq!(name, "Bon");
+    assert_eq!(Sut::builder().label("Bon").formatter()(&2), 4);
+}
@@ -0,0 +1,26 @@
+use crate::prelude::*;
+
+#[test]
+#[allow(deprecated)]
+fn stub_setters_do_nothing() {
+    #[derive(Debug, Builder)]
+    #[builder(deprecated_removed_setter(
+        /// Replaced by `timeout_ms`
+        timeout: u32,
+        legacy_retries: impl Into<u64>,
+    ))]
+    #[allow(dead_code)]
+    struct Sut {
+        timeout_ms: u64,
+        name: Option<&'static str>,
+    }
+
+    let sut = Sut::builder()
+        .timeout(5)
+        .legacy_retries(1_u32)
+        .timeout_ms(5000)
+        .timeout(6)
+        .build();
+
+    assert_debug_eq(sut, expect!["Sut { timeout_ms: 5000, name: None }"]);
+}
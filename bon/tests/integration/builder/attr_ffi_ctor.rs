@@ -0,0 +1,80 @@
+use crate::prelude::*;
+
+#[test]
+fn smoke() {
+    #[derive(Debug, Builder)]
+    #[builder(ffi_ctor = bon_test_ffi_ctor_smoke_new)]
+    #[repr(C)]
+    struct Sut {
+        x: u32,
+        y: Option<&'static u8>,
+        ptr: *const u8,
+
+        #[builder(skip = x * 2)]
+        z: u32,
+    }
+
+    static VAL: u8 = 42;
+
+    let actual = bon_test_ffi_ctor_smoke_new(1, Some(&VAL), core::ptr::null());
+
+    assert_debug_eq(
+        &actual,
+        expect!["Sut { x: 1, y: Some(42), ptr: 0x0, z: 2 }"],
+    );
+
+    // The builder is still generated
+    let _ = Sut::builder().x(1).ptr(core::ptr::null()).build();
+}
+
+#[test]
+fn with_lifetimes() {
+    #[derive(Builder)]
+    #[builder(ffi_ctor = bon_test_ffi_ctor_with_lifetimes_new)]
+    #[repr(C)]
+    struct Sut<'a> {
+        #[builder(start_fn)]
+        x: &'a u32,
+        y: i8,
+    }
+
+    let actual = bon_test_ffi_ctor_with_lifetimes_new(&1, 2);
+
+    assert_eq!(*actual.x, 1);
+    assert_eq!(actual.y, 2);
+}
+
+#[test]
+fn repr_transparent() {
+    #[derive(Builder)]
+    #[builder(ffi_ctor = bon_test_ffi_ctor_repr_transparent_new)]
+    #[repr(transparent)]
+    struct Sut {
+        x: u32,
+    }
+
+    let actual = bon_test_ffi_ctor_repr_transparent_new(1);
+
+    assert_eq!(actual.x, 1);
+}
+
+// The `#[no_mangle]` attribute generated by the macro isn't reported
+// by the `unsafe_code` lint, because it comes from an external macro
+#[forbid(unsafe_code)]
+mod forbid_unsafe_code {
+    use crate::prelude::*;
+
+    #[derive(Builder)]
+    #[builder(ffi_ctor = bon_test_ffi_ctor_forbid_unsafe_code_new)]
+    #[repr(C)]
+    pub(super) struct Sut {
+        pub(super) x: u32,
+    }
+}
+
+#[test]
+fn forbid_unsafe_code() {
+    let actual = forbid_unsafe_code::bon_test_ffi_ctor_forbid_unsafe_code_new(1);
+
+    assert_eq!(actual.x, 1);
+}
@@ -0,0 +1,73 @@
+use crate::prelude::*;
+
+mod sut {
+    use crate::prelude::*;
+
+    #[derive(Debug, Builder)]
+    #[builder(field_setter_vis)]
+    #[allow(dead_code)]
+    pub(crate) struct FieldVis {
+        pub x1: u32,
+        pub(crate) x2: Option<u32>,
+        x3: Option<u32>,
+    }
+
+    // The setters have the builder's visibility by default
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    pub(crate) struct DefaultVis {
+        x1: u32,
+        x2: Option<u32>,
+    }
+
+    // The setters of the private fields are accessible in the module of the struct
+    pub(crate) fn field_vis_with_private() -> FieldVis {
+        FieldVis::builder().x1(1).x2(2).x3(3).build()
+    }
+
+    #[derive(Debug, Builder)]
+    #[builder(field_setter_vis, module = builder_module)]
+    #[allow(dead_code)]
+    pub(crate) struct InModule {
+        pub(crate) x1: u32,
+        x2: Option<u32>,
+    }
+
+    pub(crate) fn in_module_with_private() -> InModule {
+        InModule::builder().x1(1).x2(2).build()
+    }
+}
+
+#[test]
+fn field_vis() {
+    assert_debug_eq(
+        sut::FieldVis::builder().x1(1).x2(2).build(),
+        expect!["FieldVis { x1: 1, x2: Some(2), x3: None }"],
+    );
+
+    assert_debug_eq(
+        sut::field_vis_with_private(),
+        expect!["FieldVis { x1: 1, x2: Some(2), x3: Some(3) }"],
+    );
+}
+
+#[test]
+fn default_vis() {
+    assert_debug_eq(
+        sut::DefaultVis::builder().x1(1).x2(2).build(),
+        expect!["DefaultVis { x1: 1, x2: Some(2) }"],
+    );
+}
+
+#[test]
+fn field_vis_in_child_module() {
+    assert_debug_eq(
+        sut::InModule::builder().x1(1).build(),
+        expect!["InModule { x1: 1, x2: None }"],
+    );
+
+    assert_debug_eq(
+        sut::in_module_with_private(),
+        expect!["InModule { x1: 1, x2: Some(2) }"],
+    );
+}
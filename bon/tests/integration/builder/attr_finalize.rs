@@ -0,0 +1,57 @@
+use crate::prelude::*;
+
+trait Finalize {
+    fn finalize(&mut self);
+}
+
+#[test]
+fn smoke() {
+    #[derive(Debug, Builder)]
+    #[builder(finalize = Finalize)]
+    #[allow(dead_code)]
+    struct Sut {
+        tags: Vec<&'static str>,
+
+        #[builder(skip)]
+        tags_count: usize,
+    }
+
+    impl Finalize for Sut {
+        fn finalize(&mut self) {
+            self.tags.sort_unstable();
+            self.tags_count = self.tags.len();
+        }
+    }
+
+    assert_debug_eq(
+        Sut::builder().tags(vec!["b", "c", "a"]).build(),
+        expect![[r#"Sut { tags: ["a", "b", "c"], tags_count: 3 }"#]],
+    );
+}
+
+#[test]
+fn generic_struct_with_path() {
+    mod hooks {
+        pub(super) trait Normalize {
+            fn finalize(&mut self);
+        }
+    }
+
+    #[derive(Debug, Builder)]
+    #[builder(finalize = hooks::Normalize)]
+    struct Sut<T: Ord> {
+        values: Vec<T>,
+    }
+
+    impl<T: Ord> hooks::Normalize for Sut<T> {
+        fn finalize(&mut self) {
+            self.values.sort_unstable();
+            self.values.dedup();
+        }
+    }
+
+    assert_debug_eq(
+        Sut::builder().values(vec![3, 1, 3, 2]).build(),
+        expect!["Sut { values: [1, 2, 3] }"],
+    );
+}
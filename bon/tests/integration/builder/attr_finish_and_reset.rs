@@ -0,0 +1,91 @@
+use crate::prelude::*;
+
+#[test]
+fn reuse_in_loop() {
+    #[derive(Debug, Builder)]
+    #[builder(finish_and_reset)]
+    #[allow(dead_code)]
+    struct Sut {
+        x: u32,
+        y: Option<u32>,
+    }
+
+    let mut builder = Sut::builder();
+    let mut built = [None, None, None];
+
+    for (i, slot) in (0..).zip(&mut built) {
+        let (sut, reset) = builder
+            .x(i)
+            .maybe_y((i % 2 == 0).then(|| i * 10))
+            .build_and_reset();
+        *slot = Some(sut);
+        builder = reset;
+    }
+
+    assert_debug_eq(
+        built,
+        expect![[r#"
+            [
+                Some(
+                    Sut {
+                        x: 0,
+                        y: Some(
+                            0,
+                        ),
+                    },
+                ),
+                Some(
+                    Sut {
+                        x: 1,
+                        y: None,
+                    },
+                ),
+                Some(
+                    Sut {
+                        x: 2,
+                        y: Some(
+                            20,
+                        ),
+                    },
+                ),
+            ]"#]],
+    );
+}
+
+#[test]
+fn start_fn_and_finish_fn_args() {
+    #[derive(Debug, Builder)]
+    #[builder(finish_and_reset, finish_fn = assemble)]
+    #[allow(dead_code)]
+    struct Sut<T> {
+        #[builder(start_fn)]
+        prefix: &'static str,
+
+        #[builder(finish_fn)]
+        suffix: u32,
+
+        value: T,
+    }
+
+    let (first, reset) = Sut::builder("a").value(true).assemble_and_reset(1);
+
+    // The start function's arguments are preserved in the new builder
+    let second = reset.value(false).assemble(2);
+
+    assert_debug_eq(
+        (first, second),
+        expect![[r#"
+            (
+                Sut {
+                    prefix: "a",
+                    suffix: 1,
+                    value: true,
+                },
+                Sut {
+                    prefix: "a",
+                    suffix: 2,
+                    value: false,
+                },
+            )"#]],
+    );
+}
@@ -0,0 +1,27 @@
+use crate::prelude::*;
+
+#[test]
+fn vis() {
+    mod sut {
+        #[derive(Debug, bon::Builder)]
+        #[builder(finish_fn(name = finish, vis = "pub(super)"))]
+        #[allow(dead_code)]
+        pub(crate) struct Sut {
+            x: u32,
+        }
+    }
+
+    assert_debug_eq(sut::Sut::builder().x(1).finish(), expect!["Sut { x: 1 }"]);
+}
+
+#[test]
+fn vis_fn() {
+    mod sut {
+        #[bon::builder(finish_fn(vis = "pub(super)"))]
+        pub(crate) fn sut(x: u32) -> u32 {
+            x
+        }
+    }
+
+    assert_eq!(sut::sut().x(1).call(), 1);
+}
@@ -0,0 +1,60 @@
+use crate::prelude::*;
+use core::mem::MaybeUninit;
+
+#[test]
+#[allow(unsafe_code)]
+fn smoke() {
+    #[derive(Debug, Builder)]
+    #[builder(finish_into_out)]
+    #[allow(dead_code)]
+    struct Sut {
+        x: u32,
+        y: Option<u32>,
+    }
+
+    let mut slot = MaybeUninit::uninit();
+
+    let sut = Sut::builder().x(1).build_into_out(&mut slot);
+    sut.x += 1;
+
+    // SAFETY: the slot is initialized by `build_into_out()`
+    let sut = unsafe { slot.assume_init() };
+
+    assert_debug_eq(sut, expect!["Sut { x: 2, y: None }"]);
+}
+
+#[test]
+#[allow(unsafe_code)]
+fn finish_fn_args() {
+    #[derive(Debug, Builder)]
+    #[builder(finish_into_out, finish_fn = assemble)]
+    #[allow(dead_code)]
+    struct Sut<T> {
+        #[builder(start_fn)]
+        prefix: &'static str,
+
+        #[builder(finish_fn)]
+        suffix: u32,
+
+        value: T,
+    }
+
+    // The slot is overwritten without dropping the previous value
+    let mut slot = MaybeUninit::new(Sut {
+        prefix: "old",
+        suffix: 0,
+        value: false,
+    });
+
+    Sut::builder("a")
+        .value(true)
+        .assemble_into_out(1, &mut slot);
+
+    // SAFETY: the slot is initialized by `assemble_into_out()`
+    let sut = unsafe { slot.assume_init() };
+
+    assert_debug_eq(
+        sut,
+        expect![[r#"Sut { prefix: "a", suffix: 1, value: true }"#]],
+    );
+}
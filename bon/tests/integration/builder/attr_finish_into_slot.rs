@@ -0,0 +1,51 @@
+use crate::prelude::*;
+
+#[test]
+fn smoke() {
+    #[derive(Debug, Builder)]
+    #[builder(finish_into_slot)]
+    #[allow(dead_code)]
+    struct Sut {
+        x: u32,
+        y: Option<u32>,
+    }
+
+    let mut slot = None;
+
+    let sut = Sut::builder().x(1).build_into_slot(&mut slot);
+    sut.x += 1;
+
+    assert_debug_eq(slot, expect!["Some(Sut { x: 2, y: None })"]);
+}
+
+#[test]
+fn finish_fn_args() {
+    #[derive(Debug, Builder)]
+    #[builder(finish_into_slot, finish_fn = assemble)]
+    #[allow(dead_code)]
+    struct Sut<T> {
+        #[builder(start_fn)]
+        prefix: &'static str,
+
+        #[builder(finish_fn)]
+        suffix: u32,
+
+        value: T,
+    }
+
+    // The previous value in the slot is replaced
+    let mut slot = Some(Sut {
+        prefix: "old",
+        suffix: 0,
+        value: false,
+    });
+
+    Sut::builder("a")
+        .value(true)
+        .assemble_into_slot(1, &mut slot);
+
+    assert_debug_eq(
+        slot,
+        expect![[r#"Some(Sut { prefix: "a", suffix: 1, value: true })"#]],
+    );
+}
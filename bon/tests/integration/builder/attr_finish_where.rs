@@ -0,0 +1,37 @@
+use crate::prelude::*;
+
+#[test]
+fn struct_smoke() {
+    #[derive(Debug, Builder)]
+    #[builder(finish_where(T: Clone))]
+    #[allow(dead_code)]
+    struct Sut<T> {
+        x: T,
+
+        #[builder(skip = x.clone())]
+        x_copy: T,
+    }
+
+    struct NotClone;
+
+    // The builder can be created and filled for the type that isn't `Clone`
+    let _ = Sut::builder().x(NotClone);
+
+    assert_debug_eq(
+        Sut::builder().x(42).build(),
+        expect!["Sut { x: 42, x_copy: 42 }"],
+    );
+}
+
+#[test]
+fn fn_smoke() {
+    // The function itself doesn't require `T: Default`, only the default
+    // value computed in the finishing function does.
+    #[builder(finish_where(T: Default))]
+    fn sut<T>(#[builder(default)] x: T) -> T {
+        x
+    }
+
+    assert_eq!(sut::<u32>().call(), 0);
+    assert_eq!(sut().x("x").call(), "x");
+}
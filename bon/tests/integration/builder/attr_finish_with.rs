@@ -0,0 +1,67 @@
+use crate::prelude::*;
+
+#[test]
+fn smoke() {
+    #[derive(Debug, Builder)]
+    #[builder(finish_with = Sut::new)]
+    #[allow(dead_code)]
+    struct Sut {
+        name: &'static str,
+        level: Option<u32>,
+
+        #[builder(skip = 1)]
+        scale: u32,
+    }
+
+    impl Sut {
+        fn new(name: &'static str, level: Option<u32>, scale: u32) -> Self {
+            Self {
+                name,
+                level: level.map(|level| level * scale * 10),
+                scale,
+            }
+        }
+    }
+
+    assert_debug_eq(
+        Sut::builder().name("bon").level(2).build(),
+        expect![[r#"Sut { name: "bon", level: Some(20), scale: 1 }"#]],
+    );
+}
+
+#[test]
+fn finish_by_ref() {
+    #[derive(Debug, Builder)]
+    #[builder(finish_with = Sut::from_table)]
+    #[allow(dead_code)]
+    struct Sut<T> {
+        tag: T,
+
+        #[builder(finish_by_ref)]
+        table: [u32; 256],
+
+        #[builder(default)]
+        checksum: u32,
+    }
+
+    impl<T> Sut<T> {
+        // Borrowing the large table avoids an extra copy of it in the finishing
+        // function. The value is copied only once into the struct itself.
+        fn from_table(tag: T, table: &[u32; 256], checksum: u32) -> Self {
+            Self {
+                tag,
+                table: *table,
+                checksum: checksum + table.iter().sum::<u32>(),
+            }
+        }
+    }
+
+    let sut = Sut::builder()
+        .tag("tag")
+        .table([2; 256])
+        .checksum(1)
+        .build();
+
+    assert_eq!(sut.tag, "tag");
+    assert_eq!(sut.checksum, 513);
+}
@@ -0,0 +1,119 @@
+use crate::prelude::*;
+
+// The generated tests are placed next to the structs, and `#[test]` functions
+// can't be nested in other functions, so the structs are declared at the
+// module level here.
+
+#[derive(Builder)]
+#[builder(generate_test)]
+#[allow(dead_code)]
+struct Simple {
+    x1: u32,
+    x2: Option<bool>,
+
+    #[builder(default = 1)]
+    x3: u32,
+}
+
+#[derive(Builder)]
+#[builder(generate_test, builder_type = CustomBuilder, finish_fn = finish)]
+#[allow(dead_code)]
+struct Complex<'a> {
+    #[builder(start_fn)]
+    x1: u8,
+
+    #[builder(finish_fn, into)]
+    x2: u32,
+
+    #[builder(into)]
+    x3: &'a str,
+
+    #[builder(checked(with = |value: &u32| if *value > 0 { Err(()) } else { Ok(()) }, error = ()))]
+    x4: u32,
+
+    #[builder(skip = 42)]
+    x5: u32,
+}
+
+#[derive(Builder)]
+#[builder(generate_test)]
+#[allow(dead_code)]
+struct OneOf {
+    #[builder(one_of(source), into)]
+    x1: Option<u32>,
+
+    #[builder(one_of(source))]
+    x2: Option<bool>,
+}
+
+type AppError = &'static str;
+
+#[derive(Builder)]
+#[builder(generate_test, invariant(with = Invariant::check, error = AppError))]
+#[allow(dead_code)]
+struct Invariant {
+    x1: u32,
+}
+
+impl Invariant {
+    fn check(&self) -> Result<(), AppError> {
+        if self.x1 > 100 {
+            return Err("x1 must not exceed 100");
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn generated_tests_run() {
+    simple_builder_builds_from_default_values();
+    custom_builder_builds_from_default_values();
+    one_of_builder_builds_from_default_values();
+    invariant_builder_builds_from_default_values().unwrap();
+}
+
+// The generated test is also run by the test harness, where it must pass, so
+// the invariant rejects the default values only when the flag is set in the
+// current thread
+#[cfg(feature = "std")]
+mod rejects_defaults {
+    use super::AppError;
+    use crate::prelude::*;
+    use std::cell::Cell;
+
+    type AppResult<T> = Result<T, AppError>;
+
+    std::thread_local! {
+        static REJECT: Cell<bool> = const { Cell::new(false) };
+    }
+
+    #[derive(Builder)]
+    #[builder(
+        generate_test,
+        build_result_wrapper = AppResult,
+        invariant = RejectsDefaults::check,
+    )]
+    #[allow(dead_code)]
+    struct RejectsDefaults {
+        x1: u32,
+    }
+
+    impl RejectsDefaults {
+        fn check(&self) -> Result<(), AppError> {
+            if self.x1 == 0 && REJECT.with(Cell::get) {
+                return Err("x1 must not be zero");
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn generated_test_fails_if_invariant_rejects_defaults() {
+        REJECT.with(|reject| reject.set(true));
+
+        assert_eq!(
+            rejects_defaults_builder_builds_from_default_values(),
+            Err("x1 must not be zero"),
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use crate::prelude::*;
+use core::sync::atomic::{AtomicU32, Ordering};
+
+#[test]
+fn struct_default_references_later_member() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(default = height * 2, init_after = height)]
+        width: u32,
+
+        height: u32,
+
+        #[builder(skip = width + height, init_after = width)]
+        perimeter_half: u32,
+    }
+
+    assert_debug_eq(
+        Sut::builder().height(3).build(),
+        expect!["Sut { width: 6, height: 3, perimeter_half: 9 }"],
+    );
+
+    assert_debug_eq(
+        Sut::builder().width(1).height(3).build(),
+        expect!["Sut { width: 1, height: 3, perimeter_half: 4 }"],
+    );
+}
+
+#[test]
+fn fn_default_references_later_member() {
+    #[builder]
+    fn sut(#[builder(default = y + 1, init_after = y)] x: u32, y: u32) -> (u32, u32) {
+        (x, y)
+    }
+
+    assert_eq!(sut().y(2).call(), (3, 2));
+    assert_eq!(sut().x(5).y(2).call(), (5, 2));
+}
+
+#[test]
+fn evaluation_order() {
+    static COUNTER: AtomicU32 = AtomicU32::new(0);
+
+    fn next() -> u32 {
+        COUNTER.fetch_add(1, Ordering::SeqCst) + 1
+    }
+
+    #[builder]
+    fn sut(
+        #[builder(default = next(), init_after = x4)] x1: u32,
+        #[builder(default = next())] x2: u32,
+        #[builder(default = next(), init_after = x4)] x3: u32,
+        #[builder(default = next())] x4: u32,
+        #[builder(default = next(), init_after = x1)] x5: u32,
+    ) -> [u32; 5] {
+        [x1, x2, x3, x4, x5]
+    }
+
+    // The members are evaluated in the order: x2, x4, x1, x5, x3
+    assert_eq!(sut().call(), [3, 1, 5, 2, 4]);
+}
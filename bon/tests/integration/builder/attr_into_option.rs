@@ -0,0 +1,44 @@
+use crate::prelude::*;
+
+#[test]
+fn struct_smoke() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(into_option)]
+        level: Option<u32>,
+
+        #[builder(into_option, default = 7)]
+        priority: u32,
+    }
+
+    assert_debug_eq(
+        Sut::builder().level(99).priority(1).build(),
+        expect!["Sut { level: Some(99), priority: 1 }"],
+    );
+
+    assert_debug_eq(
+        Sut::builder().level(Some(42)).priority(None).build(),
+        expect!["Sut { level: Some(42), priority: 7 }"],
+    );
+
+    assert_debug_eq(
+        Sut::builder()
+            .level(None::<u32>)
+            .maybe_priority(Some(3))
+            .build(),
+        expect!["Sut { level: None, priority: 3 }"],
+    );
+}
+
+#[test]
+fn fn_smoke() {
+    #[builder]
+    fn sut(#[builder(into_option)] level: Option<&str>) -> Option<&str> {
+        level
+    }
+
+    assert_eq!(sut().level("info").call(), Some("info"));
+    assert_eq!(sut().level(Some("warn")).call(), Some("warn"));
+    assert_eq!(sut().level(None).call(), None);
+}
@@ -0,0 +1,77 @@
+use crate::prelude::*;
+
+#[derive(Debug, PartialEq, Eq)]
+struct InvalidRange {
+    min: u32,
+    max: u32,
+}
+
+#[test]
+fn with_error_type() {
+    #[derive(Debug, Builder)]
+    #[builder(invariant(with = check_range, error = InvalidRange))]
+    struct Sut {
+        min: u32,
+        max: u32,
+    }
+
+    fn check_range(sut: &Sut) -> Result<(), InvalidRange> {
+        if sut.min > sut.max {
+            return Err(InvalidRange {
+                min: sut.min,
+                max: sut.max,
+            });
+        }
+        Ok(())
+    }
+
+    assert_debug_eq(
+        Sut::builder().min(1).max(2).build(),
+        expect!["Ok(Sut { min: 1, max: 2 })"],
+    );
+
+    assert_eq!(
+        Sut::builder().min(3).max(2).build().unwrap_err(),
+        InvalidRange { min: 3, max: 2 }
+    );
+}
+
+#[test]
+fn error_conversion_with_build_result_wrapper() {
+    #[derive(Debug, PartialEq, Eq)]
+    enum AppError {
+        Range(InvalidRange),
+    }
+
+    impl From<InvalidRange> for AppError {
+        fn from(err: InvalidRange) -> Self {
+            Self::Range(err)
+        }
+    }
+
+    type AppResult<T> = Result<T, AppError>;
+
+    #[derive(Debug, Builder)]
+    #[builder(
+        build_result_wrapper = AppResult,
+        invariant = |sut: &Sut| if sut.min > sut.max {
+            Err(InvalidRange { min: sut.min, max: sut.max })
+        } else {
+            Ok(())
+        },
+    )]
+    struct Sut {
+        min: u32,
+
+        #[builder(default = 10)]
+        max: u32,
+    }
+
+    let result: AppResult<Sut> = Sut::builder().min(1).build();
+    assert_debug_eq(result, expect!["Ok(Sut { min: 1, max: 10 })"]);
+
+    assert_eq!(
+        Sut::builder().min(11).build().unwrap_err(),
+        AppError::Range(InvalidRange { min: 11, max: 10 })
+    );
+}
@@ -0,0 +1,40 @@
+use crate::prelude::*;
+
+#[test]
+fn struct_smoke() {
+    #[derive(Builder)]
+    #[builder(json_schema)]
+    #[allow(dead_code)]
+    struct Sut<'a, T> {
+        /// Name of the "user"
+        name: &'a str,
+
+        level: Option<u32>,
+
+        #[builder(default)]
+        is_admin: bool,
+
+        tags: Vec<T>,
+
+        #[builder(skip)]
+        cache: u64,
+    }
+
+    expect![[r#"{"$schema":"https://json-schema.org/draft/2020-12/schema","title":"SutBuilder","type":"object","properties":{"name":{"type":"string","x-rust-type":"&'a str","description":"Name of the \"user\""},"level":{"type":"integer","x-rust-type":"u32"},"is_admin":{"type":"boolean","x-rust-type":"bool"},"tags":{"x-rust-type":"Vec<T>"}},"required":["name","tags"]}"#]]
+    .assert_eq(&SutBuilder::<'_, u32>::json_schema());
+}
+
+#[test]
+fn fn_smoke() {
+    #[builder(json_schema)]
+    fn sut(#[builder(start_fn)] id: u64, scale: Option<f64>) -> f64 {
+        #[allow(clippy::cast_precision_loss)]
+        let id = id as f64;
+        id * scale.unwrap_or(1.0)
+    }
+
+    expect![[r#"{"$schema":"https://json-schema.org/draft/2020-12/schema","title":"SutBuilder","type":"object","properties":{"id":{"type":"integer","x-rust-type":"u64"},"scale":{"type":"number","x-rust-type":"f64"}},"required":["id"]}"#]]
+    .assert_eq(&SutBuilder::json_schema());
+
+    assert!((sut(2).scale(1.5).call() - 3.0).abs() < f64::EPSILON);
+}
@@ -0,0 +1,67 @@
+use crate::prelude::*;
+
+#[test]
+fn struct_smoke() {
+    #[derive(Debug, Builder)]
+    #[builder(macro = sut)]
+    #[allow(dead_code)]
+    struct Sut<T> {
+        required: u32,
+        optional: Option<u32>,
+
+        #[builder(default = 7)]
+        default: u32,
+
+        #[builder(into)]
+        label: &'static str,
+
+        #[builder(name = renamed)]
+        generic: T,
+    }
+
+    assert_debug_eq(
+        sut! { required: 1, label: "one", renamed: true },
+        expect![[r#"
+            Sut {
+                required: 1,
+                optional: None,
+                default: 7,
+                label: "one",
+                generic: true,
+            }"#]],
+    );
+
+    // The order of the members doesn't matter, and a trailing comma is allowed
+    assert_debug_eq(
+        sut! {
+            renamed: 'x',
+            default: 2,
+            optional: 3,
+            label: "two",
+            required: 1 + 1,
+        },
+        expect![[r#"
+            Sut {
+                required: 2,
+                optional: Some(
+                    3,
+                ),
+                default: 2,
+                label: "two",
+                generic: 'x',
+            }"#]],
+    );
+}
+
+#[test]
+fn finish_fn_and_start_fn_renamed() {
+    #[derive(Debug, Builder)]
+    #[builder(macro = sut, start_fn = create, finish_fn = finish)]
+    #[allow(dead_code)]
+    struct Sut {
+        x1: u32,
+        x2: Option<u32>,
+    }
+
+    assert_debug_eq(sut! { x1: 1 }, expect!["Sut { x1: 1, x2: None }"]);
+}
@@ -0,0 +1,60 @@
+use crate::prelude::*;
+
+// The builders are generated in child modules, so the structs must be
+// declared at the module level for the glob import from the parent to work.
+
+#[derive(Debug, Clone, Copy)]
+#[allow(dead_code)]
+struct Level(u32);
+
+#[derive(Debug, Builder)]
+#[allow(dead_code)]
+#[builder(module = private_builder, setter_trait = PrivateBuilderSetters)]
+struct Private {
+    level: Level,
+    name: Option<&'static str>,
+}
+
+#[derive(Debug, Builder)]
+#[allow(dead_code)]
+#[builder(module = gated_builder, cfg(feature = "alloc"))]
+pub(crate) struct Gated {
+    x: u32,
+}
+
+#[test]
+fn private_struct() {
+    fn fill<B: private_builder::PrivateBuilderSetters>(builder: B) -> B::Level {
+        builder.level(Level(2))
+    }
+
+    let builder: private_builder::PrivateBuilder<_> = Private::builder();
+
+    assert_debug_eq(
+        fill(builder).name("bon").build(),
+        expect![[r#"Private { level: Level(2), name: Some("bon") }"#]],
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn cfg_enabled() {
+    let builder: gated_builder::GatedBuilder = Gated::builder();
+
+    assert_debug_eq(builder.x(1).build(), expect!["Gated { x: 1 }"]);
+}
+
+#[cfg(not(feature = "alloc"))]
+#[test]
+fn cfg_disabled() {
+    // Provides `Gated::builder()` when the inherent one isn't generated
+    trait BuilderFallback {
+        fn builder() -> &'static str {
+            "absent"
+        }
+    }
+
+    impl BuilderFallback for Gated {}
+
+    assert_eq!(Gated::builder(), "absent");
+}
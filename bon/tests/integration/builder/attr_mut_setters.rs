@@ -0,0 +1,56 @@
+use crate::prelude::*;
+
+#[test]
+fn struct_smoke() {
+    #[derive(Debug, Builder)]
+    #[builder(mut_setters, start_with_defaults)]
+    #[allow(dead_code)]
+    struct Sut {
+        required: u32,
+        optional: Option<u32>,
+
+        #[builder(default = 3_u32, into)]
+        with_default: u32,
+    }
+
+    // Required members use the consuming setters that change the state,
+    // while optional members can be overwritten in place
+    let mut builder = Sut::builder_with_defaults().required(1);
+    builder.set_optional(2);
+    builder.set_with_default(4_u8).set_maybe_optional(None);
+
+    assert_debug_eq(
+        builder.build(),
+        expect!["Sut { required: 1, optional: None, with_default: 4 }"],
+    );
+
+    let mut builder = Sut::builder().optional(1).with_default(2_u32);
+    builder.set_maybe_with_default(None::<u32>);
+
+    for value in 5..7 {
+        builder.set_optional(value);
+    }
+
+    assert_debug_eq(
+        builder.required(0).build(),
+        expect!["Sut { required: 0, optional: Some(6), with_default: 3 }"],
+    );
+}
+
+#[test]
+fn fn_smoke() {
+    #[builder(mut_setters, start_with_defaults)]
+    fn sut(
+        required: bool,
+        #[builder(checked(with = |value: &u32| if *value > 9 { Err(()) } else { Ok(()) }, error = ()))]
+        digit: Option<u32>,
+    ) -> (bool, Option<u32>) {
+        (required, digit)
+    }
+
+    let mut builder = sut_with_defaults();
+    assert!(builder.set_digit(10).is_err());
+    builder.set_digit(5).unwrap();
+
+    assert_eq!(builder.required(true).call(), (true, Some(5)));
+}
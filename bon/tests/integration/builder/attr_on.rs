@@ -70,3 +70,11 @@ fn match_generic() {
 
     sut().arg1(true).arg2(()).arg3(IntoGeneric("foo")).call();
 }
+
+#[test]
+fn overlapping_rules() {
+    #[builder(on(bool, into), on(_, into))]
+    fn sut(_arg1: bool, _arg2: Option<()>) {}
+
+    sut().arg1(IntoBool(true)).arg2(IntoUnit).call();
+}
@@ -0,0 +1,110 @@
+use crate::prelude::*;
+
+#[test]
+fn exactly_one_member_of_group() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(one_of(source))]
+        path: Option<&'static str>,
+
+        #[builder(one_of(source), into)]
+        url: Option<&'static str>,
+
+        retries: Option<u32>,
+    }
+
+    assert_debug_eq(
+        Sut::builder().path("/etc/app").build(),
+        expect![[r#"Sut { path: Some("/etc/app"), url: None, retries: None }"#]],
+    );
+
+    assert_debug_eq(
+        Sut::builder().retries(3).url("https://example.com").build(),
+        expect![[r#"
+            Sut {
+                path: None,
+                url: Some(
+                    "https://example.com",
+                ),
+                retries: Some(
+                    3,
+                ),
+            }"#]],
+    );
+}
+
+#[test]
+fn multiple_groups() {
+    #[derive(Debug, Builder)]
+    #[builder(start_with_defaults)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(one_of(source))]
+        path: Option<u32>,
+
+        #[builder(one_of(auth))]
+        token: Option<u32>,
+
+        #[builder(one_of(source))]
+        url: Option<u32>,
+
+        #[builder(one_of(auth))]
+        password: Option<u32>,
+
+        retries: Option<u32>,
+    }
+
+    assert_debug_eq(
+        Sut::builder().url(1).token(2).build(),
+        expect![[r#"
+            Sut {
+                path: None,
+                token: Some(
+                    2,
+                ),
+                url: Some(
+                    1,
+                ),
+                password: None,
+                retries: None,
+            }"#]],
+    );
+
+    // The `one_of` members stay unset in the builder with defaults
+    assert_debug_eq(
+        Sut::builder_with_defaults().password(3).path(4).build(),
+        expect![[r#"
+            Sut {
+                path: Some(
+                    4,
+                ),
+                token: None,
+                url: None,
+                password: Some(
+                    3,
+                ),
+                retries: None,
+            }"#]],
+    );
+}
+
+#[test]
+fn checked() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(one_of(limit), checked(with = |x: &u32| if *x > 0 { Ok(()) } else { Err(()) }, error = ()))]
+        max_items: Option<u32>,
+
+        #[builder(one_of(limit))]
+        unlimited: Option<()>,
+    }
+
+    assert_eq!(Sut::builder().max_items(0).err(), Some(()));
+
+    assert_debug_eq(
+        Sut::builder().max_items(1).unwrap().build(),
+        expect!["Sut { max_items: Some(1), unlimited: None }"],
+    );
+}
@@ -0,0 +1,38 @@
+use crate::prelude::*;
+
+#[test]
+fn struct_smoke() {
+    #[derive(Debug, Builder)]
+    #[builder(view = SutView)]
+    #[allow(dead_code)]
+    struct Sut<T> {
+        #[builder(required_message = "you must provide a level")]
+        level: u32,
+
+        #[builder(required_message = "the value is required", into)]
+        value: T,
+    }
+
+    #[allow(dead_code)]
+    struct SutView<'a, T> {
+        level: &'a u32,
+        value: &'a T,
+    }
+
+    let builder = Sut::builder().level(1).value(true);
+
+    let view = builder.build_view();
+    assert_eq!((*view.level, *view.value), (1, true));
+
+    assert_debug_eq(builder.build(), expect!["Sut { level: 1, value: true }"]);
+}
+
+#[test]
+fn fn_smoke() {
+    #[builder]
+    fn sut(#[builder(required_message = "`x` is required")] x: u32, y: Option<u32>) -> u32 {
+        x + y.unwrap_or_default()
+    }
+
+    assert_eq!(sut().x(1).y(2).call(), 3);
+}
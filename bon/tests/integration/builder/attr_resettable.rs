@@ -0,0 +1,54 @@
+use crate::prelude::*;
+
+#[test]
+fn struct_set_reset_set() {
+    #[derive(Debug, Builder)]
+    #[builder(resettable)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(start_fn)]
+        id: u32,
+
+        x: u32,
+        y: &'static str,
+        z: Option<u32>,
+    }
+
+    let builder = Sut::builder(1).x(1).y("first").z(3);
+
+    // The other members stay set
+    let builder = builder.reset_y().y("second");
+
+    // Resetting the member that isn't set is a no-op
+    let builder = builder.reset_x().reset_x().x(2);
+
+    assert_debug_eq(
+        builder.build(),
+        expect![[r#"Sut { id: 1, x: 2, y: "second", z: Some(3) }"#]],
+    );
+}
+
+#[test]
+fn fn_set_reset_set() {
+    #[builder(resettable)]
+    fn sut(x: u32, y: Option<u32>) -> (u32, Option<u32>) {
+        (x, y)
+    }
+
+    assert_eq!(sut().x(1).y(2).reset_x().x(3).call(), (3, Some(2)));
+}
+
+#[test]
+fn method_set_reset_set() {
+    struct Sut(u32);
+
+    #[bon]
+    impl Sut {
+        #[builder(resettable)]
+        fn add(&self, x: u32) -> u32 {
+            self.0 + x
+        }
+    }
+
+    assert_eq!(Sut(1).add().x(1).reset_x().x(2).call(), 3);
+}
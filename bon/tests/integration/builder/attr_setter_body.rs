@@ -0,0 +1,65 @@
+use crate::prelude::*;
+
+#[test]
+fn struct_clamps_input() {
+    fn round_to_even(value: u32) -> u32 {
+        value & !1
+    }
+
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(setter_body = |v| v.clamp(0, 100))]
+        percent: i32,
+
+        #[builder(setter_body = |v| v.min(10), into)]
+        retries: Option<u32>,
+
+        #[builder(setter_body = round_to_even, default = 7)]
+        size: u32,
+    }
+
+    assert_debug_eq(
+        Sut::builder().percent(150).retries(42_u8).build(),
+        expect!["Sut { percent: 100, retries: Some(10), size: 7 }"],
+    );
+
+    assert_debug_eq(
+        Sut::builder()
+            .percent(-5)
+            .maybe_retries(Some(3_u32))
+            .size(5)
+            .build(),
+        expect!["Sut { percent: 0, retries: Some(3), size: 4 }"],
+    );
+}
+
+#[test]
+fn fn_clamps_input() {
+    #[builder]
+    fn sut(#[builder(setter_body = |v| v.clamp(1, 5))] level: u8) -> u8 {
+        level
+    }
+
+    assert_eq!(sut().level(0).call(), 1);
+    assert_eq!(sut().level(9).call(), 5);
+}
+
+#[test]
+fn checked_after_transformation() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(
+            setter_body = i32::abs,
+            checked(with = |v: &i32| if *v > 10 { Err(()) } else { Ok(()) }, error = ()),
+        )]
+        x: i32,
+    }
+
+    assert_debug_eq(
+        Sut::builder().x(-3).unwrap().build(),
+        expect!["Sut { x: 3 }"],
+    );
+    assert!(Sut::builder().x(-11).is_err());
+}
@@ -0,0 +1,150 @@
+use crate::prelude::*;
+
+#[cfg(feature = "alloc")]
+#[test]
+fn struct_smoke() {
+    #[derive(Debug, Builder)]
+    #[builder(setter_trait = SutBuilderSetters)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(into)]
+        name: String,
+        level: Option<u32>,
+    }
+
+    // Code that is generic over the setters trait, so it can be tested with
+    // a mock builder instead of the real one.
+    fn configure<B: SutBuilderSetters>(builder: B) -> <B::Name as SutBuilderSetters>::Level {
+        builder.name("config").level(3)
+    }
+
+    #[derive(Debug, Default, PartialEq, Eq)]
+    struct MockBuilder {
+        calls: Vec<String>,
+    }
+
+    impl SutBuilderSetters for MockBuilder {
+        type Name = Self;
+        type Level = Self;
+
+        fn name(mut self, value: impl Into<String>) -> Self {
+            self.calls.push(format!("name({})", value.into()));
+            self
+        }
+
+        fn maybe_level(mut self, value: Option<u32>) -> Self {
+            self.calls.push(format!("maybe_level({value:?})"));
+            self
+        }
+
+        fn level(self, value: u32) -> Self {
+            self.maybe_level(Some(value))
+        }
+    }
+
+    assert_debug_eq(
+        configure(Sut::builder()).build(),
+        expect![[r#"Sut { name: "config", level: Some(3) }"#]],
+    );
+
+    assert_debug_eq(
+        configure(MockBuilder::default()).calls,
+        expect![[r#"["name(config)", "maybe_level(Some(3))"]"#]],
+    );
+}
+
+#[test]
+fn overwrite_via_trait() {
+    #[derive(Debug, Builder)]
+    #[builder(setter_trait = SutBuilderSetters)]
+    #[allow(dead_code)]
+    struct Sut {
+        x: u32,
+    }
+
+    let sut = SutBuilderSetters::x(Sut::builder().x(1), 2).build();
+
+    assert_debug_eq(sut, expect!["Sut { x: 2 }"]);
+}
+
+#[test]
+fn fn_generic() {
+    #[builder(setter_trait = SutBuilderSetters)]
+    fn sut<T: Clone>(x: T, y: Option<T>) -> (T, Option<T>) {
+        (x, y)
+    }
+
+    fn set_x<B: SutBuilderSetters<T>, T: Clone>(builder: B, value: T) -> B::X {
+        builder.x(value)
+    }
+
+    assert_eq!(set_x(sut(), 1).y(2).call(), (1, Some(2)));
+}
+
+#[test]
+fn members_reordering_keeps_the_type() {
+    mod original {
+        use crate::prelude::*;
+
+        #[derive(Builder)]
+        #[builder(setter_trait = SutBuilderSetters)]
+        #[allow(dead_code)]
+        pub(super) struct Sut {
+            pub(super) x: u32,
+            pub(super) y: Option<bool>,
+        }
+    }
+
+    mod reordered {
+        use crate::prelude::*;
+
+        #[derive(Builder)]
+        #[builder(setter_trait = SutBuilderSetters)]
+        #[allow(dead_code)]
+        pub(super) struct Sut {
+            pub(super) y: Option<bool>,
+            pub(super) x: u32,
+        }
+    }
+
+    // Strips the module path, which is the only expected difference
+    fn state_type_name<T>(_: &T) -> &'static str {
+        core::any::type_name::<T>()
+            .split_once("SutBuilder")
+            .unwrap()
+            .1
+    }
+
+    fn assert_same_state<B1, B2>(original: &B1, reordered: &B2) {
+        assert_eq!(state_type_name(original), state_type_name(reordered));
+    }
+
+    // The associated types of the setter trait are keyed by the member names
+    fn set_x_original<B: original::SutBuilderSetters>(builder: B) -> B::X {
+        builder.x(1)
+    }
+
+    fn set_x_reordered<B: reordered::SutBuilderSetters>(builder: B) -> B::X {
+        builder.x(1)
+    }
+
+    assert_same_state(&original::Sut::builder(), &reordered::Sut::builder());
+    assert_same_state(
+        &original::Sut::builder().x(1),
+        &reordered::Sut::builder().x(1),
+    );
+    assert_same_state(
+        &original::Sut::builder().y(true),
+        &reordered::Sut::builder().y(true),
+    );
+
+    assert_same_state(
+        &set_x_original(original::Sut::builder()).y(true),
+        &set_x_reordered(reordered::Sut::builder()).y(true),
+    );
+
+    let original = set_x_original(original::Sut::builder()).build();
+    let reordered = set_x_reordered(reordered::Sut::builder()).build();
+
+    assert_eq!((original.x, original.y), (reordered.x, reordered.y));
+}
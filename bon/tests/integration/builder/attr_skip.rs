@@ -127,3 +127,37 @@ fn interaction_with_positional_members() {
             }"#]],
     );
 }
+
+#[test]
+fn members_are_initialized_in_declaration_order() {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    fn next() -> usize {
+        COUNTER.fetch_add(1, Ordering::SeqCst)
+    }
+
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(skip = next())]
+        x1: usize,
+
+        #[builder(default = next())]
+        x2: usize,
+
+        x3: usize,
+
+        #[builder(skip = next())]
+        x4: usize,
+
+        #[builder(default = next())]
+        x5: usize,
+    }
+
+    assert_debug_eq(
+        Sut::builder().x3(99).build(),
+        expect!["Sut { x1: 0, x2: 1, x3: 99, x4: 2, x5: 3 }"],
+    );
+}
@@ -0,0 +1,33 @@
+use crate::prelude::*;
+
+#[test]
+fn on_other_type() {
+    struct App;
+
+    #[derive(Debug, Builder)]
+    #[builder(start_fn(name = config, on = App))]
+    #[allow(dead_code)]
+    struct Config {
+        level: u32,
+    }
+
+    #[derive(Debug, Builder)]
+    #[builder(start_fn(name = pair, on = App))]
+    #[allow(dead_code)]
+    struct Pair<'a, T: Clone> {
+        #[builder(start_fn)]
+        left: &'a T,
+        right: Option<T>,
+    }
+
+    let config: ConfigBuilder = App::config();
+
+    assert_debug_eq(config.level(1).build(), expect!["Config { level: 1 }"]);
+
+    let pair: PairBuilder<'_, u32> = App::pair(&1);
+
+    assert_debug_eq(
+        pair.right(2).build(),
+        expect!["Pair { left: 1, right: Some(2) }"],
+    );
+}
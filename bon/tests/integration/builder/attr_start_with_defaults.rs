@@ -0,0 +1,78 @@
+use crate::prelude::*;
+
+#[test]
+fn struct_smoke() {
+    #[derive(Debug, Builder)]
+    #[builder(start_with_defaults)]
+    #[allow(dead_code)]
+    struct Sut<T> {
+        #[builder(start_fn)]
+        id: u32,
+
+        value: T,
+        level: Option<u32>,
+
+        #[builder(default = 99)]
+        limit: u32,
+
+        #[builder(default)]
+        enabled: bool,
+    }
+
+    // Only the required members are left to be set
+    let actual = Sut::builder_with_defaults(1).value("a").build();
+
+    assert_debug_eq(
+        actual,
+        expect![[r#"
+            Sut {
+                id: 1,
+                value: "a",
+                level: None,
+                limit: 99,
+                enabled: false,
+            }"#]],
+    );
+
+    // The regular start function is still available
+    let actual = Sut::builder(2).value(true).limit(1).build();
+
+    assert_debug_eq(
+        actual,
+        expect![[r#"
+            Sut {
+                id: 2,
+                value: true,
+                level: None,
+                limit: 1,
+                enabled: false,
+            }"#]],
+    );
+}
+
+#[test]
+fn fn_smoke() {
+    #[builder(start_with_defaults)]
+    fn sut(x: u32, y: Option<u32>, #[builder(default = 3)] z: u32) -> u32 {
+        x + y.unwrap_or(10) + z
+    }
+
+    assert_eq!(sut_with_defaults().x(1).call(), 14);
+    assert_eq!(sut().x(1).y(2).z(3).call(), 6);
+}
+
+#[test]
+fn method_smoke() {
+    struct Sut;
+
+    #[bon]
+    impl Sut {
+        #[builder(start_with_defaults)]
+        fn method(&self, x: u32, y: Option<u32>) -> u32 {
+            let _ = self;
+            x + y.unwrap_or(5)
+        }
+    }
+
+    assert_eq!(Sut.method_with_defaults().x(1).call(), 6);
+}
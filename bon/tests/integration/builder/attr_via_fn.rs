@@ -0,0 +1,50 @@
+use crate::prelude::*;
+
+#[cfg(feature = "alloc")]
+#[test]
+fn struct_smoke() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(via_fn)]
+        level: u32,
+
+        #[builder(via_fn, into)]
+        name: Option<String>,
+    }
+
+    let mut calls = 0;
+
+    let builder = Sut::builder().level_via(|| {
+        calls += 1;
+        2 + 3
+    });
+
+    // The closure is invoked eagerly in the setter
+    assert_eq!(calls, 1);
+
+    assert_debug_eq(
+        builder.name_via(|| "bon".to_owned()).build(),
+        expect![[r#"Sut { level: 5, name: Some("bon") }"#]],
+    );
+
+    // The regular setters are still available
+    assert_debug_eq(
+        Sut::builder().level(1).name("littlepip").build(),
+        expect![[r#"Sut { level: 1, name: Some("littlepip") }"#]],
+    );
+}
+
+#[test]
+fn fn_checked() {
+    #[builder]
+    fn sut(
+        #[builder(via_fn, checked(with = |x: &u32| if *x > 0 { Ok(()) } else { Err(()) }, error = ()))]
+        x: u32,
+    ) -> u32 {
+        x
+    }
+
+    assert_eq!(sut().x_via(|| 2).unwrap().call(), 2);
+    assert!(sut().x_via(|| 0).is_err());
+}
@@ -0,0 +1,89 @@
+use crate::prelude::*;
+
+#[test]
+fn smoke() {
+    #[derive(Debug, Builder)]
+    #[builder(view = SutView)]
+    #[allow(dead_code)]
+    struct Sut {
+        name: &'static str,
+        level: Option<u32>,
+
+        #[builder(default = 3)]
+        scale: u32,
+
+        #[builder(skip)]
+        cache: u32,
+    }
+
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct SutView<'a> {
+        name: &'a &'static str,
+        level: Option<&'a u32>,
+        scale: Option<&'a u32>,
+    }
+
+    let builder = Sut::builder().name("bon").level(2);
+
+    assert_debug_eq(
+        builder.build_view(),
+        expect![[r#"SutView { name: "bon", level: Some(2), scale: None }"#]],
+    );
+
+    // The view doesn't consume the builder
+    assert_debug_eq(
+        builder.build(),
+        expect![[r#"Sut { name: "bon", level: Some(2), scale: 3, cache: 0 }"#]],
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn generic() {
+    #[derive(Debug, Builder)]
+    #[builder(view = SutView, finish_fn = finish)]
+    #[allow(dead_code)]
+    struct Sut<'a, T> {
+        #[builder(start_fn)]
+        id: u32,
+
+        items: Vec<T>,
+        label: Option<&'a str>,
+    }
+
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct SutView<'view, 'a, T> {
+        id: &'view u32,
+        items: &'view Vec<T>,
+        label: Option<&'view &'a str>,
+    }
+
+    let builder = Sut::builder(1).items(vec![true, false]);
+
+    // The name of the view method is derived from the finishing function's name
+    let view = builder.finish_view();
+    let total = view.items.len();
+
+    assert_debug_eq(
+        view,
+        expect![[r#"SutView { id: 1, items: [true, false], label: None }"#]],
+    );
+    assert_eq!(total, 2);
+
+    assert_debug_eq(
+        builder.label("zero-copy").finish(),
+        expect![[r#"
+            Sut {
+                id: 1,
+                items: [
+                    true,
+                    false,
+                ],
+                label: Some(
+                    "zero-copy",
+                ),
+            }"#]],
+    );
+}
@@ -0,0 +1,73 @@
+use crate::prelude::*;
+
+#[test]
+fn smoke() {
+    #[bon::builder_all]
+    #[allow(dead_code)]
+    mod dto {
+        use super::Builder;
+
+        #[derive(Debug)]
+        pub(crate) struct User {
+            pub(crate) name: &'static str,
+            pub(crate) level: Option<u32>,
+        }
+
+        #[derive(Debug)]
+        #[builder(start_fn = init)]
+        pub(crate) struct Renamed {
+            pub(crate) x: u32,
+        }
+
+        // The derive is already here, so it must not be generated twice
+        #[derive(Debug, Builder)]
+        pub(crate) struct AlreadyDerived {
+            pub(crate) y: u32,
+        }
+
+        #[derive(Debug, bon::Builder)]
+        pub(crate) struct AlreadyDerivedWithPath {
+            pub(crate) w: u32,
+        }
+
+        #[builder_skip]
+        pub(crate) struct Skipped {
+            pub(crate) z: u32,
+        }
+
+        pub(crate) struct Tuple(pub(crate) u32);
+
+        pub(crate) struct Unit;
+
+        impl Skipped {
+            // This would conflict with the generated `builder` method
+            pub(crate) fn builder() -> u32 {
+                42
+            }
+        }
+    }
+
+    assert_debug_eq(
+        dto::User::builder().name("Bon").level(1).build(),
+        expect![[r#"User { name: "Bon", level: Some(1) }"#]],
+    );
+
+    assert_debug_eq(
+        dto::Renamed::init().x(1).build(),
+        expect!["Renamed { x: 1 }"],
+    );
+
+    assert_debug_eq(
+        dto::AlreadyDerived::builder().y(2).build(),
+        expect!["AlreadyDerived { y: 2 }"],
+    );
+
+    assert_debug_eq(
+        dto::AlreadyDerivedWithPath::builder().w(3).build(),
+        expect!["AlreadyDerivedWithPath { w: 3 }"],
+    );
+
+    assert_eq!(dto::Skipped::builder(), 42);
+
+    let _ = (dto::Tuple(1), dto::Unit);
+}
@@ -24,6 +24,22 @@ fn smoke_fn() {
     );
 }
 
+#[test]
+fn test_only_debug() {
+    #[derive(Builder)]
+    #[builder(test_only_debug)]
+    #[allow(dead_code)]
+    struct Sut {
+        arg1: bool,
+        arg2: Option<u32>,
+    }
+
+    assert_debug_eq(
+        Sut::builder().arg1(true),
+        expect!["SutBuilder { arg1: true }"],
+    );
+}
+
 #[test]
 fn smoke_struct() {
     #[derive(Builder)]
@@ -234,3 +250,73 @@ fn positional_members_impl_block() {
             }"#]],
     );
 }
+
+#[test]
+fn partial_eq() {
+    #[derive(Builder)]
+    #[builder(derive(PartialEq))]
+    #[allow(dead_code)]
+    struct Sut<'a> {
+        #[builder(start_fn)]
+        start_fn_arg: u32,
+
+        arg1: bool,
+        arg2: Option<&'a str>,
+        arg3: Option<f64>,
+    }
+
+    assert!(Sut::builder(1) == Sut::builder(1));
+    assert!(Sut::builder(1) != Sut::builder(2));
+    assert!(Sut::builder(1).arg1(true) == Sut::builder(1).arg1(true));
+    assert!(Sut::builder(1).arg1(true) != Sut::builder(1).arg1(false));
+    assert!(Sut::builder(1).arg3(0.5) != Sut::builder(1).arg3(f64::NAN));
+
+    // Both setters of the optional member store the same `Option` value
+    assert!(Sut::builder(1).arg2("a") == Sut::builder(1).maybe_arg2(Some("a")));
+    assert!(Sut::builder(1).arg2("a") != Sut::builder(1).maybe_arg2(None));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn eq_and_hash() {
+    use std::collections::HashMap;
+
+    #[derive(Builder, PartialEq, Eq, Hash)]
+    #[builder(derive(Clone, PartialEq, Eq, Hash))]
+    struct Sut {
+        arg1: u32,
+        arg2: Option<&'static str>,
+
+        // Make sure the generic param of the hasher doesn't clash with
+        // the generic param of this member
+        #[builder(default)]
+        h: (),
+    }
+
+    #[bon]
+    impl Sut {
+        #[builder(derive(PartialEq, Eq, Hash))]
+        fn method(&self, arg: u32) -> u32 {
+            self.arg1 + arg
+        }
+    }
+
+    let mut cache = HashMap::new();
+
+    for (arg1, name) in [(1, "a"), (2, "b"), (1, "c")] {
+        let builder = Sut::builder().arg1(arg1);
+        cache.entry(builder.clone()).or_insert(name);
+    }
+
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache[&Sut::builder().arg1(1)], "a");
+    assert_eq!(cache[&Sut::builder().arg1(2)], "b");
+
+    let sut = Sut::builder().arg1(1).arg2("a").build();
+    let mut cache = HashMap::new();
+
+    cache.insert(sut.method().arg(2), 3);
+
+    assert_eq!(cache.get(&sut.method().arg(2)), Some(&3));
+    assert_eq!(cache.get(&sut.method().arg(3)), None);
+}
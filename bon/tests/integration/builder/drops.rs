@@ -0,0 +1,73 @@
+use crate::prelude::*;
+use crate::tracked::{drop_counts, Tracked, NO_DROPS};
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+fn struct_with_drop_impl() {
+    static DROPS: [AtomicUsize; 7] = [NO_DROPS; 7];
+    static STRUCT_DROPS: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Builder)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(start_fn)]
+        start: Tracked,
+
+        #[builder(finish_fn)]
+        finish: Tracked,
+
+        required: Tracked,
+        optional: Option<Tracked>,
+
+        #[builder(default = Tracked::new(4, &DROPS))]
+        default: Tracked,
+
+        #[builder(skip = Tracked::new(5, &DROPS))]
+        skipped: Tracked,
+
+        unset: Option<Tracked>,
+    }
+
+    impl Drop for Sut {
+        fn drop(&mut self) {
+            STRUCT_DROPS.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let sut = Sut::builder(Tracked::new(0, &DROPS))
+        .required(Tracked::new(2, &DROPS))
+        .optional(Tracked::new(3, &DROPS))
+        .build(Tracked::new(1, &DROPS));
+
+    // Nothing is dropped while building
+    assert_eq!(drop_counts(&DROPS), [0; 7]);
+
+    drop(sut);
+
+    // Every field is dropped exactly once together with the struct
+    assert_eq!(drop_counts(&DROPS), [1, 1, 1, 1, 1, 1, 0]);
+    assert_eq!(STRUCT_DROPS.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn unfinished_builder() {
+    static DROPS: [AtomicUsize; 2] = [NO_DROPS; 2];
+
+    #[derive(Builder)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(start_fn)]
+        start: Tracked,
+
+        required: Tracked,
+    }
+
+    let builder = Sut::builder(Tracked::new(0, &DROPS)).required(Tracked::new(1, &DROPS));
+
+    assert_eq!(drop_counts(&DROPS), [0, 0]);
+
+    // The builder that is dropped without finishing drops its members once
+    drop(builder);
+
+    assert_eq!(drop_counts(&DROPS), [1, 1]);
+}
@@ -0,0 +1,65 @@
+use crate::prelude::*;
+use bon::Finish;
+
+// The builders are in different states, so they are of different types
+fn finish_pair<A: Finish, B: Finish>(first: A, second: B) -> (A::Output, B::Output) {
+    (first.finish(), second.finish())
+}
+
+#[test]
+fn struct_builder() {
+    #[derive(Debug, Builder)]
+    #[builder(finish_fn = finish_building)]
+    #[allow(dead_code)]
+    struct Sut<T> {
+        x: T,
+        y: Option<u32>,
+    }
+
+    assert_debug_eq(
+        finish_pair(Sut::builder().x(true), Sut::builder().x(false).y(2)),
+        expect!["(Sut { x: true, y: None }, Sut { x: false, y: Some(2) })"],
+    );
+}
+
+#[test]
+fn fn_builder() {
+    #[builder]
+    fn sut(x: u32, y: Option<u32>) -> u32 {
+        x + y.unwrap_or_default()
+    }
+
+    assert_eq!(finish_pair(sut().x(1), sut().x(2).y(3)), (1, 5));
+}
+
+#[test]
+fn method_builder() {
+    struct Sut(u32);
+
+    #[bon]
+    impl Sut {
+        #[builder]
+        fn add(&self, x: u32) -> u32 {
+            self.0 + x
+        }
+    }
+
+    let sut = Sut(1);
+
+    assert_eq!(finish_pair(sut.add().x(1), sut.add().x(2)), (2, 3));
+}
+
+#[test]
+fn unit_output() {
+    #[builder]
+    fn sut(x: &mut u32) {
+        *x += 1;
+    }
+
+    let mut x = 1;
+
+    // `()` is the output of the finishing function
+    let () = Finish::finish(sut().x(&mut x));
+
+    assert_eq!(x, 2);
+}
@@ -90,6 +90,46 @@ fn assoc_types_work_in_return_type() {
     sut::<()>().call();
 }
 
+#[cfg(feature = "alloc")]
+#[test]
+fn assoc_types_of_generic_bounds_in_struct_fields() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Sut<I: Iterator>
+    where
+        I::Item: Default,
+    {
+        source: I,
+        items: Vec<I::Item>,
+        first: Option<<I as Iterator>::Item>,
+
+        #[builder(default)]
+        count: <I as Iterator>::Item,
+    }
+
+    let actual = Sut::builder()
+        .source(1..3)
+        .items(vec![1, 2])
+        .first(1)
+        .build();
+
+    assert_debug_eq(
+        actual,
+        expect![[r#"
+            Sut {
+                source: 1..3,
+                items: [
+                    1,
+                    2,
+                ],
+                first: Some(
+                    1,
+                ),
+                count: 0,
+            }"#]],
+    );
+}
+
 // This is based on the issue https://github.com/elastio/bon/issues/16
 #[test]
 fn self_only_generic_param() {
@@ -190,6 +230,39 @@ fn const_generics() {
     assert_debug_eq(actual, expect![[r#"Sut { a: "a", b: 42, c: [0, 0, 0] }"#]]);
 }
 
+// Bounds with arbitrary const expressions such as `[(); N * 2]:` require the
+// unstable `generic_const_exprs` feature, so they are tested in the nightly UI
+// tests. The predicates with const params in array lengths are preserved the
+// same way, so we test them on stable as well.
+#[test]
+fn const_generics_in_where_clause() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Sut<const N: usize>
+    where
+        [(); N]:,
+        [u8; N]: Default,
+    {
+        buf: [u8; N],
+
+        #[builder(default)]
+        extra: [u8; N],
+    }
+
+    #[builder]
+    fn buf_len<const N: usize>(buf: [u8; N]) -> usize
+    where
+        [(); N]:,
+    {
+        buf.len()
+    }
+
+    let actual = Sut::builder().buf([1; 2]).build();
+
+    assert_debug_eq(actual, expect!["Sut { buf: [1, 1], extra: [0, 0] }"]);
+    assert_eq!(buf_len().buf([0; 4]).call(), 4);
+}
+
 #[test]
 fn default_generic_const_params() {
     #[derive(bon::Builder)]
@@ -199,6 +272,106 @@ fn default_generic_const_params() {
     let _: Sut = builder.build();
 }
 
+#[test]
+fn default_generic_params_in_builder_type() {
+    #[derive(Debug, Builder)]
+    #[builder(setter_trait = SutBuilderSetters)]
+    #[allow(dead_code)]
+    struct Sut<T = &'static str, const N: usize = 4> {
+        value: T,
+        buf: [u8; N],
+    }
+
+    // The setter trait carries the same defaults as the builder type
+    fn fill<B: SutBuilderSetters>(builder: B) -> <B::Value as SutBuilderSetters>::Buf {
+        builder.value("bon").buf([1; 4])
+    }
+
+    let builder: SutBuilder = Sut::builder();
+    let sut: Sut = fill(builder).build();
+
+    assert_debug_eq(sut, expect![[r#"Sut { value: "bon", buf: [1, 1, 1, 1] }"#]]);
+}
+
+#[test]
+fn turbofish_in_finish_fn() {
+    #[derive(Debug, Builder)]
+    #[builder(finish_turbofish)]
+    #[allow(dead_code)]
+    struct Sut<'a, T: ?Sized, U, const N: usize> {
+        a: Option<&'a T>,
+        b: Option<U>,
+        c: [u8; N],
+    }
+
+    // Nothing hints the types of `T` and `U` to the compiler, because
+    // the optional members are never set
+    let actual = Sut::builder().c([0; 2]).build::<str, bool>();
+
+    assert_debug_eq(actual, expect!["Sut { a: None, b: None, c: [0, 0] }"]);
+
+    // The turbofish is optional if the types can be inferred
+    let actual = Sut::builder().a("a").b(true).c([]).build();
+
+    assert_debug_eq(
+        actual,
+        expect![[r#"Sut { a: Some("a"), b: Some(true), c: [] }"#]],
+    );
+
+    // Only some of the parameters may be specified explicitly
+    let actual = Sut::builder().b(42_u8).c([1]).build::<[u8], _>();
+
+    assert_debug_eq(actual, expect!["Sut { a: None, b: Some(42), c: [1] }"]);
+}
+
+// The finishing function doesn't have any generic parameters of its own by
+// default, so it can be passed as a function item without the turbofish
+#[test]
+fn finish_fn_as_fn_item() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Sut<T, U> {
+        a: T,
+        b: Option<U>,
+    }
+
+    fn finish<B, T>(builder: B, finish: impl FnOnce(B) -> T) -> T {
+        finish(builder)
+    }
+
+    let actual = finish(Sut::builder().a(1).b("b"), SutBuilder::build);
+
+    assert_debug_eq(actual, expect![[r#"Sut { a: 1, b: Some("b") }"#]]);
+}
+
+// `Self` references are replaced with the struct type, which must reference
+// every kind of generic param without their bounds and defaults.
+#[test]
+fn mixed_generic_params_with_self_refs() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code, explicit_outlives_requirements)]
+    struct Sut<'a, 'b: 'a, T: Copy + 'a = u32, const N: usize = 2>
+    where
+        Self: Sized,
+    {
+        a: &'a [T; N],
+        b: &'b str,
+        c: Option<::core::marker::PhantomData<Self>>,
+    }
+
+    let builder: SutBuilder<'_, '_> = Sut::builder();
+    let actual: Sut<'_, '_> = builder.a(&[1, 2]).b("b").build();
+
+    assert_debug_eq(actual, expect![[r#"Sut { a: [1, 2], b: "b", c: None }"#]]);
+
+    let actual = Sut::builder().a(&[true; 3]).b("b").build();
+
+    assert_debug_eq(
+        actual,
+        expect![[r#"Sut { a: [true, true, true], b: "b", c: None }"#]],
+    );
+}
+
 #[test]
 fn lifetimes_with_bounds() {
     #[builder]
@@ -209,3 +382,149 @@ fn lifetimes_with_bounds() {
 
     sut().arg(&42).arg2(&42).call();
 }
+
+#[cfg(feature = "alloc")]
+#[test]
+fn closure_with_bound_lifetime() {
+    #[derive(Builder)]
+    struct Handler<'a> {
+        f: Box<dyn FnMut(&'a str) + 'a>,
+    }
+
+    #[builder]
+    fn handle<'a>(mut f: Box<dyn FnMut(&'a str) + 'a>, input: &'a str) {
+        f(input);
+    }
+
+    let mut calls = Vec::new();
+
+    {
+        let mut handler = Handler::builder()
+            .f(Box::new(|input| calls.push(input.to_owned())))
+            .build();
+
+        (handler.f)("a");
+        (handler.f)("b");
+    }
+
+    handle()
+        .f(Box::new(|input| calls.push(input.to_owned())))
+        .input("c")
+        .call();
+
+    assert_eq!(calls, ["a", "b", "c"]);
+}
+
+// The builder's own generic params must not clash with the user's ones
+#[test]
+#[allow(non_upper_case_globals)]
+fn struct_generic_params_named_like_generated_ones() {
+    #[derive(Debug, Builder)]
+    #[builder(finish_turbofish)]
+    #[allow(dead_code, non_camel_case_types)]
+    struct Sut<S, State, ___State, __Value, __S, const __Count: usize> {
+        s: S,
+        state: State,
+        value: __Value,
+        count: Option<[___State; __Count]>,
+        other: Option<__S>,
+    }
+
+    let actual = Sut::builder()
+        .s(1)
+        .state("state")
+        .value(true)
+        .count([(); 2])
+        .build::<_, _, _, _, u8>();
+
+    assert_debug_eq(
+        actual,
+        expect![[r#"
+            Sut {
+                s: 1,
+                state: "state",
+                value: true,
+                count: Some(
+                    [
+                        (),
+                        (),
+                    ],
+                ),
+                other: None,
+            }"#]],
+    );
+}
+
+#[test]
+fn fn_generic_params_named_like_generated_ones() {
+    #[builder]
+    #[allow(non_camel_case_types)]
+    fn sut<__0: Into<u32>, __Arg>(arg: __Arg, other: impl Into<u32>, into: __0) -> u32
+    where
+        __Arg: Into<u32>,
+    {
+        arg.into() + other.into() + into.into()
+    }
+
+    assert_eq!(sut().arg(1_u8).other(2_u16).into(3_u32).call(), 6);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn method_generics_in_generic_impl_block() {
+    struct Sut<'a, T> {
+        prefix: &'a [T],
+    }
+
+    #[bon]
+    impl<'a, T: Clone> Sut<'a, T> {
+        #[builder]
+        #[allow(single_use_lifetimes, clippy::needless_lifetimes)]
+        fn method<'b, U, const N: usize>(
+            &self,
+            items: [U; N],
+            suffix: &'b [T],
+            extra: Option<T>,
+        ) -> (Vec<T>, Vec<U>)
+        where
+            U: Into<T> + Clone,
+        {
+            let mut all = self.prefix.to_vec();
+            all.extend(items.iter().cloned().map(Into::into));
+            all.extend(extra);
+            all.extend_from_slice(suffix);
+
+            (all, items.into())
+        }
+    }
+
+    let sut = Sut { prefix: &[1_u64] };
+
+    let (all, items) = sut.method().items([2_u8, 3]).suffix(&[5]).extra(4).call();
+
+    assert_eq!(all, [1, 2, 3, 4, 5]);
+    assert_eq!(items, [2, 3]);
+
+    let (all, items) = sut.method().items([true]).suffix(&[]).call();
+
+    assert_eq!(all, [1, 1]);
+    assert_eq!(items, [true]);
+}
+
+// The generic params generated for `impl Trait` must not clash with the
+// generics of the method and the impl block
+#[test]
+#[allow(non_camel_case_types)]
+fn method_generics_named_like_generated_ones() {
+    struct Sut<__0>(__0);
+
+    #[bon]
+    impl<__0: Into<u64>> Sut<__0> {
+        #[builder]
+        fn sum<__1: Into<u64>>(self, a: __1, b: impl Into<u64>) -> u64 {
+            self.0.into() + a.into() + b.into()
+        }
+    }
+
+    assert_eq!(Sut(1_u8).sum().a(2_u16).b(3_u32).call(), 6);
+}
@@ -68,3 +68,44 @@ fn struct_init_order() {
         expect!["(1, 2, [6, 7, 8])"],
     );
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn panic_in_default_drops_evaluated_members() {
+    use crate::tracked::{drop_counts, Tracked, NO_DROPS};
+    use core::sync::atomic::AtomicUsize;
+
+    static DROPS: [AtomicUsize; 3] = [NO_DROPS; 3];
+
+    fn fail() -> u32 {
+        panic!("panic in the default expression")
+    }
+
+    #[derive(Builder)]
+    #[allow(dead_code)]
+    struct Sut {
+        arg1: Tracked,
+
+        #[builder(skip = Tracked::new(1, &DROPS))]
+        arg2: Tracked,
+
+        #[builder(default = fail())]
+        arg3: u32,
+
+        // Set in the builder, but its variable isn't initialized yet at the
+        // moment of the panic
+        arg4: Tracked,
+    }
+
+    let result = std::panic::catch_unwind(|| {
+        Sut::builder()
+            .arg1(Tracked::new(0, &DROPS))
+            .arg4(Tracked::new(2, &DROPS))
+            .build()
+    });
+
+    assert!(result.is_err());
+
+    // Every member is dropped exactly once
+    assert_eq!(drop_counts(&DROPS), [1, 1, 1]);
+}
@@ -10,3 +10,27 @@ fn func_with_skipped_generic_arg() {
 
     sut().arg(&32).call();
 }
+
+#[test]
+#[deny(non_snake_case)]
+fn non_snake_case_members() {
+    #[builder]
+    fn sum(
+        #[allow(non_snake_case)] fooBar: u32,
+        #[allow(non_snake_case)] bazQux: Option<u32>,
+    ) -> u32 {
+        fooBar + bazQux.unwrap_or_default()
+    }
+
+    #[derive(Builder)]
+    #[allow(non_snake_case)]
+    struct Sut {
+        fooBar: u32,
+        bazQux: Option<u32>,
+    }
+
+    assert_eq!(sum().fooBar(1).bazQux(2).call(), 3);
+
+    let actual = Sut::builder().fooBar(1).bazQux(2).build();
+    assert_eq!((actual.fooBar, actual.bazQux), (1, Some(2)));
+}
@@ -1,10 +1,60 @@
+#[cfg(feature = "arbitrary")]
+mod attr_arbitrary;
+mod attr_args_struct;
+mod attr_as_set_fields;
+mod attr_assert_field_count;
+mod attr_bitor;
+mod attr_blanket_from;
+#[cfg(feature = "alloc")]
+mod attr_borrow;
+mod attr_build_by_ref;
+mod attr_build_result_wrapper;
+mod attr_capture_locations;
+mod attr_checked;
+#[cfg(feature = "alloc")]
+mod attr_collection;
+mod attr_const_field;
 mod attr_default;
+mod attr_defmt_trace;
+mod attr_delegate;
+mod attr_deprecated_removed_setter;
 mod attr_expose_positional_fn;
+mod attr_ffi_ctor;
+mod attr_field_setter_vis;
+#[cfg(feature = "alloc")]
+mod attr_finalize;
+mod attr_finish_and_reset;
+mod attr_finish_fn;
+mod attr_finish_into_out;
+mod attr_finish_into_slot;
+mod attr_finish_where;
+mod attr_finish_with;
+mod attr_generate_test;
+mod attr_init_after;
 mod attr_into;
+mod attr_into_option;
+mod attr_invariant;
+#[cfg(feature = "json-schema")]
+mod attr_json_schema;
+mod attr_macro;
+mod attr_module;
+mod attr_mut_setters;
 mod attr_on;
+mod attr_one_of;
+mod attr_required_message;
+mod attr_resettable;
+mod attr_setter_body;
+mod attr_setter_trait;
 mod attr_skip;
+mod attr_start_fn;
+mod attr_start_with_defaults;
+mod attr_via_fn;
+mod attr_view;
+mod builder_all;
 mod builder_derives;
 mod cfgs;
+mod drops;
+mod finish_trait;
 mod generics;
 mod init_order;
 mod lints;
@@ -12,7 +62,9 @@ mod many_params;
 mod name_conflicts;
 mod positional_members;
 mod raw_idents;
+mod required_fields;
 mod smoke;
+mod ufcs;
 
 /// Tests for the deprecated features that we still support, but that we'll
 /// eventually remove in the future in a new major version release.
@@ -195,6 +247,48 @@ fn self_in_a_bunch_of_places() {
     assert_eq!(Sut.method().me(Sut).call().count(), 1);
 }
 
+#[cfg(feature = "alloc")]
+#[test]
+fn self_in_nested_member_types() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Node {
+        value: u32,
+        next: Option<Box<Self>>,
+    }
+
+    #[bon]
+    impl Node {
+        #[builder]
+        fn link(value: u32, next: Option<Box<Self>>) -> Self {
+            Self { value, next }
+        }
+    }
+
+    let tail = Node::builder().value(2).build();
+    let node = Node::builder().value(1).next(Box::new(tail)).build();
+    let node = Node::link().value(0).next(Box::new(node)).call();
+
+    assert_debug_eq(
+        node,
+        expect![[r#"
+            Node {
+                value: 0,
+                next: Some(
+                    Node {
+                        value: 1,
+                        next: Some(
+                            Node {
+                                value: 2,
+                                next: None,
+                            },
+                        ),
+                    },
+                ),
+            }"#]],
+    );
+}
+
 #[test]
 fn receiver_is_non_default() {
     struct Sut {
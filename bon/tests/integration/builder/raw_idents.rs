@@ -40,3 +40,52 @@ fn fn_case() {
 
     let _: r#type = sut();
 }
+
+#[test]
+#[allow(non_camel_case_types)]
+fn keyword_struct_case() {
+    #[derive(Builder)]
+    #[builder(derive(Clone))]
+    struct r#enum<T: Clone> {
+        r#type: T,
+        r#ref: Option<u32>,
+    }
+
+    let builder: enumBuilder<u32> = r#enum::builder();
+    let builder = builder.r#type(42);
+    let actual = builder.clone().r#ref(1).build();
+
+    assert_eq!(actual.r#type, 42);
+    assert_eq!(actual.r#ref, Some(1));
+    assert_eq!(builder.build().r#ref, None);
+}
+
+#[test]
+#[allow(non_camel_case_types)]
+fn assoc_method_case() {
+    struct r#struct {
+        r#type: u32,
+    }
+
+    #[bon]
+    impl r#struct {
+        #[builder]
+        fn new(r#type: u32) -> Self {
+            Self { r#type }
+        }
+
+        #[builder]
+        fn r#match(&self, r#in: u32) -> u32 {
+            self.r#type + r#in
+        }
+    }
+
+    let _: structBuilder = r#struct::builder();
+    let actual = r#struct::builder().r#type(42).build();
+
+    assert_eq!(actual.r#type, 42);
+
+    let _: structMatchBuilder<'_> = actual.r#match();
+
+    assert_eq!(actual.r#match().r#in(8).call(), 50);
+}
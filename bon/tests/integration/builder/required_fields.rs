@@ -0,0 +1,51 @@
+use crate::prelude::*;
+
+#[test]
+fn struct_smoke() {
+    #[derive(Builder)]
+    #[builder(required_fields)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(start_fn)]
+        id: u32,
+
+        #[builder(finish_fn)]
+        token: u64,
+
+        name: &'static str,
+        level: Option<u32>,
+
+        #[builder(default)]
+        retries: u32,
+
+        #[builder(skip)]
+        cache: u8,
+
+        r#type: &'static str,
+    }
+
+    assert_eq!(SutBuilder::REQUIRED_FIELDS, ["id", "token", "name", "type"]);
+}
+
+#[test]
+fn generic_struct() {
+    #[derive(Builder)]
+    #[builder(required_fields)]
+    #[allow(dead_code)]
+    struct Sut<T> {
+        value: T,
+        extra: Option<T>,
+    }
+
+    assert_eq!(SutBuilder::<u32>::REQUIRED_FIELDS, ["value"]);
+}
+
+#[test]
+fn fn_smoke() {
+    #[builder(required_fields)]
+    fn sut(_x: u32, y: Option<u32>, z: bool) {
+        let _ = (y, z);
+    }
+
+    assert_eq!(SutBuilder::REQUIRED_FIELDS, ["_x", "z"]);
+}
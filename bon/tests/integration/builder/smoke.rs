@@ -107,3 +107,139 @@ fn smoke_struct() {
 
     expected.assert_debug_eq(&actual);
 }
+
+#[test]
+fn struct_emitted_by_other_macro() {
+    // Emulates an attribute macro that owns the struct definition and emits it
+    // with some modifications. The derive is kept in the output of that macro,
+    // so `bon` only adds the builder items and doesn't emit the struct again.
+    macro_rules! add_field {
+        ($(#[$attr:meta])* struct $name:ident { $($field:ident: $ty:ty),* $(,)? }) => {
+            $(#[$attr])*
+            struct $name {
+                $($field: $ty,)*
+                added: u32,
+            }
+        };
+    }
+
+    add_field! {
+        #[derive(Debug, Builder)]
+        #[builder(finish_fn = finish)]
+        #[allow(dead_code)]
+        struct Sut {
+            x: u32,
+        }
+    }
+
+    assert_debug_eq(
+        Sut::builder().x(1).added(2).finish(),
+        expect!["Sut { x: 1, added: 2 }"],
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+#[allow(deprecated)]
+fn struct_field_attrs_passthrough() {
+    // The `#[builder]` attribute on a struct emits the struct as is, so non-`builder`
+    // attributes on the fields must be preserved, while `#[builder(...)]` field
+    // attributes are consumed by the `#[derive(Builder)]` as helper attributes.
+    #[builder]
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[cfg_attr(all(), builder(default = 42))]
+        x: u32,
+
+        #[builder(into)]
+        #[cfg_attr(all(), allow(dead_code))]
+        y: String,
+
+        // This field must disappear from both the emitted struct and the builder
+        #[cfg(any())]
+        z: u32,
+    }
+
+    // Same for `#[derive(Builder)]` which doesn't emit the struct at all
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct SutDerive {
+        #[cfg_attr(all(), builder(default = 42))]
+        x: u32,
+
+        #[cfg(any())]
+        z: u32,
+    }
+
+    assert_debug_eq(
+        Sut::builder().y("y").build(),
+        expect![[r#"Sut { x: 42, y: "y" }"#]],
+    );
+
+    assert_debug_eq(SutDerive::builder().build(), expect!["SutDerive { x: 42 }"]);
+}
+
+#[test]
+#[allow(deprecated)]
+fn struct_field_attrs_of_other_derives_are_kept() {
+    use serde::de::value::{Error, MapDeserializer};
+    use serde::Deserialize;
+
+    // The `serde` derive reads its attribute from the field of the struct
+    // emitted by `#[builder]`, so the field can be deserialized only if the
+    // attribute is kept there
+    #[builder]
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[serde(rename = "renamed")]
+        #[builder(default)]
+        x: u32,
+    }
+
+    let deserializer = MapDeserializer::<_, Error>::new(core::iter::once(("renamed", 1_u32)));
+
+    assert_debug_eq(Sut::deserialize(deserializer), expect!["Ok(Sut { x: 1 })"]);
+    assert_debug_eq(Sut::builder().build(), expect!["Sut { x: 0 }"]);
+}
+
+#[test]
+fn fallible_fn() {
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Foo {
+        x: u32,
+    }
+
+    #[derive(Debug)]
+    struct FooError;
+
+    #[builder(finish_fn = build)]
+    fn make(x: u32) -> Result<Foo, FooError> {
+        if x == 0 {
+            return Err(FooError);
+        }
+        Ok(Foo { x })
+    }
+
+    struct Factory;
+
+    #[bon]
+    impl Factory {
+        #[builder]
+        fn make(x: u32) -> Result<Foo, FooError> {
+            make().x(x).build()
+        }
+    }
+
+    fn run(x: u32) -> Result<u32, FooError> {
+        let foo = make().x(x).build()?;
+        let other: Foo = Factory::make().x(x + 1).call()?;
+
+        Ok(foo.x + other.x)
+    }
+
+    assert_eq!(run(1).unwrap(), 3);
+    assert_debug_eq(run(0), expect!["Err(FooError)"]);
+}
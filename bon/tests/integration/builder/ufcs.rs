@@ -0,0 +1,45 @@
+use crate::prelude::*;
+
+#[test]
+fn setters_in_ufcs_form() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Sut {
+        x1: u32,
+        x2: Option<u32>,
+    }
+
+    let builder = SutBuilder::x1(Sut::builder(), 1);
+    let builder = SutBuilder::maybe_x2(builder, Some(2));
+
+    assert_debug_eq(builder.build(), expect!["Sut { x1: 1, x2: Some(2) }"]);
+
+    #[builder]
+    fn sum(x1: u32, x2: u32) -> u32 {
+        x1 + x2
+    }
+
+    // Setters can be passed around as function values
+    let set_x1 = SumBuilder::x1;
+
+    assert_eq!(set_x1(sum(), 3).x2(4).call(), 7);
+}
+
+// Setters from the setter trait don't change the type of the builder once
+// the member is set, so they can be used with `fold`
+#[test]
+fn setter_trait_in_fold() {
+    #[derive(Debug, Builder)]
+    #[builder(setter_trait = SutBuilderSetters)]
+    #[allow(dead_code)]
+    struct Sut {
+        x1: u32,
+    }
+
+    let sut = [1, 2, 3]
+        .into_iter()
+        .fold(Sut::builder().x1(0), SutBuilderSetters::x1)
+        .build();
+
+    assert_debug_eq(sut, expect!["Sut { x1: 3 }"]);
+}
@@ -16,7 +16,7 @@ extern crate alloc;
 mod prelude {
     #[cfg(feature = "alloc")]
     pub(crate) use alloc::{
-        borrow::ToOwned, collections::BTreeSet, format, string::String, vec, vec::Vec,
+        borrow::ToOwned, boxed::Box, collections::BTreeSet, format, string::String, vec, vec::Vec,
     };
 
     pub(crate) use super::assert_debug_eq;
@@ -25,6 +25,7 @@ mod prelude {
 }
 
 mod builder;
+mod tracked;
 mod ui;
 
 use expect_test::Expect;
@@ -0,0 +1,34 @@
+//! Values that count how many times they were dropped. They are used to test
+//! that the builders drop every member exactly once.
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// Initial value of the drop counters. Use it to declare an array of counters
+/// in a `static`, e.g. `static DROPS: [AtomicUsize; 2] = [NO_DROPS; 2];`
+#[allow(clippy::declare_interior_mutable_const)]
+pub(crate) const NO_DROPS: AtomicUsize = AtomicUsize::new(0);
+
+/// Counts the drops of the values with the same id
+pub(crate) struct Tracked {
+    id: usize,
+    drops: &'static [AtomicUsize],
+}
+
+impl Tracked {
+    pub(crate) fn new(id: usize, drops: &'static [AtomicUsize]) -> Self {
+        Self { id, drops }
+    }
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.drops[self.id].fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+pub(crate) fn drop_counts<const N: usize>(drops: &[AtomicUsize; N]) -> [usize; N] {
+    let mut counts = [0; N];
+    for (count, drops) in counts.iter_mut().zip(drops) {
+        *count = drops.load(Ordering::SeqCst);
+    }
+    counts
+}
@@ -0,0 +1,13 @@
+mod private_field {
+    #[derive(bon::Builder)]
+    #[builder(args_struct = SutArgs)]
+    pub struct Sut {
+        pub x: u32,
+        y: Option<u32>,
+    }
+}
+
+fn main() {
+    // The fields of the args struct have the visibility of the struct's fields
+    let _ = private_field::Sut::from_args(private_field::SutArgs { x: 1, y: None });
+}
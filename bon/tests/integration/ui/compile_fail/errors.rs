@@ -130,3 +130,491 @@ fn double_must_use() {}
 
 #[builder]
 struct BuilderProcMacroAttrOnAStruct {}
+
+#[derive(Builder)]
+#[builder(ffi_ctor = ffi_unsafe_member_new)]
+#[repr(C)]
+struct FfiUnsafeMember {
+    x: u32,
+    y: String,
+}
+
+#[derive(Builder)]
+#[builder(ffi_ctor = ffi_generic_struct_new)]
+#[repr(C)]
+struct FfiGenericStruct<T> {
+    x: T,
+}
+
+#[derive(Builder)]
+#[builder(ffi_ctor = ffi_array_member_new)]
+#[repr(C)]
+struct FfiArrayMember {
+    x: [u32; 2],
+}
+
+#[derive(Builder)]
+#[builder(ffi_ctor = ffi_default_repr_new)]
+struct FfiDefaultRepr {
+    x: u32,
+}
+
+#[derive(Builder)]
+struct IntoOptionRequiredMember {
+    #[builder(into_option)]
+    x: u32,
+}
+
+#[derive(Builder)]
+struct IntoOptionWithInto {
+    #[builder(into_option, into)]
+    x: Option<String>,
+}
+
+#[derive(Builder)]
+struct SetterCollidesWithFinishFn {
+    build: u32,
+}
+
+#[derive(Builder)]
+#[builder(finish_fn = maybe_x)]
+struct OptionalSetterCollidesWithFinishFn {
+    x: Option<u32>,
+}
+
+#[builder]
+fn setter_collides_with_call(call: u32) {
+    let _ = call;
+}
+
+#[derive(Builder)]
+#[builder(finish_fn = x_via)]
+struct ViaSetterCollidesWithFinishFn {
+    #[builder(via_fn)]
+    x: u32,
+}
+
+#[derive(Builder)]
+#[builder(mut_setters)]
+struct MutSetterCollidesWithOtherSetter {
+    x: Option<u32>,
+    set_x: u32,
+}
+
+#[derive(Builder)]
+#[builder(view = ViewCollisionView)]
+struct SetterCollidesWithView {
+    build_view: u32,
+}
+
+#[derive(Builder)]
+#[builder(finish_into_out)]
+struct SetterCollidesWithFinishIntoOut {
+    build_into_out: u32,
+}
+
+#[derive(Builder)]
+#[builder(finish_and_reset)]
+struct SetterCollidesWithFinishAndReset {
+    build_and_reset: u32,
+}
+
+#[derive(Builder)]
+#[builder(as_set_fields)]
+struct SetterCollidesWithAsSetFields {
+    as_set_fields: u32,
+}
+
+#[derive(Builder)]
+struct MaybeSetterCollidesWithOtherSetter {
+    x: Option<u32>,
+    maybe_x: u32,
+}
+
+#[derive(Builder)]
+struct TrySetterCollidesWithOtherSetter {
+    #[builder(collection, item_validate(with = |_: &u32| Ok(()), error = ()))]
+    x: Vec<u32>,
+    try_x: u32,
+}
+
+#[derive(Builder)]
+#[builder(derive(Debug), test_only_debug)]
+struct TestOnlyDebugWithDeriveDebug {
+    x: u32,
+}
+
+#[derive(Builder)]
+struct FinishByRefWithoutFinishWith {
+    #[builder(finish_by_ref)]
+    x: u32,
+}
+
+#[builder]
+fn finish_by_ref_on_fn_arg(#[builder(finish_by_ref)] x: u32) {
+    let _ = x;
+}
+
+#[derive(Builder)]
+#[builder(finish_fn(on = String))]
+struct FinishFnOnOtherType {}
+
+#[derive(Builder)]
+#[builder(view = ViewBeforeRequiredMemberIsSetView)]
+struct ViewBeforeRequiredMemberIsSet {
+    x: u32,
+    y: Option<u32>,
+}
+
+struct ViewBeforeRequiredMemberIsSetView<'a> {
+    x: &'a u32,
+    y: Option<&'a u32>,
+}
+
+fn view_before_required_member_is_set() {
+    let view = ViewBeforeRequiredMemberIsSet::builder().y(1).build_view();
+    let _ = (view.x, view.y);
+}
+
+#[derive(Builder)]
+struct RequiredMessage {
+    #[builder(required_message = "you must provide a name")]
+    name: u32,
+
+    #[builder(required_message = "`{level}` is a required logging level")]
+    level: u32,
+}
+
+fn required_message_is_reported() {
+    let _ = RequiredMessage::builder().level(1).build();
+    let _ = RequiredMessage::builder().name(1).build();
+}
+
+#[derive(Builder)]
+struct RequiredMessageOnOptionalMember {
+    #[builder(required_message = "this is never reported")]
+    x: Option<u32>,
+}
+
+#[derive(Builder)]
+struct BitOrWithoutBitOrImpl {
+    #[builder(bitor)]
+    x: String,
+}
+
+#[derive(Builder)]
+#[builder(setter_trait = BitOrInSetterTraitSetters)]
+struct BitOrInSetterTrait {
+    #[builder(bitor)]
+    x: u32,
+}
+
+#[derive(Builder)]
+struct BitOrWithCheck {
+    #[builder(bitor, checked(with = |_: &u32| Ok(()), error = ()))]
+    x: u32,
+}
+
+#[derive(Builder)]
+struct CollectionOfNonVec {
+    #[builder(collection)]
+    x: u32,
+}
+
+#[derive(Builder)]
+struct CollectionWithVia {
+    #[builder(collection, via_fn)]
+    x: Vec<u32>,
+}
+
+#[derive(Builder)]
+struct ItemValidateWithoutCollection {
+    #[builder(item_validate(with = |_: &u32| Ok(()), error = ()))]
+    x: Vec<u32>,
+}
+
+#[derive(Builder)]
+#[builder(setter_trait = CollectionInSetterTraitSetters)]
+struct CollectionInSetterTrait {
+    #[builder(collection)]
+    x: Vec<u32>,
+}
+
+#[derive(Builder)]
+#[builder(delegate(fn associated() -> u32))]
+struct DelegateWithoutReceiver {
+    x: u32,
+}
+
+#[derive(Builder)]
+#[builder(delegate(async fn method(&self) -> u32))]
+struct DelegateAsync {
+    x: u32,
+}
+
+#[derive(Builder)]
+#[builder(delegate(fn method(&self) -> u32))]
+struct DelegateWithFinishFnMember {
+    #[builder(finish_fn)]
+    x: u32,
+}
+
+#[derive(Builder)]
+#[builder(delegate(fn name(&self) -> &str))]
+struct DelegateReturnsElidedBorrow {
+    label: String,
+}
+
+#[derive(Builder)]
+#[builder(delegate(fn first<'a>(&'a self) -> Option<&'a u32>))]
+struct DelegateReturnsNamedBorrow {
+    items: Vec<u32>,
+}
+
+#[derive(Builder)]
+#[builder(delegate(fn name(&self) -> String))]
+struct DelegateCollidesWithSetter {
+    name: String,
+}
+
+#[derive(Builder)]
+#[builder(derive(Eq, Hash))]
+struct DeriveEqWithoutPartialEq {
+    x: u32,
+}
+
+#[derive(Builder)]
+#[builder(finish_into_out)]
+struct FinishIntoOutWithOutMember {
+    #[builder(finish_fn)]
+    out: u32,
+}
+
+#[derive(Builder)]
+#[builder(finish_into_slot)]
+struct FinishIntoSlotWithSlotMember {
+    #[builder(finish_fn)]
+    slot: u32,
+}
+
+#[derive(Builder)]
+#[builder(capture_locations)]
+struct CaptureLocationsWithFieldLocationMember {
+    field_location: u32,
+}
+
+#[derive(Builder)]
+#[builder(blanket_from)]
+struct BlanketFromWithSeveralMembers {
+    #[builder(into)]
+    x: u32,
+    y: u32,
+}
+
+#[derive(Builder)]
+#[builder(blanket_from)]
+struct BlanketFromWithoutInto {
+    x: u32,
+}
+
+#[derive(Builder)]
+struct BorrowOfNonCow<'a> {
+    #[builder(borrow)]
+    x: &'a str,
+}
+
+#[derive(Builder)]
+struct BorrowWithInto<'a> {
+    #[builder(borrow, into)]
+    x: std::borrow::Cow<'a, str>,
+}
+
+#[derive(Builder)]
+#[builder(generate_test)]
+struct GenerateTestWithGenerics<T> {
+    x: T,
+}
+
+#[derive(Builder)]
+#[builder(assert_field_count = 1)]
+struct AssertFieldCountMismatch {
+    x: u32,
+    y: u32,
+}
+
+#[derive(Builder)]
+#[builder(build_result_wrapper = Option, blanket_from)]
+struct BuildResultWrapperWithBlanketFrom {
+    #[builder(into)]
+    x: u32,
+}
+
+#[derive(Builder)]
+#[builder(deprecated_removed_setter(x: u32))]
+struct DeprecatedRemovedSetterCollision {
+    x: u32,
+}
+
+#[derive(Builder)]
+struct SetterBodyWithBitOr {
+    #[builder(setter_body = |v| v, bitor)]
+    x: u32,
+}
+
+#[derive(Builder)]
+#[builder(resettable)]
+struct ResettableCollision {
+    x: u32,
+    reset_x: Option<u32>,
+}
+
+#[builder]
+fn const_field_on_fn_arg(#[builder(const_field = 1)] _x: u32) {}
+
+#[derive(Builder)]
+struct ConstFieldWithInto {
+    #[builder(const_field = 1, into)]
+    x: u32,
+}
+
+#[derive(Builder)]
+struct ConstFieldInvalidDerivedName {
+    #[builder(const_field = 1)]
+    _1: u32,
+}
+
+#[derive(Builder)]
+struct ConstFieldEmptyDerivedName {
+    #[builder(const_field = 1)]
+    __: u32,
+}
+
+#[derive(Builder)]
+struct ConstFieldDuplicateName {
+    #[builder(const_field = 1)]
+    _x: u32,
+
+    #[builder(const_field = 2)]
+    x: u32,
+}
+
+#[derive(Builder)]
+struct ConstFieldDuplicateExplicitName {
+    #[builder(const_field = 1)]
+    x: u32,
+
+    #[builder(const_field(name = X, value = 2))]
+    y: u32,
+}
+
+#[derive(Builder)]
+struct ConstFieldWithoutValue {
+    #[builder(const_field(name = X))]
+    x: u32,
+}
+
+#[derive(Builder)]
+#[builder(cfg(unix, windows))]
+struct CfgWithSeveralPredicates {
+    x: u32,
+}
+
+#[derive(Builder)]
+#[builder(cfg())]
+struct CfgWithoutPredicate {
+    x: u32,
+}
+
+#[derive(Builder)]
+#[builder(finish_turbofish)]
+struct FinishTurbofishWithoutTypeParams<'a, const N: usize> {
+    x: &'a [u32; N],
+}
+
+#[derive(Builder)]
+#[builder(arbitrary)]
+struct ArbitraryWithBitor {
+    #[builder(bitor)]
+    x: u32,
+}
+
+#[derive(Builder)]
+struct InitAfterCycle {
+    #[builder(default, init_after = z)]
+    x: u32,
+
+    #[builder(default, init_after = x)]
+    y: u32,
+
+    #[builder(default, init_after = y)]
+    z: u32,
+}
+
+#[derive(Builder)]
+struct InitAfterUnknownMember {
+    #[builder(default, init_after = unknown)]
+    x: u32,
+}
+
+#[derive(Builder)]
+struct InitAfterWithStartFn {
+    #[builder(start_fn, init_after = y)]
+    x: u32,
+    y: u32,
+}
+
+#[derive(Builder)]
+#[builder(macro = macro_unknown_member)]
+struct MacroUnknownMember {
+    x: u32,
+    y: Option<u32>,
+}
+
+fn macro_unknown_member() {
+    let _ = macro_unknown_member! { x: 1, z: 2 };
+}
+
+#[derive(Builder)]
+#[builder(macro = macro_with_start_fn)]
+struct MacroWithStartFn {
+    #[builder(start_fn)]
+    x: u32,
+}
+
+fn check_invariant<T>(_: &T) -> Result<(), ()> {
+    Ok(())
+}
+
+#[derive(Builder)]
+#[builder(invariant = check_invariant)]
+struct InvariantWithoutErrorType {
+    x: u32,
+}
+
+#[derive(Builder)]
+#[builder(
+    invariant(with = check_invariant, error = ()),
+    build_result_wrapper = Option,
+)]
+struct InvariantErrorWithBuildResultWrapper {
+    x: u32,
+}
+
+#[derive(Builder)]
+#[builder(invariant(with = check_invariant, error = ()), args_struct = InvariantArgs)]
+struct InvariantWithArgsStruct {
+    x: u32,
+}
+
+#[derive(Builder)]
+#[builder(args_struct = ArgsStructWithCheckedArgs)]
+struct ArgsStructWithChecked {
+    #[builder(checked(with = |_: &u32| Ok(()), error = ()))]
+    x: u32,
+}
+
+#[derive(Builder)]
+#[builder(build_result_wrapper = "AppResult")]
+struct BuildResultWrapperNotPath {
+    x: u32,
+}
@@ -0,0 +1,52 @@
+use bon::Builder;
+
+#[derive(Builder)]
+struct Source {
+    #[builder(one_of(source))]
+    path: Option<String>,
+
+    #[builder(one_of(source))]
+    url: Option<String>,
+}
+
+#[derive(Builder)]
+struct OneOfNonOption {
+    #[builder(one_of(source))]
+    path: String,
+
+    #[builder(one_of(source))]
+    url: Option<String>,
+}
+
+#[derive(Builder)]
+struct OneOfSingleMember {
+    #[builder(one_of(source))]
+    path: Option<String>,
+}
+
+#[derive(Builder)]
+struct OneOfWithDefault {
+    #[builder(one_of(source))]
+    path: Option<String>,
+
+    #[builder(one_of(source), default)]
+    url: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(args_struct = OneOfWithArgsStructArgs)]
+struct OneOfWithArgsStruct {
+    #[builder(one_of(source))]
+    path: Option<String>,
+
+    #[builder(one_of(source))]
+    url: Option<String>,
+}
+
+fn main() {
+    // Two members of the group are set
+    let _ = Source::builder().path("a".to_owned()).url("b".to_owned());
+
+    // None of the members of the group are set
+    let _ = Source::builder().build();
+}
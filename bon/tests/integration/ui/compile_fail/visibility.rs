@@ -0,0 +1,39 @@
+mod private_finish_fn {
+    #[derive(bon::Builder)]
+    #[builder(finish_fn(vis = ""))]
+    pub struct Sut {
+        pub x: u32,
+    }
+}
+
+mod private_field {
+    #[derive(bon::Builder)]
+    #[builder(field_setter_vis)]
+    pub struct Sut {
+        pub x: u32,
+        y: Option<u32>,
+    }
+}
+
+mod private_field_setter_trait {
+    #[derive(bon::Builder)]
+    #[builder(field_setter_vis, setter_trait = SutSetters)]
+    pub struct Sut {
+        pub x: u32,
+        y: Option<u32>,
+    }
+}
+
+// The setter trait doesn't expose the setters of the private fields
+fn configure<B: private_field_setter_trait::SutSetters>(builder: B) {
+    let _ = builder.x(1).maybe_y(Some(2));
+}
+
+fn main() {
+    // Setters are accessible, but the finishing function isn't
+    let _ = private_finish_fn::Sut::builder().x(1).build();
+
+    // Setters of the private fields are private
+    let _ = private_field::Sut::builder().x(1).y(2).build();
+    let _ = private_field::Sut::builder().x(1).maybe_y(Some(2)).build();
+}
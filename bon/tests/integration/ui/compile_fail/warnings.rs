@@ -54,4 +54,52 @@ fn main() {
 
         must_use_compiled_out().call();
     }
+
+    // Test #[must_use] on the setters that don't return the builder type directly
+    #[deny(unused_must_use)]
+    {
+        #[allow(dead_code)]
+        #[derive(Builder)]
+        #[builder(setter_trait = CheckedExampleSetters)]
+        struct CheckedExample {
+            x: u32,
+
+            #[builder(checked(with = |_: &u32| Ok(()), error = ()))]
+            y: u32,
+        }
+
+        fn set_x<B: CheckedExampleSetters>(builder: B) {
+            builder.x(1);
+        }
+
+        set_x(CheckedExample::builder());
+
+        CheckedExample::builder().y(1);
+    }
+
+    // Test #[must_use] on the other setters that return the builder
+    #[deny(unused_must_use)]
+    {
+        #[allow(dead_code)]
+        #[derive(Builder)]
+        #[builder(resettable)]
+        struct ResettableExample {
+            x: u32,
+            y: Option<u32>,
+        }
+
+        ResettableExample::builder().maybe_y(Some(1));
+        ResettableExample::builder().x(1).reset_x();
+    }
+
+    // Test the deprecation warning of the removed setters
+    {
+        #[derive(Builder)]
+        #[builder(deprecated_removed_setter(old_x: u32))]
+        struct RemovedSetterExample {
+            x: u32,
+        }
+
+        let _ = RemovedSetterExample::builder().old_x(1).x(2).build();
+    }
 }
@@ -4,3 +4,12 @@ fn ui() {
     t.compile_fail("tests/integration/ui/compile_fail/*.rs");
     t.pass("tests/integration/ui/pass/*.rs");
 }
+
+/// Tests for the unstable features of the language, that are available
+/// only on the nightly toolchain.
+#[rustversion::nightly]
+#[test]
+fn ui_nightly() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/integration/ui/nightly/*.rs");
+}
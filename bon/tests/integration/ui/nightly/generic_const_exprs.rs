@@ -0,0 +1,32 @@
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+
+use bon::{builder, Builder};
+
+#[derive(Debug, Builder)]
+struct Sut<const N: usize>
+where
+    [(); N * 2]:,
+{
+    buf: [u8; N * 2],
+
+    #[builder(default)]
+    len: usize,
+}
+
+#[builder]
+fn doubled_len<const N: usize>(buf: [u8; N * 2]) -> usize
+where
+    [(); N * 2]:,
+{
+    buf.len()
+}
+
+fn main() {
+    let sut = Sut::<2>::builder().buf([1; 4]).len(4).build();
+
+    assert_eq!(sut.buf, [1; 4]);
+    assert_eq!(sut.len, 4);
+
+    assert_eq!(doubled_len::<3>().buf([0; 6]).call(), 6);
+}
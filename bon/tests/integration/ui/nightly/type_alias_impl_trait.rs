@@ -0,0 +1,36 @@
+#![feature(type_alias_impl_trait)]
+
+use bon::{builder, Builder};
+
+pub type Numbers = impl Iterator<Item = u32> + Clone;
+
+#[define_opaque(Numbers)]
+fn numbers() -> Numbers {
+    1..4
+}
+
+#[derive(Builder)]
+#[builder(derive(Clone))]
+struct Sut {
+    numbers: Numbers,
+    extra: Option<Numbers>,
+}
+
+#[builder]
+fn sum(numbers: Numbers, extra: Option<Numbers>) -> u32 {
+    numbers.chain(extra.into_iter().flatten()).sum()
+}
+
+fn main() {
+    let sut = Sut::builder()
+        .numbers(numbers())
+        .extra(numbers())
+        .clone()
+        .build();
+
+    assert_eq!(sut.numbers.sum::<u32>(), 6);
+    assert_eq!(sut.extra.into_iter().flatten().sum::<u32>(), 6);
+
+    assert_eq!(sum().numbers(numbers()).call(), 6);
+    assert_eq!(sum().numbers(numbers()).extra(numbers()).call(), 12);
+}
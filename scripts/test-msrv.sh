@@ -32,9 +32,11 @@ with_log cd bon
 
 step echo '[workspace]' >> Cargo.toml
 
+step cargo update -p arbitrary --precise 1.1.6
 step cargo update -p syn --precise 2.0.56
 step cargo update -p tokio --precise 1.29.1
 step cargo update -p expect-test --precise 1.4.1
+step cargo update -p serde --precise 1.0.204
 step cargo update -p windows-sys --precise 0.52.0
 
 export RUSTFLAGS="${RUSTFLAGS:-} --allow unknown-lints"
@@ -131,6 +131,10 @@ export default defineConfig({
                             text: "Into Conversions In-Depth",
                             link: "/guide/patterns/into-conversions-in-depth",
                         },
+                        {
+                            text: "Setters as Functions",
+                            link: "/guide/patterns/setters-as-functions",
+                        },
                         {
                             text: "Shared Configuration",
                             link: "/guide/patterns/shared-configuration",
@@ -159,26 +163,162 @@ export default defineConfig({
                                     text: "Top-Level Attributes",
                                     link: "/reference/builder#top-level-attributes",
                                     items: [
+                                        {
+                                            text: "arbitrary",
+                                            link: "/reference/builder#arbitrary",
+                                        },
+                                        {
+                                            text: "args_struct",
+                                            link: "/reference/builder#args-struct",
+                                        },
+                                        {
+                                            text: "as_set_fields",
+                                            link: "/reference/builder#as-set-fields",
+                                        },
+                                        {
+                                            text: "assert_field_count",
+                                            link: "/reference/builder#assert-field-count",
+                                        },
+                                        {
+                                            text: "blanket_from",
+                                            link: "/reference/builder#blanket-from",
+                                        },
+                                        {
+                                            text: "build_by_ref",
+                                            link: "/reference/builder#build-by-ref",
+                                        },
+                                        {
+                                            text: "build_result_wrapper",
+                                            link: "/reference/builder#build-result-wrapper",
+                                        },
                                         {
                                             text: "builder_type",
                                             link: "/reference/builder#builder-type",
                                         },
+                                        {
+                                            text: "capture_locations",
+                                            link: "/reference/builder#capture-locations",
+                                        },
+                                        {
+                                            text: "cfg",
+                                            link: "/reference/builder#cfg",
+                                        },
                                         {
                                             text: "derive",
                                             link: "/reference/builder#derive",
                                         },
+                                        {
+                                            text: "defmt_trace",
+                                            link: "/reference/builder#defmt-trace",
+                                        },
+                                        {
+                                            text: "delegate",
+                                            link: "/reference/builder#delegate",
+                                        },
+                                        {
+                                            text: "deprecated_removed_setter",
+                                            link: "/reference/builder#deprecated-removed-setter",
+                                        },
+                                        {
+                                            text: "doc_setters",
+                                            link: "/reference/builder#doc-setters",
+                                        },
                                         {
                                             text: "expose_positional_fn",
                                             link: "/reference/builder#expose-positional-fn",
                                         },
+                                        {
+                                            text: "ffi_ctor",
+                                            link: "/reference/builder#ffi-ctor",
+                                        },
+                                        {
+                                            text: "field_setter_vis",
+                                            link: "/reference/builder#field-setter-vis",
+                                        },
+                                        {
+                                            text: "finalize",
+                                            link: "/reference/builder#finalize",
+                                        },
+                                        {
+                                            text: "finish_and_reset",
+                                            link: "/reference/builder#finish-and-reset",
+                                        },
+                                        {
+                                            text: "finish_into_out",
+                                            link: "/reference/builder#finish-into-out",
+                                        },
+                                        {
+                                            text: "finish_into_slot",
+                                            link: "/reference/builder#finish-into-slot",
+                                        },
                                         {
                                             text: "finish_fn",
                                             link: "/reference/builder#finish-fn",
                                         },
+                                        {
+                                            text: "finish_turbofish",
+                                            link: "/reference/builder#finish-turbofish",
+                                        },
+                                        {
+                                            text: "finish_where",
+                                            link: "/reference/builder#finish-where",
+                                        },
+                                        {
+                                            text: "finish_with",
+                                            link: "/reference/builder#finish-with",
+                                        },
+                                        {
+                                            text: "generate_test",
+                                            link: "/reference/builder#generate-test",
+                                        },
+                                        {
+                                            text: "invariant",
+                                            link: "/reference/builder#invariant",
+                                        },
+                                        {
+                                            text: "json_schema",
+                                            link: "/reference/builder#json-schema",
+                                        },
+                                        {
+                                            text: "macro",
+                                            link: "/reference/builder#macro",
+                                        },
+                                        {
+                                            text: "module",
+                                            link: "/reference/builder#module",
+                                        },
+                                        {
+                                            text: "mut_setters",
+                                            link: "/reference/builder#mut-setters",
+                                        },
+                                        {
+                                            text: "required_fields",
+                                            link: "/reference/builder#required-fields",
+                                        },
+                                        {
+                                            text: "resettable",
+                                            link: "/reference/builder#resettable",
+                                        },
+                                        {
+                                            text: "setter_trait",
+                                            link: "/reference/builder#setter-trait",
+                                        },
+                                        {
+                                            text: "test_only_debug",
+                                            link: "/reference/builder#test-only-debug",
+                                        },
+                                        {
+                                            text: "view",
+                                            link: "/reference/builder#view",
+                                        },
                                         {
                                             text: "start_fn",
                                             link: "/reference/builder#start-fn",
                                         },
+                                        {
+                                            text: "start_with_defaults",
+                                            link: "/reference/builder#start-with-defaults",
+                                        },
                                         {
                                             text: "on",
                                             link: "/reference/builder#on",
@@ -213,6 +353,54 @@ export default defineConfig({
                                             text: "finish_fn",
                                             link: "/reference/builder#finish-fn-1",
                                         },
+                                        {
+                                            text: "finish_by_ref",
+                                            link: "/reference/builder#finish-by-ref",
+                                        },
+                                        {
+                                            text: "checked",
+                                            link: "/reference/builder#checked",
+                                        },
+                                        {
+                                            text: "into_option",
+                                            link: "/reference/builder#into_option",
+                                        },
+                                        {
+                                            text: "via_fn",
+                                            link: "/reference/builder#via-fn",
+                                        },
+                                        {
+                                            text: "required_message",
+                                            link: "/reference/builder#required-message",
+                                        },
+                                        {
+                                            text: "bitor",
+                                            link: "/reference/builder#bitor",
+                                        },
+                                        {
+                                            text: "collection",
+                                            link: "/reference/builder#collection",
+                                        },
+                                        {
+                                            text: "borrow",
+                                            link: "/reference/builder#borrow",
+                                        },
+                                        {
+                                            text: "one_of",
+                                            link: "/reference/builder#one-of",
+                                        },
+                                        {
+                                            text: "setter_body",
+                                            link: "/reference/builder#setter-body",
+                                        },
+                                        {
+                                            text: "const_field",
+                                            link: "/reference/builder#const-field",
+                                        },
+                                        {
+                                            text: "init_after",
+                                            link: "/reference/builder#init-after",
+                                        },
 
                                     ],
                                 },
@@ -222,6 +410,10 @@ export default defineConfig({
                             text: "#[bon]",
                             link: "/reference/bon",
                         },
+                        {
+                            text: "#[builder_all]",
+                            link: "/reference/builder-all",
+                        },
                         {
                             text: "Other items on docs.rs",
                             link: "https://docs.rs/bon/latest/bon/",